@@ -0,0 +1,256 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+
+use log::{info, warn};
+use tauri::AppHandle;
+use tokio::select;
+use tokio::time::MissedTickBehavior;
+
+use crate::configs::config_mining::{BatteryMiningAction, ConfigMining, MiningMode};
+use crate::configs::trait_config::ConfigImpl;
+use crate::events::BatteryPolicyUpdatePayload;
+use crate::events_emitter::EventsEmitter;
+use crate::mining_benchmark::MiningBenchmark;
+use crate::setup::setup_manager::SetupManager;
+use crate::tasks_tracker::TasksTrackers;
+use crate::utils::mining_control_utils;
+use crate::utils::system_status::{PowerSourceStatus, SystemStatus};
+
+const LOG_TARGET: &str = "tari::universe::battery_policy";
+const POLICY_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+/// Charge the battery has to gain over the threshold before the policy is lifted, avoids flapping around the threshold
+const THRESHOLD_HYSTERESIS: u8 = 5;
+
+/// Set while the policy keeps a miner stopped, the other mining controllers leave it alone until it is lifted
+static IS_CPU_MINING_HELD: AtomicBool = AtomicBool::new(false);
+static IS_GPU_MINING_HELD: AtomicBool = AtomicBool::new(false);
+
+/// What was running before the policy kicked in, restored once back on AC or charged
+struct AppliedPolicy {
+    action: BatteryMiningAction,
+    mode: MiningMode,
+    cpu_mining: bool,
+    gpu_mining: bool,
+}
+
+/// Pauses GPU mining, forces Eco mode or stops mining while running on battery, as configured in `ConfigMining`
+pub struct BatteryPolicy {
+    app_handle: AppHandle,
+    power_source: PowerSourceStatus,
+    applied_policy: Option<AppliedPolicy>,
+}
+
+impl BatteryPolicy {
+    pub async fn start(app_handle: AppHandle) {
+        let mut power_source_rx = SystemStatus::current().get_power_source_watcher();
+        let mut battery_policy = Self {
+            app_handle,
+            power_source: *power_source_rx.borrow(),
+            applied_policy: None,
+        };
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                // Config changes are only picked up on the interval, power source changes apply right away
+                let mut interval = tokio::time::interval(POLICY_CHECK_INTERVAL);
+                interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+                loop {
+                    select! {
+                        _ = interval.tick() => {
+                            if battery_policy.evaluate().await {
+                                battery_policy.emit_update().await;
+                            }
+                        }
+                        result = power_source_rx.changed() => {
+                            if result.is_err() {
+                                warn!(target: LOG_TARGET, "Power source watcher stopped, battery policy is unavailable");
+                                break;
+                            }
+                            battery_policy.power_source = *power_source_rx.borrow_and_update();
+                            battery_policy.evaluate().await;
+                            battery_policy.emit_update().await;
+                        }
+                        _ = shutdown_signal.wait() => {
+                            info!(target: LOG_TARGET, "Shutdown signal received, stopping battery policy");
+                            break;
+                        }
+                    }
+                }
+            });
+    }
+
+    pub fn is_cpu_mining_held() -> bool {
+        IS_CPU_MINING_HELD.load(Ordering::SeqCst)
+    }
+
+    pub fn is_gpu_mining_held() -> bool {
+        IS_GPU_MINING_HELD.load(Ordering::SeqCst)
+    }
+
+    fn should_apply(&self, action: BatteryMiningAction, threshold: Option<u8>) -> bool {
+        if action == BatteryMiningAction::None || !self.power_source.is_on_battery {
+            return false;
+        }
+        let Some(threshold) = threshold else {
+            return true;
+        };
+        let margin = if self.applied_policy.is_some() {
+            THRESHOLD_HYSTERESIS
+        } else {
+            0
+        };
+        match self.power_source.battery_percentage {
+            Some(percentage) => percentage < threshold.saturating_add(margin),
+            // Without a reading keep whatever is in place
+            None => self.applied_policy.is_some(),
+        }
+    }
+
+    /// Returns whether the policy was applied or lifted
+    async fn evaluate(&mut self) -> bool {
+        // The benchmark starts and stops the miners itself while it runs
+        if MiningBenchmark::is_running() {
+            return false;
+        }
+        let setup_manager = SetupManager::get_instance();
+        if !setup_manager.is_cpu_mining_unlocked().await
+            || !setup_manager.is_gpu_mining_unlocked().await
+        {
+            return false;
+        }
+
+        let config = ConfigMining::content().await;
+        let action = *config.battery_policy_action();
+        let mut is_changed = false;
+        if self
+            .applied_policy
+            .as_ref()
+            .is_some_and(|applied_policy| applied_policy.action != action)
+        {
+            self.restore().await;
+            is_changed = true;
+        }
+
+        let should_apply = self.should_apply(action, *config.battery_policy_threshold());
+        if should_apply && self.applied_policy.is_none() {
+            self.apply(action, *config.mode()).await;
+            is_changed = true;
+        } else if !should_apply && self.applied_policy.is_some() {
+            self.restore().await;
+            is_changed = true;
+        } else {
+            // The policy is already in the state it should be in
+        }
+        is_changed
+    }
+
+    async fn apply(&mut self, action: BatteryMiningAction, mode: MiningMode) {
+        let app_handle = &self.app_handle;
+        let applied_policy = AppliedPolicy {
+            action,
+            mode,
+            cpu_mining: mining_control_utils::is_cpu_mining(app_handle).await,
+            gpu_mining: mining_control_utils::is_gpu_mining(app_handle).await,
+        };
+        info!(target: LOG_TARGET, "Running on battery ({:?}), applying {:?}", self.power_source, action);
+
+        match action {
+            BatteryMiningAction::None => {}
+            BatteryMiningAction::PauseGpuMining => {
+                IS_GPU_MINING_HELD.store(true, Ordering::SeqCst);
+                mining_control_utils::stop_gpu_mining(app_handle).await;
+            }
+            BatteryMiningAction::ForceEcoMode => {
+                if mode != MiningMode::Eco {
+                    mining_control_utils::switch_mining_mode(app_handle, MiningMode::Eco).await;
+                    restart_miners(app_handle, &applied_policy).await;
+                }
+            }
+            BatteryMiningAction::StopMining => {
+                IS_CPU_MINING_HELD.store(true, Ordering::SeqCst);
+                IS_GPU_MINING_HELD.store(true, Ordering::SeqCst);
+                mining_control_utils::stop_cpu_mining(app_handle).await;
+                mining_control_utils::stop_gpu_mining(app_handle).await;
+            }
+        }
+        self.applied_policy = Some(applied_policy);
+    }
+
+    async fn restore(&mut self) {
+        let Some(applied_policy) = self.applied_policy.take() else {
+            return;
+        };
+        info!(target: LOG_TARGET, "Lifting battery policy {:?}", applied_policy.action);
+        IS_CPU_MINING_HELD.store(false, Ordering::SeqCst);
+        IS_GPU_MINING_HELD.store(false, Ordering::SeqCst);
+
+        let app_handle = &self.app_handle;
+        match applied_policy.action {
+            BatteryMiningAction::None => {}
+            BatteryMiningAction::PauseGpuMining => {
+                if applied_policy.gpu_mining {
+                    mining_control_utils::start_gpu_mining(app_handle).await;
+                }
+            }
+            BatteryMiningAction::ForceEcoMode => {
+                // Leave the mode alone if the user changed it in the meantime
+                if applied_policy.mode != MiningMode::Eco
+                    && *ConfigMining::content().await.mode() == MiningMode::Eco
+                {
+                    mining_control_utils::switch_mining_mode(app_handle, applied_policy.mode).await;
+                    restart_miners(app_handle, &applied_policy).await;
+                }
+            }
+            BatteryMiningAction::StopMining => {
+                restart_miners(app_handle, &applied_policy).await;
+            }
+        }
+    }
+
+    async fn emit_update(&self) {
+        EventsEmitter::emit_battery_policy_update(BatteryPolicyUpdatePayload {
+            power_source: self.power_source,
+            applied_action: self
+                .applied_policy
+                .as_ref()
+                .map(|applied_policy| applied_policy.action),
+            cpu_mining: mining_control_utils::is_cpu_mining(&self.app_handle).await,
+            gpu_mining: mining_control_utils::is_gpu_mining(&self.app_handle).await,
+        })
+        .await;
+    }
+}
+
+async fn restart_miners(app_handle: &AppHandle, applied_policy: &AppliedPolicy) {
+    if applied_policy.cpu_mining {
+        mining_control_utils::start_cpu_mining(app_handle).await;
+    }
+    if applied_policy.gpu_mining {
+        mining_control_utils::start_gpu_mining(app_handle).await;
+    }
+}
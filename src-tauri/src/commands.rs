@@ -26,8 +26,13 @@ use crate::app_in_memory_config::{
 };
 use crate::auto_launcher::AutoLauncher;
 use crate::binaries::{Binaries, BinaryResolver};
-use crate::configs::config_core::{AirdropTokens, ConfigCore, ConfigCoreContent};
-use crate::configs::config_mining::{ConfigMining, ConfigMiningContent, GpuThreads, MiningMode};
+use crate::configs::config_core::{
+    AirdropTokens, ConfigCore, ConfigCoreContent, CustomNodeSettings,
+};
+use crate::configs::config_mining::{
+    BatteryMiningAction, ConfigMining, ConfigMiningContent, GpuDeviceMiningSettings,
+    GpuTemperatureCeiling, GpuThreads, MiningMode, MiningScheduleRule,
+};
 use crate::configs::config_ui::{ConfigUI, ConfigUIContent, DisplayMode};
 use crate::configs::config_wallet::{ConfigWallet, ConfigWalletContent};
 use crate::configs::trait_config::ConfigImpl;
@@ -41,20 +46,27 @@ use crate::external_dependencies::{
 use crate::gpu_miner::EngineType;
 use crate::gpu_miner_adapter::{GpuMinerStatus, GpuNodeSource};
 use crate::gpu_status_file::GpuStatus;
+use crate::hardware::thermal_governor::{ThermalGovernor, ThermalGovernorAction};
 use crate::internal_wallet::{InternalWallet, PaperWalletConfig};
-use crate::node::node_adapter::BaseNodeStatus;
-use crate::node::node_manager::NodeType;
+use crate::mining_benchmark::{MiningBenchmark, MiningBenchmarkResults};
+use crate::mining_history::{BlockEvent, HashrateSample, MiningHistory};
+use crate::node::node_adapter::{BaseNodeStatus, NodePeer};
+use crate::node::node_manager::{NodeManager, NodeType, LOCAL_NODE_SETTINGS_RESTART_PHASES};
+use crate::node::node_snapshot;
 use crate::p2pool::models::{Connections, P2poolStats};
 use crate::setup::setup_manager::{SetupManager, SetupPhase};
+use crate::stratum_proxy::{StratumProxy, StratumWorker};
 use crate::tapplets::interface::ActiveTapplet;
 use crate::tapplets::tapplet_server::start_tapplet;
 use crate::tasks_tracker::TasksTrackers;
 use crate::tor_adapter::TorConfig;
 use crate::utils::address_utils::verify_send;
 use crate::utils::app_flow_utils::FrontendReadyChannel;
+use crate::utils::mining_control_utils;
 use crate::wallet_adapter::{TariAddressVariants, TransactionInfo, WalletBalance};
 use crate::wallet_manager::WalletManagerError;
 use crate::websocket_manager::WebsocketManagerStatusMessage;
+use crate::xmrig::http_api::models::{Cpu, HugepagesStatus, Memory};
 use crate::{airdrop, PoolStatus, UniverseAppState, APPLICATION_FOLDER_ID};
 
 use base64::prelude::*;
@@ -66,6 +78,7 @@ use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::fmt::Debug;
 use std::fs::{read_dir, remove_dir_all, remove_file, File};
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::sync::atomic::Ordering;
 use std::thread::{available_parallelism, sleep};
@@ -125,6 +138,7 @@ pub struct CpuMinerStatus {
     pub estimated_earnings: u64,
     pub connection: CpuMinerConnectionStatus,
     pub pool_status: Option<PoolStatus>,
+    pub diagnostics: Option<CpuMinerDiagnostics>,
 }
 
 impl Default for CpuMinerStatus {
@@ -133,17 +147,45 @@ impl Default for CpuMinerStatus {
             is_mining: false,
             hash_rate: 0.0,
             estimated_earnings: 0,
-            connection: CpuMinerConnectionStatus {
-                is_connected: false,
-            },
+            connection: CpuMinerConnectionStatus::default(),
             pool_status: None,
+            diagnostics: None,
         }
     }
 }
 #[derive(Debug, Serialize, Clone, Default)]
 pub struct CpuMinerConnectionStatus {
     pub is_connected: bool,
-    // pub error: Option<String>,
+    /// `host:port` of the pool xmrig is pointed at, `None` when mining through the built-in proxy
+    pub pool_url: Option<String>,
+    pub accepted_shares: u64,
+    pub rejected_shares: u64,
+    /// Latency to the pool in milliseconds
+    pub ping: u64,
+    pub failures: u64,
+    /// TLS version negotiated with the pool, `None` for plain connections
+    pub tls: Option<String>,
+    pub error_log: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct CpuMinerDiagnostics {
+    pub is_paused: bool,
+    pub algorithm: Option<String>,
+    pub highest_hash_rate: Option<f64>,
+    pub hashes_total: u64,
+    pub threads: Vec<CpuThreadStatus>,
+    pub hugepages: HugepagesStatus,
+    pub memory: Memory,
+    pub cpu: Cpu,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct CpuThreadStatus {
+    /// CPU core the thread is pinned to, -1 when xmrig doesn't set an affinity
+    pub affinity: i64,
+    pub intensity: Option<u32>,
+    pub hash_rate: f64,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -460,6 +502,7 @@ pub async fn get_max_consumption_levels(
         let max_gpu_threads = 8192;
         max_gpus_threads.push(GpuThreads {
             gpu_name: gpu_device.device_name,
+            device_index: Some(gpu_device.device_index),
             max_gpu_threads,
         });
     }
@@ -1260,11 +1303,209 @@ pub async fn set_mine_on_app_start(mine_on_app_start: bool) -> Result<(), Invoke
     Ok(())
 }
 
+#[tauri::command]
+pub async fn set_mining_schedule(
+    enabled: bool,
+    rules: Vec<MiningScheduleRule>,
+) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    for rule in &rules {
+        rule.validate().map_err(InvokeError::from_anyhow)?;
+    }
+    ConfigMining::update_field(ConfigMiningContent::set_mining_schedule, rules)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    ConfigMining::update_field(ConfigMiningContent::set_mining_schedule_enabled, enabled)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "set_mining_schedule took too long: {:?}", timer.elapsed());
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_idle_mining(
+    enabled: bool,
+    timeout_secs: u64,
+    mode: String,
+) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    let mode =
+        MiningMode::from_str(&mode).ok_or_else(|| InvokeError::from("Invalid mode".to_string()))?;
+    ConfigMining::update_field(
+        ConfigMiningContent::set_idle_mining_timeout_secs,
+        timeout_secs,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    ConfigMining::update_field(ConfigMiningContent::set_idle_mining_mode, mode)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    ConfigMining::update_field(ConfigMiningContent::set_idle_mining_enabled, enabled)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "set_idle_mining took too long: {:?}", timer.elapsed());
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_thermal_governor(
+    enabled: bool,
+    cpu_max_temperature: Option<f32>,
+    gpu_max_temperatures: Vec<GpuTemperatureCeiling>,
+    hysteresis: f32,
+) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    if hysteresis < 0.0 {
+        return Err(InvokeError::from(
+            "Hysteresis can not be negative".to_string(),
+        ));
+    }
+    ConfigMining::update_field(
+        ConfigMiningContent::set_cpu_max_temperature,
+        cpu_max_temperature,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    ConfigMining::update_field(
+        ConfigMiningContent::set_gpu_max_temperatures,
+        gpu_max_temperatures,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+    ConfigMining::update_field(ConfigMiningContent::set_thermal_hysteresis, hysteresis)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    ConfigMining::update_field(ConfigMiningContent::set_thermal_governor_enabled, enabled)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "set_thermal_governor took too long: {:?}", timer.elapsed());
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_gpu_device_settings(
+    gpu_device_settings: Vec<GpuDeviceMiningSettings>,
+) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    ConfigMining::update_field(
+        ConfigMiningContent::set_gpu_device_settings,
+        gpu_device_settings,
+    )
+    .await
+    .map_err(InvokeError::from_anyhow)?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "set_gpu_device_settings took too long: {:?}", timer.elapsed());
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn get_thermal_governor_actions() -> Result<Vec<ThermalGovernorAction>, String> {
+    Ok(ThermalGovernor::current().get_actions().await)
+}
+
+#[tauri::command]
+pub async fn set_battery_policy(action: String, threshold: Option<u8>) -> Result<(), InvokeError> {
+    let timer = Instant::now();
+    let action = BatteryMiningAction::from_str(&action)
+        .ok_or_else(|| InvokeError::from("Invalid battery policy action".to_string()))?;
+    if threshold.is_some_and(|threshold| threshold > 100) {
+        return Err(InvokeError::from(
+            "Battery threshold must be a percentage".to_string(),
+        ));
+    }
+    ConfigMining::update_field(ConfigMiningContent::set_battery_policy_threshold, threshold)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    ConfigMining::update_field(ConfigMiningContent::set_battery_policy_action, action)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "set_battery_policy took too long: {:?}", timer.elapsed());
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn run_mining_benchmark(
+    app_handle: tauri::AppHandle,
+    benchmark_cpu: bool,
+    benchmark_gpu: bool,
+) -> Result<MiningBenchmarkResults, InvokeError> {
+    MiningBenchmark::run(&app_handle, benchmark_cpu, benchmark_gpu)
+        .await
+        .map_err(InvokeError::from_anyhow)
+}
+
+#[tauri::command]
+pub async fn get_mining_benchmark_results(
+    app_handle: tauri::AppHandle,
+) -> Result<Option<MiningBenchmarkResults>, InvokeError> {
+    MiningBenchmark::load_results(&app_handle).map_err(InvokeError::from_anyhow)
+}
+
+#[tauri::command]
+pub async fn export_mining_benchmark_results(
+    app_handle: tauri::AppHandle,
+) -> Result<String, InvokeError> {
+    let export_path =
+        MiningBenchmark::export_results(&app_handle).map_err(InvokeError::from_anyhow)?;
+    Ok(export_path.to_string_lossy().to_string())
+}
+
+#[tauri::command]
+pub async fn get_mining_history(
+    from: u64,
+    to: u64,
+    bucket_secs: Option<u64>,
+) -> Result<Vec<HashrateSample>, InvokeError> {
+    let timer = Instant::now();
+    let samples = MiningHistory::current()
+        .get_samples(from, to, bucket_secs.unwrap_or_default())
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+
+    if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
+        warn!(target: LOG_TARGET, "get_mining_history took too long: {:?}", timer.elapsed());
+    }
+    Ok(samples)
+}
+
+#[tauri::command]
+pub async fn get_block_history(from: u64, to: u64) -> Result<Vec<BlockEvent>, InvokeError> {
+    MiningHistory::current()
+        .get_blocks(from, to)
+        .await
+        .map_err(InvokeError::from_anyhow)
+}
+
+#[tauri::command]
+pub async fn get_stratum_proxy_workers() -> Result<Vec<StratumWorker>, InvokeError> {
+    Ok(StratumProxy::current().workers().await)
+}
+
 #[tauri::command]
 pub async fn set_mode(
     mode: String,
     custom_cpu_usage: Option<u32>,
     custom_gpu_usage: Vec<GpuThreads>,
+    app_handle: tauri::AppHandle,
 ) -> Result<(), InvokeError> {
     let timer = Instant::now();
     info!(target: LOG_TARGET, "[set_mode] called with mode: {:?}", mode);
@@ -1324,6 +1565,8 @@ pub async fn set_mode(
     } else {
         return Err(InvokeError::from("Invalid mode".to_string()));
     }
+    // A running xmrig picks up the new mode live, GPU miners still need a restart by the caller
+    mining_control_utils::reconfigure_cpu_mining(&app_handle).await;
 
     if timer.elapsed() > MAX_ACCEPTABLE_COMMAND_TIME {
         warn!(target: LOG_TARGET, "set_mode took too long: {:?}", timer.elapsed());
@@ -1576,17 +1819,15 @@ pub async fn start_cpu_mining(
 ) -> Result<(), String> {
     let timer = Instant::now();
     let _lock = state.cpu_miner_stop_start_mutex.lock().await;
+    if ThermalGovernor::current().is_cpu_paused().await {
+        return Err("CPU mining is paused until the CPU cools down".to_string());
+    }
     let mut timestamp_lock = state.cpu_miner_timestamp_mutex.lock().await;
     *timestamp_lock = SystemTime::now();
 
     let cpu_mining_enabled = *ConfigMining::content().await.cpu_mining_enabled();
     let mode = *ConfigMining::content().await.mode();
-
-    let cpu_usage = match mode {
-        MiningMode::Custom => *ConfigMining::content().await.custom_max_cpu_usage(),
-        MiningMode::Eco => *ConfigMining::content().await.eco_mode_max_cpu_usage(),
-        MiningMode::Ludicrous => *ConfigMining::content().await.ludicrous_mode_max_cpu_usage(),
-    };
+    let cpu_usage = ConfigMining::content().await.max_cpu_usage();
 
     let cpu_miner = state.cpu_miner.read().await;
     let cpu_miner_running = cpu_miner.is_running().await;
@@ -1647,23 +1888,19 @@ pub async fn start_gpu_mining(
 ) -> Result<(), String> {
     let timer = Instant::now();
     let _lock = state.gpu_miner_stop_start_mutex.lock().await;
+    if ThermalGovernor::current().is_gpu_paused().await {
+        return Err("GPU mining is paused until the GPU cools down".to_string());
+    }
 
     let gpu_mining_enabled = *ConfigMining::content().await.gpu_mining_enabled();
     let mode = *ConfigMining::content().await.mode();
 
-    let gpu_usage = match mode {
-        MiningMode::Custom => ConfigMining::content().await.custom_max_gpu_usage().clone(),
-        MiningMode::Eco => ConfigMining::content()
-            .await
-            .eco_mode_max_gpu_usage()
-            .clone(),
-        MiningMode::Ludicrous => ConfigMining::content()
-            .await
-            .ludicrous_mode_max_gpu_usage()
-            .clone(),
-    };
+    // Devices with their own mode may run Custom while the global mode doesn't
+    let custom_gpu_usage = ConfigMining::content().await.custom_max_gpu_usage().clone();
+    let gpu_device_settings = ConfigMining::content().await.gpu_device_settings().clone();
 
     let p2pool_enabled = *ConfigCore::content().await.is_p2pool_enabled();
+    let gpu_pool_urls = ConfigMining::content().await.gpu_pool_urls();
 
     let mut telemetry_id = state
         .telemetry_manager
@@ -1678,6 +1915,7 @@ pub async fn start_gpu_mining(
     let gpu_miner = state.gpu_miner.read().await;
     let gpu_miner_running = gpu_miner.is_running().await;
     let gpu_available = gpu_miner.is_gpu_mining_available();
+    let gpu_pool_url = gpu_pool_urls.get(gpu_miner.active_pool_index()).cloned();
     drop(gpu_miner);
 
     info!(target: LOG_TARGET, "GPU availability {:?} gpu_mining_enabled {}", gpu_available.clone(), gpu_mining_enabled);
@@ -1685,7 +1923,10 @@ pub async fn start_gpu_mining(
     if gpu_mining_enabled && gpu_available && !gpu_miner_running {
         info!(target: LOG_TARGET, "1. Starting gpu miner");
 
-        let source = if p2pool_enabled {
+        let source = if let Some(grpc_address) = gpu_pool_url {
+            info!(target: LOG_TARGET, "Using GPU pool {}", grpc_address);
+            GpuNodeSource::BaseNode { grpc_address }
+        } else if p2pool_enabled {
             let use_local = state.node_manager.is_local_current().await.unwrap_or(false);
             let grpc_address = state.p2pool_manager.get_grpc_address(use_local).await;
             GpuNodeSource::P2Pool { grpc_address }
@@ -1720,7 +1961,8 @@ pub async fn start_gpu_mining(
                 app.path().app_log_dir().expect("Could not get log dir"),
                 mode,
                 telemetry_id,
-                gpu_usage.clone(),
+                custom_gpu_usage,
+                gpu_device_settings,
             )
             .await;
 
@@ -2113,9 +2355,15 @@ pub async fn set_node_type(
     if node_type != NodeType::Local
         && node_type != NodeType::RemoteUntilLocal
         && node_type != NodeType::Remote
+        && node_type != NodeType::Custom
     {
         node_type = NodeType::Local;
     }
+    if node_type == NodeType::Custom && ConfigCore::content().await.custom_node().is_none() {
+        return Err(InvokeError::from(
+            "Custom node settings are not configured".to_string(),
+        ));
+    }
 
     let prev_node_type = state
         .node_manager
@@ -2125,7 +2373,7 @@ pub async fn set_node_type(
     info!(target: LOG_TARGET, "[set_node_type] from {:?} to: {:?}", prev_node_type, node_type);
 
     let is_current_local = matches!(prev_node_type, NodeType::Local | NodeType::LocalAfterRemote);
-    if is_current_local && node_type != NodeType::Remote {
+    if is_current_local && matches!(node_type, NodeType::Local | NodeType::RemoteUntilLocal) {
         info!(target: LOG_TARGET, "[set_node_type] Local node is already running, no restart needed for node_type: {:?}", node_type);
         ConfigCore::update_field(ConfigCoreContent::set_node_type, node_type.clone())
             .await
@@ -2156,6 +2404,217 @@ pub async fn set_node_type(
     Ok(())
 }
 
+#[tauri::command]
+pub async fn set_custom_node(
+    host: String,
+    grpc_port: u16,
+    app_handle: tauri::AppHandle,
+) -> Result<(), InvokeError> {
+    let host = host.trim().to_string();
+    if host.is_empty() || host.contains("://") || host.contains(char::is_whitespace) {
+        return Err(InvokeError::from(format!(
+            "Custom node host {host} should be a host name or IP address"
+        )));
+    }
+    if grpc_port == 0 {
+        return Err(InvokeError::from(
+            "Custom node gRPC port can't be 0".to_string(),
+        ));
+    }
+    info!(target: LOG_TARGET, "[set_custom_node] {}:{}", host, grpc_port);
+
+    let custom_node = Some(CustomNodeSettings { host, grpc_port });
+    if *ConfigCore::content().await.node_type() == NodeType::Custom {
+        ConfigCore::update_field_requires_restart(
+            ConfigCoreContent::set_custom_node,
+            custom_node,
+            vec![SetupPhase::Node, SetupPhase::Wallet, SetupPhase::Mining],
+        )
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+        SetupManager::get_instance()
+            .restart_phases_from_queue(app_handle)
+            .await;
+    } else {
+        ConfigCore::update_field(ConfigCoreContent::set_custom_node, custom_node)
+            .await
+            .map_err(InvokeError::from_anyhow)?;
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn list_node_peers(
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<Vec<NodePeer>, InvokeError> {
+    state
+        .node_manager
+        .list_peers()
+        .await
+        .map_err(InvokeError::from_anyhow)
+}
+
+#[tauri::command]
+pub async fn ban_node_peer(
+    public_key: String,
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<(), InvokeError> {
+    info!(target: LOG_TARGET, "[ban_node_peer] banning peer {}", public_key);
+    state
+        .node_manager
+        .ban_peer(public_key)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    SetupManager::get_instance()
+        .restart_phases_from_queue(app_handle)
+        .await;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn unban_node_peer(
+    public_key: String,
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<(), InvokeError> {
+    info!(target: LOG_TARGET, "[unban_node_peer] unbanning peer {}", public_key);
+    state
+        .node_manager
+        .unban_peer(&public_key)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    SetupManager::get_instance()
+        .restart_phases_from_queue(app_handle)
+        .await;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_node_seed_peers(
+    seed_peers: Vec<String>,
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<(), InvokeError> {
+    state
+        .node_manager
+        .set_seed_peers(seed_peers)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    SetupManager::get_instance()
+        .restart_phases_from_queue(app_handle)
+        .await;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_local_node_storage(
+    pruned_mode: bool,
+    pruning_horizon: u64,
+    data_dir: Option<PathBuf>,
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<(), InvokeError> {
+    NodeManager::validate_local_node_storage_settings(pruned_mode, pruning_horizon, &data_dir)
+        .map_err(InvokeError::from_anyhow)?;
+    let base_path = app_handle
+        .path()
+        .app_local_data_dir()
+        .map_err(|e| InvokeError::from_anyhow(e.into()))?;
+    let needs_resync = state
+        .node_manager
+        .storage_switch_needs_resync(&base_path, pruned_mode, &data_dir)
+        .await;
+
+    let phases = LOCAL_NODE_SETTINGS_RESTART_PHASES.to_vec();
+    SetupManager::get_instance()
+        .shutdown_phases(phases.clone())
+        .await;
+    if needs_resync {
+        info!(target: LOG_TARGET, "[set_local_node_storage] Switching pruned mode to {}, clearing the existing chain data", pruned_mode);
+        if let Err(e) = state.node_manager.clean_data_folder(&base_path).await {
+            error!(target: LOG_TARGET, "Could not clear local node chain data: {:?}", e);
+        }
+    }
+    let result = state
+        .node_manager
+        .set_local_node_storage_settings(pruned_mode, pruning_horizon, data_dir)
+        .await;
+    SetupManager::get_instance()
+        .resume_phases(app_handle, phases)
+        .await;
+
+    result.map_err(InvokeError::from_anyhow)
+}
+
+#[tauri::command]
+pub async fn is_node_snapshot_bootstrap_enabled() -> Result<bool, InvokeError> {
+    Ok(node_snapshot::is_snapshot_bootstrap_enabled())
+}
+
+/// Only used the next time the local node starts without chain data
+#[tauri::command]
+pub async fn set_local_node_snapshot_mirror(mirror: Option<String>) -> Result<(), InvokeError> {
+    let mirror = mirror
+        .map(|mirror| mirror.trim().to_string())
+        .filter(|mirror| !mirror.is_empty());
+    if let Some(mirror) = &mirror {
+        if !node_snapshot::is_snapshot_bootstrap_enabled() {
+            return Err(InvokeError::from(
+                "Snapshot bootstrapping is disabled in this build".to_string(),
+            ));
+        }
+        node_snapshot::validate_snapshot_mirror(mirror).map_err(InvokeError::from_anyhow)?;
+    }
+    info!(target: LOG_TARGET, "[set_local_node_snapshot_mirror] {:?}", mirror);
+
+    ConfigCore::update_field(ConfigCoreContent::set_local_node_snapshot_mirror, mirror)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_node_dns_seeds(
+    dns_seeds: Vec<String>,
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<(), InvokeError> {
+    state
+        .node_manager
+        .set_dns_seeds(dns_seeds)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    SetupManager::get_instance()
+        .restart_phases_from_queue(app_handle)
+        .await;
+
+    Ok(())
+}
+
+#[tauri::command]
+pub async fn set_node_connection_limits(
+    max_inbound_connections: Option<u32>,
+    max_outbound_connections: Option<u32>,
+    app_handle: tauri::AppHandle,
+    state: tauri::State<'_, UniverseAppState>,
+) -> Result<(), InvokeError> {
+    state
+        .node_manager
+        .set_connection_limits(max_inbound_connections, max_outbound_connections)
+        .await
+        .map_err(InvokeError::from_anyhow)?;
+    SetupManager::get_instance()
+        .restart_phases_from_queue(app_handle)
+        .await;
+
+    Ok(())
+}
+
 #[tauri::command]
 pub async fn set_warmup_seen(warmup_seen: bool) -> Result<(), String> {
     ConfigUI::update_field(ConfigUIContent::set_warmup_seen, warmup_seen)
@@ -23,7 +23,7 @@
 use getset::{Getters, Setters};
 use semver::Version;
 use serde::{Deserialize, Serialize};
-use std::{sync::LazyLock, time::SystemTime};
+use std::{path::PathBuf, sync::LazyLock, time::SystemTime};
 use tari_common::configuration::Network;
 use tauri::AppHandle;
 use tokio::sync::RwLock;
@@ -41,6 +41,35 @@ pub struct AirdropTokens {
     pub refresh_token: String,
 }
 
+/// A user-run minotari_node, used when the node type is `NodeType::Custom`
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(default)]
+pub struct CustomNodeSettings {
+    pub host: String,
+    pub grpc_port: u16,
+}
+
+impl CustomNodeSettings {
+    pub fn grpc_address(&self) -> String {
+        format!("http://{}:{}", self.host, self.grpc_port)
+    }
+}
+
+/// A peer the local node won't dial, it can still connect inbound
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BannedPeer {
+    pub public_key: String,
+    /// Addresses the local node won't dial, as last reported for the peer
+    pub addresses: Vec<String>,
+}
+
+const DEFAULT_CONTROL_API_PORT: u16 = 18290;
+const DEFAULT_LOCAL_NODE_PRUNING_HORIZON: u64 = 100;
+const DEFAULT_LOCAL_NODE_MIN_FREE_DISK_SPACE_GB: u64 = 10;
+const DEFAULT_METRICS_EXPORTER_BIND_ADDRESS: &str = "127.0.0.1:18291";
+// Other machines on the LAN connect to it, so it listens on all interfaces
+const DEFAULT_STRATUM_PROXY_BIND_ADDRESS: &str = "0.0.0.0:18292";
+
 static INSTANCE: LazyLock<RwLock<ConfigCore>> = LazyLock::new(|| RwLock::new(ConfigCore::new()));
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Serialize, Deserialize, Clone)]
@@ -67,8 +96,36 @@ pub struct ConfigCoreContent {
     last_changelog_version: Version,
     airdrop_tokens: Option<AirdropTokens>,
     remote_base_node_address: String,
+    /// Tried when `remote_base_node_address` is slow or unreachable
+    remote_base_node_fallback_addresses: Vec<String>,
     node_type: NodeType,
+    custom_node: Option<CustomNodeSettings>,
+    /// Archival nodes keep the whole chain, pruned ones only the last `local_node_pruning_horizon` blocks
+    local_node_pruned_mode: bool,
+    local_node_pruning_horizon: u64,
+    /// Chain data location, e.g. on a second disk. Defaults to `node` in the app data dir
+    local_node_data_dir: Option<PathBuf>,
+    /// Warn once the volume holding the chain data has less free space than this
+    local_node_min_free_disk_space_gb: u64,
+    /// Mirror serving chain snapshots, a fresh local node is bootstrapped from it when set
+    local_node_snapshot_mirror: Option<String>,
+    local_node_banned_peers: Vec<BannedPeer>,
+    /// Seed peers added to the network defaults, in the `<public key>::<address>` format
+    local_node_seed_peers: Vec<String>,
+    /// DNS seeds added to the network defaults
+    local_node_dns_seeds: Vec<String>,
+    local_node_max_inbound_connections: Option<u32>,
+    local_node_max_outbound_connections: Option<u32>,
     exchange_id: String,
+    control_api_enabled: bool,
+    control_api_port: u16,
+    control_api_token: Option<String>,
+    metrics_exporter_enabled: bool,
+    metrics_exporter_bind_address: String,
+    stratum_proxy_enabled: bool,
+    stratum_proxy_bind_address: String,
+    /// Miners have to send it as their `pass` on login when set
+    stratum_proxy_password: Option<String>,
 }
 
 fn default_monero_nodes() -> Vec<String> {
@@ -121,13 +178,46 @@ impl Default for ConfigCoreContent {
             last_changelog_version: Version::new(0, 0, 0),
             airdrop_tokens: None,
             remote_base_node_address,
+            remote_base_node_fallback_addresses: Vec::new(),
             node_type: NodeType::Local,
+            custom_node: None,
+            local_node_pruned_mode: false,
+            local_node_pruning_horizon: DEFAULT_LOCAL_NODE_PRUNING_HORIZON,
+            local_node_data_dir: None,
+            local_node_min_free_disk_space_gb: DEFAULT_LOCAL_NODE_MIN_FREE_DISK_SPACE_GB,
+            local_node_snapshot_mirror: None,
+            local_node_banned_peers: Vec::new(),
+            local_node_seed_peers: Vec::new(),
+            local_node_dns_seeds: Vec::new(),
+            local_node_max_inbound_connections: None,
+            local_node_max_outbound_connections: None,
             exchange_id: DEFAULT_EXCHANGE_ID.to_string(),
+            control_api_enabled: false,
+            control_api_port: DEFAULT_CONTROL_API_PORT,
+            control_api_token: None,
+            metrics_exporter_enabled: false,
+            metrics_exporter_bind_address: DEFAULT_METRICS_EXPORTER_BIND_ADDRESS.to_string(),
+            stratum_proxy_enabled: false,
+            stratum_proxy_bind_address: DEFAULT_STRATUM_PROXY_BIND_ADDRESS.to_string(),
+            stratum_proxy_password: None,
         }
     }
 }
 impl ConfigContentImpl for ConfigCoreContent {}
 
+impl ConfigCoreContent {
+    /// Remote base nodes to choose from, primary first
+    pub fn remote_base_node_addresses(&self) -> Vec<String> {
+        let mut addresses = vec![self.remote_base_node_address.clone()];
+        for address in &self.remote_base_node_fallback_addresses {
+            if !addresses.contains(address) {
+                addresses.push(address.clone());
+            }
+        }
+        addresses
+    }
+}
+
 pub struct ConfigCore {
     content: ConfigCoreContent,
     app_handle: RwLock<Option<AppHandle>>,
@@ -138,7 +228,11 @@ impl ConfigCore {
         let mut config = Self::current().write().await;
         config.load_app_handle(app_handle.clone()).await;
 
-        EventsEmitter::emit_core_config_loaded(config.content.clone()).await;
+        // The frontend has no use for the control API token or the Stratum proxy password, keep them out of the webview
+        let mut content = config.content.clone();
+        content.set_control_api_token(None);
+        content.set_stratum_proxy_password(None);
+        EventsEmitter::emit_core_config_loaded(content).await;
     }
 }
 
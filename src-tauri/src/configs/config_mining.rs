@@ -20,9 +20,14 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use crate::{events_emitter::EventsEmitter, gpu_miner::EngineType, UniverseAppState};
+use crate::{
+    events_emitter::EventsEmitter, gpu_miner::EngineType, pool_status_watcher::PoolApiType,
+    UniverseAppState,
+};
 use std::{sync::LazyLock, time::SystemTime};
 
+use anyhow::anyhow;
+use chrono::{Datelike, NaiveDateTime, NaiveTime, Weekday};
 use getset::{Getters, Setters};
 use serde::{Deserialize, Serialize};
 use tari_common::configuration::Network;
@@ -55,9 +60,154 @@ impl MiningMode {
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct GpuThreads {
     pub gpu_name: String,
+    /// Configs saved before devices were told apart by index only carry the name
+    #[serde(default)]
+    pub device_index: Option<u32>,
     pub max_gpu_threads: u32,
 }
 
+/// Overrides for a single GPU, devices without an entry follow the global mining settings
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GpuDeviceMiningSettings {
+    pub device_index: u32,
+    /// Lets a card driving a display stay in Eco while headless cards run Ludicrous
+    pub mode: Option<MiningMode>,
+    pub engine: Option<EngineType>,
+}
+
+/// Failover pool, tried after the primary pool url in `priority` order
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MiningPool {
+    /// `host:port` or a stratum URL for CPU pools, gRPC address for GPU pools
+    pub url: String,
+    /// Lower values are tried first
+    pub priority: u32,
+    #[serde(flatten)]
+    pub options: PoolConnectionOptions,
+    /// Stats API polled while this is the active CPU pool, same placeholders as `cpu_mining_pool_status_url`
+    #[serde(default)]
+    pub status_url: Option<String>,
+    #[serde(default)]
+    pub status_api: PoolApiType,
+}
+
+/// Connection settings passed to xmrig along with the pool url
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+#[serde(default)]
+pub struct PoolConnectionOptions {
+    /// Also enabled by a `stratum+ssl://` url
+    pub tls: bool,
+    /// SHA-256 fingerprint of the pool certificate, other certificates are refused when set
+    pub tls_fingerprint: Option<String>,
+    pub worker_name: Option<String>,
+    pub password: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct GpuTemperatureCeiling {
+    pub device_index: u32,
+    pub max_temperature: f32,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum ScheduleDay {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl From<Weekday> for ScheduleDay {
+    fn from(weekday: Weekday) -> Self {
+        match weekday {
+            Weekday::Mon => ScheduleDay::Monday,
+            Weekday::Tue => ScheduleDay::Tuesday,
+            Weekday::Wed => ScheduleDay::Wednesday,
+            Weekday::Thu => ScheduleDay::Thursday,
+            Weekday::Fri => ScheduleDay::Friday,
+            Weekday::Sat => ScheduleDay::Saturday,
+            Weekday::Sun => ScheduleDay::Sunday,
+        }
+    }
+}
+
+/// What to do with mining while the machine runs on battery
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum BatteryMiningAction {
+    None,
+    PauseGpuMining,
+    ForceEcoMode,
+    StopMining,
+}
+
+impl BatteryMiningAction {
+    pub fn from_str(s: &str) -> Option<BatteryMiningAction> {
+        match s {
+            "None" => Some(BatteryMiningAction::None),
+            "PauseGpuMining" => Some(BatteryMiningAction::PauseGpuMining),
+            "ForceEcoMode" => Some(BatteryMiningAction::ForceEcoMode),
+            "StopMining" => Some(BatteryMiningAction::StopMining),
+            _ => None,
+        }
+    }
+}
+
+const SCHEDULE_TIME_FORMAT: &str = "%H:%M";
+const DEFAULT_IDLE_MINING_TIMEOUT_SECS: u64 = 5 * 60;
+const DEFAULT_THERMAL_HYSTERESIS: f32 = 5.0;
+const DEFAULT_MINING_HISTORY_RETENTION_DAYS: u64 = 365;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct MiningScheduleRule {
+    pub days: Vec<ScheduleDay>,
+    /// Local time in HH:MM format
+    pub start_time: String,
+    /// Local time in HH:MM format, a time before `start_time` makes the window run past midnight
+    pub end_time: String,
+    pub cpu_mining: bool,
+    pub gpu_mining: bool,
+    pub mode: MiningMode,
+}
+
+impl MiningScheduleRule {
+    fn parse_times(&self) -> Result<(NaiveTime, NaiveTime), anyhow::Error> {
+        let start = NaiveTime::parse_from_str(&self.start_time, SCHEDULE_TIME_FORMAT)
+            .map_err(|e| anyhow!("Invalid start time {}: {}", self.start_time, e))?;
+        let end = NaiveTime::parse_from_str(&self.end_time, SCHEDULE_TIME_FORMAT)
+            .map_err(|e| anyhow!("Invalid end time {}: {}", self.end_time, e))?;
+        Ok((start, end))
+    }
+
+    pub fn validate(&self) -> Result<(), anyhow::Error> {
+        if self.days.is_empty() {
+            return Err(anyhow!("Schedule rule has no days selected"));
+        }
+        self.parse_times().map(|_| ())
+    }
+
+    /// Equal start and end times cover the whole day. Windows running past midnight belong to the day they start on.
+    pub fn is_active_at(&self, now: NaiveDateTime) -> bool {
+        let Ok((start, end)) = self.parse_times() else {
+            return false;
+        };
+        let time = now.time();
+        let today = ScheduleDay::from(now.weekday());
+        let yesterday = ScheduleDay::from(now.weekday().pred());
+
+        if start == end {
+            self.days.contains(&today)
+        } else if start < end {
+            self.days.contains(&today) && start <= time && time < end
+        } else {
+            (self.days.contains(&today) && time >= start)
+                || (self.days.contains(&yesterday) && time < end)
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone)]
 #[serde(rename_all = "snake_case")]
 #[serde(default)]
@@ -85,9 +235,27 @@ pub struct ConfigMiningContent {
     gpu_engine: EngineType,
     squad_override: Option<String>,
     cpu_mining_pool_url: Option<String>,
+    cpu_mining_pool_options: PoolConnectionOptions,
     cpu_mining_pool_status_url: Option<String>,
+    cpu_mining_pool_api: PoolApiType,
     gpu_mining_pool_url: Option<String>,
+    cpu_mining_pools: Vec<MiningPool>,
+    gpu_mining_pools: Vec<MiningPool>,
     mining_time: u128,
+    mining_schedule_enabled: bool,
+    mining_schedule: Vec<MiningScheduleRule>,
+    idle_mining_enabled: bool,
+    idle_mining_timeout_secs: u64,
+    idle_mining_mode: MiningMode,
+    thermal_governor_enabled: bool,
+    cpu_max_temperature: Option<f32>,
+    gpu_max_temperatures: Vec<GpuTemperatureCeiling>,
+    gpu_device_settings: Vec<GpuDeviceMiningSettings>,
+    thermal_hysteresis: f32,
+    battery_policy_action: BatteryMiningAction,
+    /// Battery percentage below which the action applies, `None` applies it whenever on battery
+    battery_policy_threshold: Option<u8>,
+    mining_history_retention_days: u64,
 }
 
 impl Default for ConfigMiningContent {
@@ -113,14 +281,82 @@ impl Default for ConfigMiningContent {
             gpu_engine: EngineType::OpenCL,
             squad_override: None,
             cpu_mining_pool_url: default_cpu_mining_pool_url(),
+            cpu_mining_pool_options: PoolConnectionOptions::default(),
             cpu_mining_pool_status_url: default_cpu_mining_pool_status_url(),
+            cpu_mining_pool_api: PoolApiType::default(),
             gpu_mining_pool_url: None,
+            cpu_mining_pools: vec![],
+            gpu_mining_pools: vec![],
             mining_time: 0,
+            mining_schedule_enabled: false,
+            mining_schedule: vec![],
+            idle_mining_enabled: false,
+            idle_mining_timeout_secs: DEFAULT_IDLE_MINING_TIMEOUT_SECS,
+            idle_mining_mode: MiningMode::Ludicrous,
+            thermal_governor_enabled: false,
+            cpu_max_temperature: None,
+            gpu_max_temperatures: vec![],
+            gpu_device_settings: vec![],
+            thermal_hysteresis: DEFAULT_THERMAL_HYSTERESIS,
+            battery_policy_action: BatteryMiningAction::None,
+            battery_policy_threshold: None,
+            mining_history_retention_days: DEFAULT_MINING_HISTORY_RETENTION_DAYS,
         }
     }
 }
 impl ConfigContentImpl for ConfigMiningContent {}
 
+impl ConfigMiningContent {
+    /// CPU threads configured for the current mode
+    pub fn max_cpu_usage(&self) -> Option<u32> {
+        match self.mode {
+            MiningMode::Custom => self.custom_max_cpu_usage,
+            MiningMode::Eco => self.eco_mode_max_cpu_usage,
+            MiningMode::Ludicrous => self.ludicrous_mode_max_cpu_usage,
+        }
+    }
+
+    /// Pools xmrig can connect to, primary first
+    pub fn cpu_pools(&self) -> Vec<MiningPool> {
+        let primary = self.cpu_mining_pool_url.as_ref().map(|url| MiningPool {
+            url: url.clone(),
+            priority: 0,
+            options: self.cpu_mining_pool_options.clone(),
+            status_url: self.cpu_mining_pool_status_url.clone(),
+            status_api: self.cpu_mining_pool_api,
+        });
+        ordered_pools(primary, &self.cpu_mining_pools)
+    }
+
+    /// Pools glytex can take templates from, primary first
+    pub fn gpu_pool_urls(&self) -> Vec<String> {
+        let primary = self.gpu_mining_pool_url.as_ref().map(|url| MiningPool {
+            url: url.clone(),
+            priority: 0,
+            options: PoolConnectionOptions::default(),
+            status_url: None,
+            status_api: PoolApiType::default(),
+        });
+        ordered_pools(primary, &self.gpu_mining_pools)
+            .into_iter()
+            .map(|pool| pool.url)
+            .collect()
+    }
+}
+
+fn ordered_pools(primary: Option<MiningPool>, pools: &[MiningPool]) -> Vec<MiningPool> {
+    let mut pools = pools.to_vec();
+    pools.sort_by_key(|pool| pool.priority);
+
+    let mut ordered: Vec<MiningPool> = primary.into_iter().collect();
+    for pool in pools {
+        if !ordered.iter().any(|existing| existing.url == pool.url) {
+            ordered.push(pool);
+        }
+    }
+    ordered
+}
+
 fn default_cpu_mining_pool_url() -> Option<String> {
     match Network::get_current_or_user_setting_or_default() {
         Network::MainNet => Some("pool-global.tari.snipanet.com:3333".to_string()),
@@ -198,3 +434,76 @@ impl ConfigImpl for ConfigMining {
         &mut self.content
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn rule(days: Vec<ScheduleDay>, start_time: &str, end_time: &str) -> MiningScheduleRule {
+        MiningScheduleRule {
+            days,
+            start_time: start_time.to_string(),
+            end_time: end_time.to_string(),
+            cpu_mining: true,
+            gpu_mining: true,
+            mode: MiningMode::Eco,
+        }
+    }
+
+    // 2025-01-06 is a Monday
+    fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 1, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_schedule_rule_same_day_window() {
+        let rule = rule(vec![ScheduleDay::Monday], "09:00", "17:30");
+        assert!(rule.is_active_at(at(6, 9, 0)));
+        assert!(rule.is_active_at(at(6, 17, 29)));
+        assert!(!rule.is_active_at(at(6, 17, 30)));
+        assert!(!rule.is_active_at(at(6, 8, 59)));
+        assert!(!rule.is_active_at(at(7, 12, 0)));
+    }
+
+    #[test]
+    fn test_schedule_rule_window_past_midnight() {
+        let rule = rule(vec![ScheduleDay::Friday], "22:00", "06:00");
+        assert!(rule.is_active_at(at(10, 23, 0)));
+        assert!(rule.is_active_at(at(11, 5, 59)));
+        assert!(!rule.is_active_at(at(11, 6, 0)));
+        assert!(!rule.is_active_at(at(10, 5, 0)));
+        assert!(!rule.is_active_at(at(11, 23, 0)));
+    }
+
+    #[test]
+    fn test_schedule_rule_whole_day() {
+        let rule = rule(
+            vec![ScheduleDay::Saturday, ScheduleDay::Sunday],
+            "00:00",
+            "00:00",
+        );
+        assert!(rule.is_active_at(at(11, 0, 0)));
+        assert!(rule.is_active_at(at(12, 23, 59)));
+        assert!(!rule.is_active_at(at(13, 0, 0)));
+    }
+
+    #[test]
+    fn test_schedule_rule_validation() {
+        assert!(rule(vec![ScheduleDay::Monday], "09:00", "17:00")
+            .validate()
+            .is_ok());
+        assert!(rule(vec![], "09:00", "17:00").validate().is_err());
+        assert!(rule(vec![ScheduleDay::Monday], "9am", "17:00")
+            .validate()
+            .is_err());
+        assert!(rule(vec![ScheduleDay::Monday], "09:00", "24:00")
+            .validate()
+            .is_err());
+    }
+}
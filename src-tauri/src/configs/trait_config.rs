@@ -127,11 +127,7 @@ pub trait ConfigImpl {
         Self: 'static,
     {
         debug!(target: LOG_TARGET, "[{}] [update_field] with function: {:?} and value: {:?}", Self::_get_name(), std::any::type_name::<F>(), value);
-        setter_callback(
-            Self::current().write().await._get_content_mut(),
-            value.clone(),
-        );
-        Self::_save_config(Self::current().read().await._get_content().clone()).inspect_err(|error|
+        Self::_write_field(setter_callback, value.clone()).await.inspect_err(|error|
             debug!(target: LOG_TARGET, "[{}] [update_field] error: {:?}", Self::_get_name(), error)
         )?;
         Self::current()
@@ -148,6 +144,26 @@ pub trait ConfigImpl {
             .await;
         Ok(())
     }
+    /// Same as `update_field` but the value is neither logged nor sent to telemetry, meant for tokens and passwords
+    async fn update_secret_field<F, I>(setter_callback: F, value: I) -> Result<(), Error>
+    where
+        F: FnOnce(&mut Self::Config, I) -> &mut Self::Config,
+        Self: 'static,
+    {
+        debug!(target: LOG_TARGET, "[{}] [update_secret_field] with function: {:?}", Self::_get_name(), std::any::type_name::<F>());
+        Self::_write_field(setter_callback, value).await.inspect_err(|error|
+            debug!(target: LOG_TARGET, "[{}] [update_secret_field] error: {:?}", Self::_get_name(), error)
+        )?;
+        Ok(())
+    }
+    async fn _write_field<F, I>(setter_callback: F, value: I) -> Result<(), Error>
+    where
+        F: FnOnce(&mut Self::Config, I) -> &mut Self::Config,
+        Self: 'static,
+    {
+        setter_callback(Self::current().write().await._get_content_mut(), value);
+        Self::_save_config(Self::current().read().await._get_content().clone())
+    }
     async fn update_field_requires_restart<F, I>(
         setter_callback: F,
         value: I,
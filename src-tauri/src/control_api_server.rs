@@ -0,0 +1,260 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::net::SocketAddr;
+
+use axum::{
+    extract::{Path, Query, Request, State},
+    http::{header::AUTHORIZATION, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use log::{error, info, warn};
+use serde::Deserialize;
+use tauri::{AppHandle, Manager};
+
+use crate::{
+    commands,
+    configs::{
+        config_core::{ConfigCore, ConfigCoreContent},
+        config_mining::GpuThreads,
+        trait_config::ConfigImpl,
+    },
+    node::node_adapter::BaseNodeStatus,
+    p2pool::models::P2poolStats,
+    tasks_tracker::TasksTrackers,
+    wallet_adapter::TransactionInfo,
+    UniverseAppState,
+};
+
+const LOG_TARGET: &str = "tari::universe::control_api_server";
+
+type ControlApiResult<T> = Result<Json<T>, (StatusCode, String)>;
+
+#[derive(Clone)]
+struct ControlApiState {
+    app_handle: AppHandle,
+    token: String,
+}
+
+#[derive(Deserialize)]
+struct SetModeRequest {
+    mode: String,
+    custom_cpu_usage: Option<u32>,
+    #[serde(default)]
+    custom_gpu_usage: Vec<GpuThreads>,
+}
+
+#[derive(Deserialize)]
+struct DeviceExclusionRequest {
+    excluded: bool,
+}
+
+#[derive(Deserialize)]
+struct TransactionsQuery {
+    offset: Option<u32>,
+    limit: Option<u32>,
+    status_bitflag: Option<u32>,
+}
+
+pub struct ControlApiServer;
+
+impl ControlApiServer {
+    /// Serves the control API on localhost when it is enabled in the core config.
+    /// The server lives until the common tasks are shut down.
+    pub async fn start(app_handle: AppHandle) -> Result<(), anyhow::Error> {
+        if !*ConfigCore::content().await.control_api_enabled() {
+            info!(target: LOG_TARGET, "Control API is disabled");
+            return Ok(());
+        }
+
+        let port = *ConfigCore::content().await.control_api_port();
+        let token = match ConfigCore::content().await.control_api_token().clone() {
+            Some(token) => token,
+            None => {
+                let token = hex::encode(rand::random::<[u8; 32]>());
+                ConfigCore::update_secret_field(
+                    ConfigCoreContent::set_control_api_token,
+                    Some(token.clone()),
+                )
+                .await?;
+                info!(target: LOG_TARGET, "Generated a new control API token, it is stored in the core config");
+                token
+            }
+        };
+
+        let router = Self::router(ControlApiState { app_handle, token });
+        let addr = SocketAddr::from(([127, 0, 0, 1], port));
+        let listener = tokio::net::TcpListener::bind(addr).await?;
+        info!(target: LOG_TARGET, "Control API listening on {}", addr);
+
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let _unused = axum::serve(listener, router)
+                    .with_graceful_shutdown(async move { shutdown_signal.wait().await })
+                    .await
+                    .inspect_err(
+                        |e| error!(target: LOG_TARGET, "Control API server error: {:?}", e),
+                    );
+                info!(target: LOG_TARGET, "Control API stopped");
+            });
+
+        Ok(())
+    }
+
+    fn router(state: ControlApiState) -> Router {
+        Router::new()
+            .route("/cpu-mining/start", post(start_cpu_mining))
+            .route("/cpu-mining/stop", post(stop_cpu_mining))
+            .route("/gpu-mining/start", post(start_gpu_mining))
+            .route("/gpu-mining/stop", post(stop_gpu_mining))
+            .route("/mode", post(set_mode))
+            .route(
+                "/gpu-devices/:device_index/exclusion",
+                post(toggle_device_exclusion),
+            )
+            .route("/p2pool/stats", get(get_p2pool_stats))
+            .route("/transactions", get(get_transactions))
+            .route("/base-node/status", get(get_base_node_status))
+            .route_layer(middleware::from_fn_with_state(state.clone(), authorize))
+            .with_state(state)
+    }
+}
+
+async fn authorize(State(state): State<ControlApiState>, request: Request, next: Next) -> Response {
+    let is_authorized = request
+        .headers()
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .is_some_and(|token| constant_time_eq(token.as_bytes(), state.token.as_bytes()));
+
+    if !is_authorized {
+        warn!(target: LOG_TARGET, "Rejected unauthorized request to {}", request.uri());
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+    next.run(request).await
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+fn command_error(error: String) -> (StatusCode, String) {
+    (StatusCode::INTERNAL_SERVER_ERROR, error)
+}
+
+async fn start_cpu_mining(State(state): State<ControlApiState>) -> ControlApiResult<()> {
+    let app_state = state.app_handle.state::<UniverseAppState>();
+    commands::start_cpu_mining(app_state, state.app_handle.clone())
+        .await
+        .map_err(command_error)?;
+    Ok(Json(()))
+}
+
+async fn stop_cpu_mining(State(state): State<ControlApiState>) -> ControlApiResult<()> {
+    commands::stop_cpu_mining(state.app_handle.state::<UniverseAppState>())
+        .await
+        .map_err(command_error)?;
+    Ok(Json(()))
+}
+
+async fn start_gpu_mining(State(state): State<ControlApiState>) -> ControlApiResult<()> {
+    let app_state = state.app_handle.state::<UniverseAppState>();
+    commands::start_gpu_mining(app_state, state.app_handle.clone())
+        .await
+        .map_err(command_error)?;
+    Ok(Json(()))
+}
+
+async fn stop_gpu_mining(State(state): State<ControlApiState>) -> ControlApiResult<()> {
+    commands::stop_gpu_mining(state.app_handle.state::<UniverseAppState>())
+        .await
+        .map_err(command_error)?;
+    Ok(Json(()))
+}
+
+async fn set_mode(Json(request): Json<SetModeRequest>) -> ControlApiResult<()> {
+    commands::set_mode(
+        request.mode,
+        request.custom_cpu_usage,
+        request.custom_gpu_usage,
+    )
+    .await
+    .map_err(|e| (StatusCode::BAD_REQUEST, e.0.to_string()))?;
+    Ok(Json(()))
+}
+
+async fn toggle_device_exclusion(
+    State(state): State<ControlApiState>,
+    Path(device_index): Path<u32>,
+    Json(request): Json<DeviceExclusionRequest>,
+) -> ControlApiResult<()> {
+    commands::toggle_device_exclusion(
+        device_index,
+        request.excluded,
+        state.app_handle.clone(),
+        state.app_handle.state::<UniverseAppState>(),
+    )
+    .await
+    .map_err(command_error)?;
+    Ok(Json(()))
+}
+
+async fn get_p2pool_stats(
+    State(state): State<ControlApiState>,
+) -> ControlApiResult<Option<P2poolStats>> {
+    commands::get_p2pool_stats(state.app_handle.state::<UniverseAppState>())
+        .await
+        .map(Json)
+        .map_err(command_error)
+}
+
+async fn get_transactions(
+    State(state): State<ControlApiState>,
+    Query(query): Query<TransactionsQuery>,
+) -> ControlApiResult<Vec<TransactionInfo>> {
+    commands::get_transactions(
+        state.app_handle.state::<UniverseAppState>(),
+        query.offset,
+        query.limit,
+        query.status_bitflag,
+    )
+    .await
+    .map(Json)
+    .map_err(command_error)
+}
+
+async fn get_base_node_status(
+    State(state): State<ControlApiState>,
+) -> ControlApiResult<BaseNodeStatus> {
+    commands::get_base_node_status(state.app_handle.state::<UniverseAppState>())
+        .await
+        .map(Json)
+        .map_err(command_error)
+}
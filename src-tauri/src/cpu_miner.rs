@@ -21,17 +21,26 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use crate::binaries::Binaries;
-use crate::commands::{CpuMinerConnection, CpuMinerConnectionStatus, CpuMinerStatus};
-use crate::configs::config_mining::{ConfigMiningContent, MiningMode};
+use crate::commands::{
+    CpuMinerConnection, CpuMinerConnectionStatus, CpuMinerDiagnostics, CpuMinerStatus,
+    CpuThreadStatus,
+};
+use crate::configs::config_mining::{
+    ConfigMiningContent, MiningMode, MiningPool, PoolConnectionOptions,
+};
 use crate::configs::config_wallet::ConfigWalletContent;
 use crate::events_emitter::EventsEmitter;
-use crate::pool_status_watcher::SupportXmrStyleAdapter;
+use crate::hardware::thermal_governor::ThermalGovernor;
+use crate::pool_status_watcher::{ConfiguredPoolApiAdapter, PoolApiType};
 use crate::process_stats_collector::ProcessStatsCollectorBuilder;
-use crate::process_watcher::ProcessWatcher;
+use crate::process_watcher::{ProcessWatcher, RestartPolicy};
 use crate::tasks_tracker::TasksTrackers;
 use crate::utils::math_utils::estimate_earning;
-use crate::xmrig::http_api::models::Summary;
-use crate::xmrig_adapter::{XmrigAdapter, XmrigNodeConnection};
+use crate::utils::pool_url_utils::{pool_address, PoolUrl};
+use crate::xmrig::http_api::models::{error_log_message, Backend, Summary};
+use crate::xmrig_adapter::{
+    apply_cpu_threads, XmrigAdapter, XmrigNodeConnection, XmrigStatusMonitor,
+};
 use crate::{mm_proxy_manager, BaseNodeStatus, PoolStatusWatcher};
 use log::{debug, error, info, warn};
 use std::path::PathBuf;
@@ -58,7 +67,12 @@ pub struct CpuMinerConfig {
     pub monero_address: String,
     pub pool_host_name: Option<String>,
     pub pool_port: Option<u16>,
+    pub pool_options: PoolConnectionOptions,
+    /// Primary pool followed by the failover pools
+    pub pools: Vec<MiningPool>,
+    pub active_pool_index: usize,
     pub pool_status_url: Option<String>,
+    pub pool_api: PoolApiType,
 }
 
 impl CpuMinerConfig {
@@ -69,24 +83,35 @@ impl CpuMinerConfig {
         self.eco_mode_xmrig_options = config_mining_content.eco_mode_cpu_options().clone();
         self.ludicrous_mode_xmrig_options =
             config_mining_content.ludicrous_mode_cpu_options().clone();
-        if let Some(ref pool_url) = config_mining_content.cpu_mining_pool_url() {
-            let parts = pool_url.split(':').collect::<Vec<_>>();
-            if parts.len() == 2 {
-                if let Ok(port) = parts[1].parse::<u16>() {
-                    self.pool_port = Some(port);
-                } else {
-                    error!(target: LOG_TARGET, "Invalid port number in pool URL: {}", pool_url);
-                }
-                self.pool_host_name = Some(parts[0].to_string());
-            } else {
-                error!(target: LOG_TARGET, "Invalid pool URL format: {}", pool_url);
-            }
-            self.node_connection = CpuMinerConnection::Pool;
-        } else {
+        self.pools = config_mining_content.cpu_pools();
+        self.select_pool(0);
+    }
+
+    /// Points the miner and the pool status watcher at `pools[index]`, takes effect on the next start
+    pub fn select_pool(&mut self, index: usize) {
+        self.active_pool_index = index;
+        let Some(pool) = self.pools.get(index) else {
             self.pool_host_name = None;
+            self.pool_status_url = None;
+            return;
+        };
+        self.pool_status_url = pool.status_url.clone();
+        self.pool_api = pool.status_api;
+        match PoolUrl::parse(&pool.url) {
+            Ok(pool_url) => {
+                self.pool_host_name = Some(pool_url.host);
+                self.pool_port = Some(pool_url.port);
+                self.pool_options = PoolConnectionOptions {
+                    tls: pool.options.tls || pool_url.tls,
+                    ..pool.options.clone()
+                };
+            }
+            Err(e) => {
+                error!(target: LOG_TARGET, "Invalid pool URL: {}", e);
+                self.pool_host_name = None;
+            }
         }
-
-        self.pool_status_url = config_mining_content.cpu_mining_pool_status_url().clone();
+        self.node_connection = CpuMinerConnection::Pool;
     }
 
     pub fn load_from_config_wallet(&mut self, config_wallet_content: &ConfigWalletContent) {
@@ -98,9 +123,11 @@ pub(crate) struct CpuMiner {
     watcher: Arc<RwLock<ProcessWatcher<XmrigAdapter>>>,
     cpu_miner_status_watch_tx: watch::Sender<CpuMinerStatus>,
     summary_watch_rx: watch::Receiver<Option<Summary>>,
+    backends_watch_rx: watch::Receiver<Vec<Backend>>,
     node_status_watch_rx: watch::Receiver<BaseNodeStatus>,
     pub benchmarked_hashrate: u64,
-    pool_status_watcher: Option<PoolStatusWatcher<SupportXmrStyleAdapter>>,
+    pool_status_watcher: Option<PoolStatusWatcher<ConfiguredPoolApiAdapter>>,
+    active_pool: Option<String>,
     pub pool_status_shutdown_signal: Shutdown,
 }
 
@@ -111,21 +138,32 @@ impl CpuMiner {
         node_status_watch_rx: watch::Receiver<BaseNodeStatus>,
     ) -> Self {
         let (summary_watch_tx, summary_watch_rx) = watch::channel::<Option<Summary>>(None);
-        let xmrig_adapter = XmrigAdapter::new(summary_watch_tx);
-        let process_watcher = ProcessWatcher::new(xmrig_adapter, stats_collector.take_cpu_miner());
+        let (backends_watch_tx, backends_watch_rx) = watch::channel::<Vec<Backend>>(vec![]);
+        let xmrig_adapter = XmrigAdapter::new(summary_watch_tx, backends_watch_tx);
+        let mut process_watcher =
+            ProcessWatcher::new(xmrig_adapter, stats_collector.take_cpu_miner());
+        // xmrig rebuilds its RandomX dataset on every start, so give it more room between restarts
+        process_watcher.restart_policy = RestartPolicy {
+            max_restarts: 4,
+            restart_window: Duration::from_secs(15 * 60),
+            initial_backoff: Duration::from_secs(5),
+            max_backoff: Duration::from_secs(2 * 60),
+            ..RestartPolicy::default()
+        };
         Self {
             watcher: Arc::new(RwLock::new(process_watcher)),
             cpu_miner_status_watch_tx,
             summary_watch_rx,
+            backends_watch_rx,
             node_status_watch_rx,
             benchmarked_hashrate: 0,
             pool_status_watcher: None,
+            active_pool: None,
             pool_status_shutdown_signal: Shutdown::new(),
         }
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[allow(clippy::too_many_lines)]
     pub async fn start(
         &mut self,
         app_shutdown: ShutdownSignal,
@@ -140,7 +178,107 @@ impl CpuMiner {
     ) -> Result<(), anyhow::Error> {
         self.pool_status_shutdown_signal = Shutdown::new();
 
-        let (xmrig_node_connection, pool_watcher) = match cpu_miner_config.node_connection {
+        let (xmrig_node_connection, pool_watcher) =
+            Self::node_connection(cpu_miner_config, mm_proxy_manager, tari_address).await?;
+        self.pool_status_watcher = pool_watcher;
+        self.active_pool = Self::active_pool(&xmrig_node_connection);
+        let cpu_max_percentage =
+            Self::cpu_threads(cpu_miner_config, mode, custom_cpu_threads).await;
+        {
+            let mut lock = self.watcher.write().await;
+
+            lock.adapter.node_connection = Some(xmrig_node_connection);
+            lock.adapter.cpu_threads = Some(cpu_max_percentage);
+            lock.adapter.extra_options = Self::extra_options(cpu_miner_config, mode);
+
+            let shutdown_signal = TasksTrackers::current().hardware_phase.get_signal().await;
+            let task_tracker = TasksTrackers::current()
+                .hardware_phase
+                .get_task_tracker()
+                .await;
+
+            lock.start(
+                base_path.clone(),
+                config_path.clone(),
+                log_dir.clone(),
+                Binaries::Xmrig,
+                shutdown_signal,
+                task_tracker,
+            )
+            .await?;
+        }
+
+        self.initialize_status_updates(app_shutdown).await;
+
+        Ok(())
+    }
+
+    /// Applies mode, thread and pool changes to the running xmrig through its HTTP API so the RandomX dataset
+    /// stays warm. Returns `false` when the change needs a restart instead.
+    pub async fn reconfigure(
+        &mut self,
+        cpu_miner_config: &CpuMinerConfig,
+        mm_proxy_manager: &mm_proxy_manager::MmProxyManager,
+        mode: MiningMode,
+        custom_cpu_threads: Option<u32>,
+        tari_address: &TariAddress,
+    ) -> Result<bool, anyhow::Error> {
+        let (xmrig_node_connection, pool_watcher) =
+            Self::node_connection(cpu_miner_config, mm_proxy_manager, tari_address).await?;
+        let cpu_threads = Self::cpu_threads(cpu_miner_config, mode, custom_cpu_threads).await;
+
+        let mut lock = self.watcher.write().await;
+        let client = match lock.status_monitor.as_ref() {
+            Some(status_monitor) if lock.is_running() => status_monitor.client().clone(),
+            _ => return Ok(false),
+        };
+        // Extra options only exist as command line arguments and benchmark runs are one-off processes
+        if lock.adapter.extra_options != Self::extra_options(cpu_miner_config, mode)
+            || matches!(
+                lock.adapter.node_connection,
+                None | Some(XmrigNodeConnection::Benchmark)
+            )
+        {
+            return Ok(false);
+        }
+
+        let mut config = client.config().await?;
+        xmrig_node_connection.apply_to_config(&mut config)?;
+        apply_cpu_threads(&mut config, cpu_threads)?;
+        client.pause().await?;
+        let result = client.update_config(&config).await;
+        // xmrig keeps the previous config when the update is rejected, so resume either way
+        client.resume().await?;
+        result?;
+
+        // Restarts after a crash have to pick up the new settings as well
+        self.active_pool = Self::active_pool(&xmrig_node_connection);
+        lock.adapter.node_connection = Some(xmrig_node_connection);
+        lock.adapter.cpu_threads = Some(cpu_threads);
+        drop(lock);
+        info!(target: LOG_TARGET, "Reconfigured xmrig for {:?} mode with {:?} threads", mode, cpu_threads);
+
+        // The status loop holds on to the pool status watcher, restart it for the new pool
+        self.pool_status_watcher = pool_watcher;
+        self.pool_status_shutdown_signal.trigger();
+        self.pool_status_shutdown_signal = Shutdown::new();
+        self.initialize_status_updates(TasksTrackers::current().hardware_phase.get_signal().await)
+            .await;
+        Ok(true)
+    }
+
+    async fn node_connection(
+        cpu_miner_config: &CpuMinerConfig,
+        mm_proxy_manager: &mm_proxy_manager::MmProxyManager,
+        tari_address: &TariAddress,
+    ) -> Result<
+        (
+            XmrigNodeConnection,
+            Option<PoolStatusWatcher<ConfiguredPoolApiAdapter>>,
+        ),
+        anyhow::Error,
+    > {
+        let connection = match cpu_miner_config.node_connection {
             CpuMinerConnection::BuiltInProxy => (
                 XmrigNodeConnection::LocalMmproxy {
                     host_name: "127.0.0.1".to_string(),
@@ -164,7 +302,7 @@ impl CpuMiner {
                     PoolStatusWatcher::new(
                         url.replace("%MONERO_ADDRESS%", &cpu_miner_config.monero_address)
                             .replace("%TARI_ADDRESS%", &tari_address.to_base58()),
-                        SupportXmrStyleAdapter {},
+                        ConfiguredPoolApiAdapter::from(cpu_miner_config.pool_api),
                     )
                 });
 
@@ -173,6 +311,7 @@ impl CpuMiner {
                         host_name: pool_address,
                         port,
                         tari_address: tari_address.to_base58(),
+                        options: cpu_miner_config.pool_options.clone(),
                     },
                     status_watch,
                 )
@@ -191,7 +330,7 @@ impl CpuMiner {
                     PoolStatusWatcher::new(
                         url.replace("%MONERO_ADDRESS%", &cpu_miner_config.monero_address)
                             .replace("%TARI_ADDRESS%", &tari_address.to_base58()),
-                        SupportXmrStyleAdapter {},
+                        ConfiguredPoolApiAdapter::from(cpu_miner_config.pool_api),
                     )
                 });
 
@@ -201,12 +340,37 @@ impl CpuMiner {
                         port,
                         monero_address: cpu_miner_config.monero_address.clone(),
                         tari_address: tari_address.to_base58(),
+                        options: cpu_miner_config.pool_options.clone(),
                     },
                     status_watch,
                 )
             }
         };
-        self.pool_status_watcher = pool_watcher;
+        Ok(connection)
+    }
+
+    fn active_pool(xmrig_node_connection: &XmrigNodeConnection) -> Option<String> {
+        match *xmrig_node_connection {
+            XmrigNodeConnection::Pool {
+                ref host_name,
+                port,
+                ..
+            }
+            | XmrigNodeConnection::MergeMinedPool {
+                ref host_name,
+                port,
+                ..
+            } => Some(pool_address(host_name, port)),
+            _ => None,
+        }
+    }
+
+    /// Number of xmrig threads for the given mode, `None` lets xmrig use every core
+    async fn cpu_threads(
+        cpu_miner_config: &CpuMinerConfig,
+        mode: MiningMode,
+        custom_cpu_threads: Option<u32>,
+    ) -> Option<u32> {
         let max_cpu_available = thread::available_parallelism();
         let max_cpu_available = match max_cpu_available {
             Ok(available_cpus) => {
@@ -234,100 +398,119 @@ impl CpuMiner {
             }
             MiningMode::Ludicrous => None,
         };
-        {
-            let mut lock = self.watcher.write().await;
+        let cpu_load_percentage = ThermalGovernor::current().cpu_load_percentage().await;
+        if cpu_load_percentage < 100 {
+            let threads = cpu_max_percentage.unwrap_or(max_cpu_available);
+            Some(ThermalGovernor::scale(threads, cpu_load_percentage))
+        } else {
+            cpu_max_percentage
+        }
+    }
 
-            lock.adapter.node_connection = Some(xmrig_node_connection);
-            lock.adapter.cpu_threads = Some(cpu_max_percentage);
-            lock.adapter.extra_options = match mode {
-                MiningMode::Eco => cpu_miner_config.eco_mode_xmrig_options.clone(),
-                MiningMode::Ludicrous => cpu_miner_config.ludicrous_mode_xmrig_options.clone(),
-                MiningMode::Custom => cpu_miner_config.custom_mode_xmrig_options.clone(),
-            };
+    fn extra_options(cpu_miner_config: &CpuMinerConfig, mode: MiningMode) -> Vec<String> {
+        match mode {
+            MiningMode::Eco => cpu_miner_config.eco_mode_xmrig_options.clone(),
+            MiningMode::Ludicrous => cpu_miner_config.ludicrous_mode_xmrig_options.clone(),
+            MiningMode::Custom => cpu_miner_config.custom_mode_xmrig_options.clone(),
+        }
+    }
 
-            let shutdown_signal = TasksTrackers::current().hardware_phase.get_signal().await;
-            let task_tracker = TasksTrackers::current()
-                .hardware_phase
-                .get_task_tracker()
-                .await;
+    pub async fn start_benchmarking(
+        &mut self,
+        duration: Duration,
+        base_path: PathBuf,
+        config_path: PathBuf,
+        log_dir: PathBuf,
+    ) -> Result<(), anyhow::Error> {
+        let max_cpu_available = thread::available_parallelism();
+        let max_cpu_available = match max_cpu_available {
+            Ok(available_cpus) => u32::try_from(available_cpus.get()).unwrap_or(1),
+            Err(_) => 1,
+        };
 
-            lock.start(
-                base_path.clone(),
-                config_path.clone(),
-                log_dir.clone(),
-                Binaries::Xmrig,
-                shutdown_signal,
-                task_tracker,
-            )
+        let hash_rate = self
+            .benchmark_threads(1, duration, base_path, config_path, log_dir)
             .await?;
-        }
-
-        self.initialize_status_updates(app_shutdown).await;
 
+        #[allow(clippy::cast_possible_truncation)]
+        let single_thread_hashrate = hash_rate.floor() as u64;
+        self.benchmarked_hashrate = single_thread_hashrate * u64::from(max_cpu_available);
         Ok(())
     }
 
-    pub async fn start_benchmarking(
+    /// Runs xmrig in benchmark mode with the given number of threads and returns the highest hashrate seen
+    pub async fn benchmark_threads(
         &mut self,
+        cpu_threads: u32,
         duration: Duration,
         base_path: PathBuf,
         config_path: PathBuf,
         log_dir: PathBuf,
-    ) -> Result<(), anyhow::Error> {
+    ) -> Result<f64, anyhow::Error> {
+        let hash_rate = match self
+            .start_benchmark_run(cpu_threads, base_path, config_path, log_dir)
+            .await?
+        {
+            Some(status) => Self::sample_benchmark_hashrate(status, duration).await,
+            None => 0.0,
+        };
+
+        // Stop the miner
+        self.stop().await?;
+
+        Ok(hash_rate)
+    }
+
+    /// Starts xmrig in benchmark mode and returns its status monitor once the HTTP API is up.
+    /// The caller samples it with [`CpuMiner::sample_benchmark_hashrate`] and stops the miner afterwards
+    pub async fn start_benchmark_run(
+        &self,
+        cpu_threads: u32,
+        base_path: PathBuf,
+        config_path: PathBuf,
+        log_dir: PathBuf,
+    ) -> Result<Option<XmrigStatusMonitor>, anyhow::Error> {
         let shutdown_signal = TasksTrackers::current().hardware_phase.get_signal().await;
         let task_tracker = TasksTrackers::current()
             .hardware_phase
             .get_task_tracker()
             .await;
 
-        let max_cpu_available = thread::available_parallelism();
-        let max_cpu_available = match max_cpu_available {
-            Ok(available_cpus) => u32::try_from(available_cpus.get()).unwrap_or(1),
-            Err(_) => 1,
-        };
-
         {
             let mut lock = self.watcher.write().await;
             lock.adapter.node_connection = Some(XmrigNodeConnection::Benchmark);
-            lock.adapter.cpu_threads = Some(Some(1));
+            lock.adapter.cpu_threads = Some(Some(cpu_threads));
             lock.adapter.extra_options = vec![];
 
             lock.start(
-                base_path.clone(),
-                config_path.clone(),
-                log_dir.clone(),
+                base_path,
+                config_path,
+                log_dir,
                 Binaries::Xmrig,
-                shutdown_signal.clone(),
+                shutdown_signal,
                 task_tracker,
             )
             .await?;
         }
 
-        let status = {
-            let mut status = None;
-            for _ in 0..10 {
-                let lock = self.watcher.read().await;
-                if let Some(s) = lock.status_monitor.as_ref() {
-                    status = Some(s.clone());
-                    break;
-                }
-                drop(lock);
-                sleep(Duration::from_secs(1)).await;
+        for _ in 0..10 {
+            let lock = self.watcher.read().await;
+            if let Some(status) = lock.status_monitor.as_ref() {
+                return Ok(Some(status.clone()));
             }
+            drop(lock);
+            sleep(Duration::from_secs(1)).await;
+        }
 
-            match status {
-                Some(s) => s,
-                None => {
-                    error!(target: LOG_TARGET, "Failed to get status for xmrig for benchmarking");
-                    // Stop the miner before returning
-                    self.stop().await?;
-                    return Ok(());
-                }
-            }
-        };
+        error!(target: LOG_TARGET, "Failed to get status for xmrig for benchmarking");
+        Ok(None)
+    }
 
+    /// Highest hashrate xmrig reports within the given duration
+    pub async fn sample_benchmark_hashrate(status: XmrigStatusMonitor, duration: Duration) -> f64 {
+        let shutdown_signal = TasksTrackers::current().hardware_phase.get_signal().await;
         let timeout_duration = duration + Duration::from_secs(10);
-        let result = match timeout(timeout_duration, async move {
+        timeout(timeout_duration, async move {
             let start_time = Instant::now();
             let mut max_hashrate = 0f64;
 
@@ -349,20 +532,10 @@ impl CpuMiner {
                 }
             }
 
-            #[allow(clippy::cast_possible_truncation)]
-            Ok::<u64, anyhow::Error>(max_hashrate.floor() as u64)
+            max_hashrate
         })
         .await
-        {
-            Ok(res) => res? * u64::from(max_cpu_available),
-            Err(_) => 0,
-        };
-
-        // Stop the miner
-        self.stop().await?;
-
-        self.benchmarked_hashrate = result;
-        Ok(())
+        .unwrap_or_default()
     }
 
     pub async fn stop(&mut self) -> Result<(), anyhow::Error> {
@@ -393,8 +566,10 @@ impl CpuMiner {
     async fn initialize_status_updates(&self, mut app_shutdown: ShutdownSignal) {
         let cpu_miner_status_watch_tx = self.cpu_miner_status_watch_tx.clone();
         let mut summary_watch_rx = self.summary_watch_rx.clone();
+        let backends_watch_rx = self.backends_watch_rx.clone();
         let node_status_watch_rx = self.node_status_watch_rx.clone();
         let pool_status_watcher = self.pool_status_watcher.clone();
+        let active_pool = self.active_pool.clone();
         let mut pool_status_check = interval(Duration::from_secs(20));
         pool_status_check.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
 
@@ -437,16 +612,16 @@ impl CpuMiner {
                                 //     .total
                                 //     .iter()
                                 //     .fold(0.0, |acc, x| acc + x.unwrap_or(0.0));
-                                let is_connected = xmrig_status.connection.uptime > 0;
                                 // dbg!(&last_pool_status);
-
+                                let diagnostics = Self::diagnostics(&xmrig_status, &backends_watch_rx.borrow());
 
                                 CpuMinerStatus {
                                     is_mining: true,
                                     hash_rate,
                                     estimated_earnings: MicroMinotari(estimated_earnings).as_u64(),
-                                    connection: CpuMinerConnectionStatus { is_connected },
+                                    connection: Self::connection_status(&xmrig_status, active_pool.clone()),
                                     pool_status: last_pool_status.clone(),
+                                    diagnostics: Some(diagnostics),
                                 }
                             }
                             None => {
@@ -469,4 +644,70 @@ impl CpuMiner {
             }
         });
     }
+
+    fn connection_status(summary: &Summary, pool_url: Option<String>) -> CpuMinerConnectionStatus {
+        let connection = &summary.connection;
+        // Pool errors are reported on the connection, older xmrig versions only fill the results log
+        let error_log = if connection.error_log.is_empty() {
+            &summary.results.error_log
+        } else {
+            &connection.error_log
+        };
+
+        CpuMinerConnectionStatus {
+            is_connected: connection.uptime > 0,
+            pool_url,
+            accepted_shares: connection.accepted,
+            rejected_shares: connection.rejected,
+            ping: connection.ping,
+            failures: connection.failures,
+            tls: connection.tls.clone(),
+            error_log: error_log.iter().map(error_log_message).collect(),
+        }
+    }
+
+    fn diagnostics(summary: &Summary, backends: &[Backend]) -> CpuMinerDiagnostics {
+        let cpu_backend = backends.iter().find(|backend| backend.kind == "cpu");
+
+        // `2/backends` knows thread affinity and intensity, fall back to the bare summary list when it isn't available
+        let threads = match cpu_backend {
+            Some(backend) => backend
+                .threads
+                .iter()
+                .map(|thread| CpuThreadStatus {
+                    affinity: thread.affinity,
+                    intensity: Some(thread.intensity),
+                    hash_rate: thread
+                        .hashrate
+                        .first()
+                        .copied()
+                        .flatten()
+                        .unwrap_or_default(),
+                })
+                .collect(),
+            None => summary
+                .hashrate
+                .threads
+                .iter()
+                .map(|hashrate| CpuThreadStatus {
+                    affinity: -1,
+                    intensity: None,
+                    hash_rate: hashrate.first().copied().flatten().unwrap_or_default(),
+                })
+                .collect(),
+        };
+
+        CpuMinerDiagnostics {
+            is_paused: summary.paused,
+            algorithm: cpu_backend.and_then(|backend| backend.algo.clone()),
+            highest_hash_rate: summary.hashrate.highest,
+            hashes_total: summary.results.hashes_total,
+            threads,
+            hugepages: cpu_backend
+                .map_or(&summary.hugepages, |backend| &backend.hugepages)
+                .status(),
+            memory: summary.resources.memory.clone(),
+            cpu: summary.cpu.clone(),
+        }
+    }
 }
@@ -166,11 +166,17 @@ pub async fn validate_checksum(
     expected_checksum: String,
 ) -> Result<bool, Error> {
     let mut file = File::open(file_path.clone()).await?;
-    let mut buffer = Vec::new();
-    file.read_to_end(&mut buffer).await?;
+    // Hashed in chunks, node snapshots are too large to hold in memory
+    let mut buffer = vec![0u8; 1024 * 1024];
 
     let mut hasher = Sha256::new();
-    hasher.update(&buffer);
+    loop {
+        let read = file.read(&mut buffer).await?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
     let hash = hasher.finalize();
     let hash_hex = format!("{hash:x}");
 
@@ -27,9 +27,15 @@ use std::{
 };
 
 use crate::{
+    configs::config_mining::{BatteryMiningAction, MiningMode, MiningScheduleRule},
+    gpu_miner::GpuDeviceHealth,
+    gpu_miner_adapter::GpuDeviceStats,
     gpu_status_file::GpuDevice,
+    hardware::hardware_status_monitor::PublicDeviceProperties,
     node::{node_adapter::NodeIdentity, node_manager::NodeType},
     setup::setup_manager::SetupPhase,
+    stratum_proxy::StratumWorker,
+    utils::system_status::PowerSourceStatus,
     wallet_adapter::{TransactionInfo, WalletBalance},
 };
 
@@ -82,6 +88,15 @@ pub enum EventType {
     ExternalTariAddressChanged,
     ShouldShowExchangeMinerModal,
     BaseTariAddressChanged,
+    ProcessWatcherGaveUp,
+    MiningScheduleUpdate,
+    IdleMiningUpdate,
+    ThermalGovernorAction,
+    BatteryPolicyUpdate,
+    MiningBenchmarkProgress,
+    StratumProxyUpdate,
+    GpuEngineFallback,
+    NodeDiskSpaceLow,
 }
 
 #[derive(Clone, Debug, Serialize)]
@@ -159,6 +174,71 @@ pub struct CriticalProblemPayload {
     pub error_message: Option<String>,
 }
 
+#[derive(Debug, Serialize, Clone)]
+pub struct ProcessWatcherGaveUpPayload {
+    pub name: String,
+    pub num_restarts: u64,
+    pub exit_code: i32,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct MiningScheduleUpdatePayload {
+    pub active_rule: Option<MiningScheduleRule>,
+    pub cpu_mining: bool,
+    pub gpu_mining: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct IdleMiningUpdatePayload {
+    pub is_idle: bool,
+    pub mode: MiningMode,
+    pub cpu_mining: bool,
+    pub gpu_mining: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct BatteryPolicyUpdatePayload {
+    pub power_source: PowerSourceStatus,
+    pub applied_action: Option<BatteryMiningAction>,
+    pub cpu_mining: bool,
+    pub gpu_mining: bool,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct MiningBenchmarkProgressPayload {
+    pub completed_runs: usize,
+    pub total_runs: usize,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct StratumProxyUpdatePayload {
+    pub workers: Vec<StratumWorker>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct GpuDeviceUpdatePayload {
+    pub device_index: u32,
+    pub device_name: String,
+    pub health: GpuDeviceHealth,
+    /// `None` while glytex isn't mining on the device
+    pub stats: Option<GpuDeviceStats>,
+    pub hardware: Option<PublicDeviceProperties>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct GpuEngineFallbackPayload {
+    pub failed_engine: String,
+    /// `None` when every detected engine failed and GPU mining was left stopped
+    pub engine: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub struct NodeDiskSpaceLowPayload {
+    pub mount_point: String,
+    pub available_space: u64,
+    pub min_free_space: u64,
+}
+
 #[derive(Debug, Serialize, Clone)]
 pub struct NodeTypeUpdatePayload {
     pub node_type: Option<NodeType>,
@@ -23,8 +23,11 @@ use std::sync::LazyLock;
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 use crate::events::{
-    ConnectionStatusPayload, CriticalProblemPayload, DisabledPhasesPayload,
-    InitWalletScanningProgressPayload,
+    BatteryPolicyUpdatePayload, ConnectionStatusPayload, CriticalProblemPayload,
+    DisabledPhasesPayload, GpuDeviceUpdatePayload, GpuEngineFallbackPayload,
+    IdleMiningUpdatePayload, InitWalletScanningProgressPayload, MiningBenchmarkProgressPayload,
+    MiningScheduleUpdatePayload, NodeDiskSpaceLowPayload, ProcessWatcherGaveUpPayload,
+    StratumProxyUpdatePayload,
 };
 #[cfg(target_os = "windows")]
 use crate::external_dependencies::RequiredExternalDependency;
@@ -41,13 +44,15 @@ use crate::{
         ProgressTrackerUpdatePayload, ShowReleaseNotesPayload, TariAddressUpdatePayload,
     },
     gpu_status_file::GpuDevice,
-    hardware::hardware_status_monitor::PublicDeviceProperties,
+    hardware::thermal_governor::ThermalGovernorAction,
+    headless_mode::HeadlessMode,
     setup::setup_manager::SetupPhase,
     utils::app_flow_utils::FrontendReadyChannel,
     wallet_adapter::{TransactionInfo, WalletBalance},
     BaseNodeStatus, GpuMinerStatus,
 };
 use log::error;
+use serde::Serialize;
 use tari_common_types::tari_address::TariAddress;
 use tauri::{AppHandle, Emitter};
 use tokio::sync::RwLock;
@@ -85,6 +90,16 @@ impl EventsEmitter {
             .expect("Cannot emit events due to missing AppHandle")
             .clone()
     }
+
+    async fn emit<S: Serialize + Clone>(event_name: &str, event: S) -> Result<(), tauri::Error> {
+        // There is no webview to listen for events in headless mode, progress is reported on stdout instead
+        if HeadlessMode::current().is_enabled() {
+            HeadlessMode::print_event(event_name, &event);
+            return Ok(());
+        }
+        Self::get_app_handle().await.emit(event_name, event)
+    }
+
     pub async fn emit_progress_tracker_update(
         event_type: ProgressEvents,
         payload: ProgressTrackerUpdatePayload,
@@ -93,10 +108,7 @@ impl EventsEmitter {
             event_type,
             payload,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(PROGRESS_TRACKER_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(PROGRESS_TRACKER_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit ProgressTrackerUpdate event: {:?}", e);
         }
     }
@@ -107,10 +119,7 @@ impl EventsEmitter {
             event_type: EventType::StuckOnOrphanChain,
             payload: is_stuck,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit StuckOnOrphanChain event: {:?}", e);
         }
     }
@@ -121,10 +130,7 @@ impl EventsEmitter {
             event_type: EventType::ShowReleaseNotes,
             payload,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit ShowReleaseNotesPayload event: {:?}", e);
         }
     }
@@ -136,10 +142,7 @@ impl EventsEmitter {
             event_type: EventType::MissingApplications,
             payload: external_dependencies,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit MissingApplications event: {:?}", e);
         }
     }
@@ -150,24 +153,117 @@ impl EventsEmitter {
             event_type: EventType::CriticalProblem,
             payload,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit CriticalProblem event: {:?}", e);
         }
     }
 
+    pub async fn emit_process_watcher_gave_up(payload: ProcessWatcherGaveUpPayload) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::ProcessWatcherGaveUp,
+            payload,
+        };
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
+            error!(target: LOG_TARGET, "Failed to emit ProcessWatcherGaveUp event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_mining_schedule_update(payload: MiningScheduleUpdatePayload) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::MiningScheduleUpdate,
+            payload,
+        };
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
+            error!(target: LOG_TARGET, "Failed to emit MiningScheduleUpdate event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_idle_mining_update(payload: IdleMiningUpdatePayload) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::IdleMiningUpdate,
+            payload,
+        };
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
+            error!(target: LOG_TARGET, "Failed to emit IdleMiningUpdate event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_thermal_governor_action(payload: ThermalGovernorAction) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::ThermalGovernorAction,
+            payload,
+        };
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
+            error!(target: LOG_TARGET, "Failed to emit ThermalGovernorAction event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_battery_policy_update(payload: BatteryPolicyUpdatePayload) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::BatteryPolicyUpdate,
+            payload,
+        };
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
+            error!(target: LOG_TARGET, "Failed to emit BatteryPolicyUpdate event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_mining_benchmark_progress(payload: MiningBenchmarkProgressPayload) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::MiningBenchmarkProgress,
+            payload,
+        };
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
+            error!(target: LOG_TARGET, "Failed to emit MiningBenchmarkProgress event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_stratum_proxy_update(payload: StratumProxyUpdatePayload) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::StratumProxyUpdate,
+            payload,
+        };
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
+            error!(target: LOG_TARGET, "Failed to emit StratumProxyUpdate event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_gpu_engine_fallback(payload: GpuEngineFallbackPayload) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::GpuEngineFallback,
+            payload,
+        };
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
+            error!(target: LOG_TARGET, "Failed to emit GpuEngineFallback event: {:?}", e);
+        }
+    }
+
+    pub async fn emit_node_disk_space_low(payload: NodeDiskSpaceLowPayload) {
+        let _unused = FrontendReadyChannel::current().wait_for_ready().await;
+        let event = Event {
+            event_type: EventType::NodeDiskSpaceLow,
+            payload,
+        };
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
+            error!(target: LOG_TARGET, "Failed to emit NodeDiskSpaceLow event: {:?}", e);
+        }
+    }
+
     pub async fn emit_restarting_phases(payload: Vec<SetupPhase>) {
         let _unused = FrontendReadyChannel::current().wait_for_ready().await;
         let event = Event {
             event_type: EventType::RestartingPhases,
             payload,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit RestartingPhases event: {:?}", e);
         }
     }
@@ -178,10 +274,7 @@ impl EventsEmitter {
             event_type: EventType::AskForRestart,
             payload: (),
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit AskForRestart event: {:?}", e);
         }
     }
@@ -192,10 +285,7 @@ impl EventsEmitter {
             event_type: EventType::DetectedDevices,
             payload: DetectedDevicesPayload { devices },
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit DetectedDevices event: {:?}", e);
         }
     }
@@ -212,10 +302,7 @@ impl EventsEmitter {
                 selected_engine,
             },
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit DetectedAvailableGpuEngines event: {:?}", e);
         }
     }
@@ -226,10 +313,7 @@ impl EventsEmitter {
             event_type: EventType::CloseSplashscreen,
             payload: (),
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit CloseSplashscreen event: {:?}", e);
         }
     }
@@ -249,10 +333,7 @@ impl EventsEmitter {
                 is_too_low,
             },
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit NetworkStatus event: {:?}", e);
         }
     }
@@ -263,10 +344,7 @@ impl EventsEmitter {
             event_type: EventType::ConfigCoreLoaded,
             payload,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit CoreConfigLoaded event: {:?}", e);
         }
     }
@@ -277,10 +355,7 @@ impl EventsEmitter {
             event_type: EventType::ConfigUILoaded,
             payload,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit UIConfigLoaded event: {:?}", e);
         }
     }
@@ -292,10 +367,7 @@ impl EventsEmitter {
             event_type: EventType::ConfigWalletLoaded,
             payload,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit WalletConfigLoaded event: {:?}", e);
         }
     }
@@ -306,10 +378,7 @@ impl EventsEmitter {
             event_type: EventType::ConfigMiningLoaded,
             payload,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit MiningConfigLoaded event: {:?}", e);
         }
     }
@@ -320,10 +389,7 @@ impl EventsEmitter {
             event_type: EventType::WalletBalanceUpdate,
             payload: balance,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit WalletBalanceUpdate event: {:?}", e);
         }
     }
@@ -335,10 +401,7 @@ impl EventsEmitter {
             payload: status,
         };
 
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit BaseNodeUpdate event: {:?}", e);
         }
     }
@@ -349,25 +412,18 @@ impl EventsEmitter {
             event_type: EventType::ShowStageSecurityModal,
             payload: (),
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit ShowStagedSecurityModal event: {:?}", e);
         }
     }
 
-    #[allow(dead_code)]
-    pub async fn emit_gpu_devices_update(gpu_public_devices: Vec<PublicDeviceProperties>) {
+    pub async fn emit_gpu_devices_update(gpu_devices: Vec<GpuDeviceUpdatePayload>) {
         let _unused = FrontendReadyChannel::current().wait_for_ready().await;
         let event = Event {
             event_type: EventType::GpuDevicesUpdate,
-            payload: gpu_public_devices,
+            payload: gpu_devices,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit GpuDevicesUpdate event: {:?}", e);
         }
     }
@@ -378,10 +434,7 @@ impl EventsEmitter {
             event_type: EventType::PoolStatusUpdate,
             payload: pool_status,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit PoolStatusUpdate event: {:?}", e);
         }
     }
@@ -391,10 +444,7 @@ impl EventsEmitter {
             event_type: EventType::CpuMiningUpdate,
             payload: status,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit CpuMiningUpdate event: {:?}", e);
         }
     }
@@ -404,10 +454,7 @@ impl EventsEmitter {
             event_type: EventType::MiningTime,
             payload: mining_time,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit MiningTime event: {:?}", e);
         }
     }
@@ -418,10 +465,7 @@ impl EventsEmitter {
             event_type: EventType::GpuMiningUpdate,
             payload: status,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit GpuMiningUpdate event: {:?}", e);
         }
     }
@@ -432,10 +476,7 @@ impl EventsEmitter {
             event_type: EventType::ConnectedPeersUpdate,
             payload: connected_peers,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit ConnectedPeersUpdate event: {:?}", e);
         }
     }
@@ -454,10 +495,7 @@ impl EventsEmitter {
                 balance,
             },
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit NewBlockHeight event: {:?}", e);
         }
     }
@@ -468,10 +506,7 @@ impl EventsEmitter {
             event_type: EventType::CorePhaseFinished,
             payload: status,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit CorePhaseFinished event: {:?}", e);
         }
     }
@@ -482,10 +517,7 @@ impl EventsEmitter {
             event_type: EventType::WalletPhaseFinished,
             payload: status,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit WalletPhaseFinished event: {:?}", e);
         }
     }
@@ -496,10 +528,7 @@ impl EventsEmitter {
             event_type: EventType::HardwarePhaseFinished,
             payload: status,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit HardwarePhaseFinished event: {:?}", e);
         }
     }
@@ -510,10 +539,7 @@ impl EventsEmitter {
             event_type: EventType::NodePhaseFinished,
             payload: status,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit NodePhaseFinished event: {:?}", e);
         }
     }
@@ -524,10 +550,7 @@ impl EventsEmitter {
             event_type: EventType::MiningPhaseFinished,
             payload: status,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit MiningPhaseFinished event: {:?}", e);
         }
     }
@@ -538,10 +561,7 @@ impl EventsEmitter {
             event_type: EventType::InitialSetupFinished,
             payload: (),
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit SetupFinished event: {:?}", e);
         }
     }
@@ -552,10 +572,7 @@ impl EventsEmitter {
             event_type: EventType::UnlockApp,
             payload: (),
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit UnlockApp event: {:?}", e);
         }
     }
@@ -566,10 +583,7 @@ impl EventsEmitter {
             event_type: EventType::UnlockWallet,
             payload: (),
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit UnlockWallet event: {:?}", e);
         }
     }
@@ -580,10 +594,7 @@ impl EventsEmitter {
             event_type: EventType::UnlockCpuMining,
             payload: (),
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit UnlockCpuMining event: {:?}", e);
         }
     }
@@ -593,10 +604,7 @@ impl EventsEmitter {
             event_type: EventType::UnlockGpuMining,
             payload: (),
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit UnlockGpuMining event: {:?}", e);
         }
     }
@@ -607,10 +615,7 @@ impl EventsEmitter {
             event_type: EventType::LockWallet,
             payload: (),
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit LockWallet event: {:?}", e);
         }
     }
@@ -621,10 +626,7 @@ impl EventsEmitter {
             event_type: EventType::LockCpuMining,
             payload: (),
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit LockCpuMining event: {:?}", e);
         }
     }
@@ -634,10 +636,7 @@ impl EventsEmitter {
             event_type: EventType::LockGpuMining,
             payload: (),
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit LockGpuMining event: {:?}", e);
         }
     }
@@ -648,10 +647,7 @@ impl EventsEmitter {
             event_type: EventType::NodeTypeUpdate,
             payload,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit NodeTypeUpdate event: {:?}", e);
         }
     }
@@ -662,10 +658,7 @@ impl EventsEmitter {
             event_type: EventType::BackgroundNodeSyncUpdate,
             payload,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit BackgroundNodeSyncUpdate event: {:?}", e);
         }
     }
@@ -684,10 +677,7 @@ impl EventsEmitter {
                 progress,
             },
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit InitWalletScanningProgress event: {:?}", e);
         }
     }
@@ -698,10 +688,7 @@ impl EventsEmitter {
             event_type: EventType::ConnectionStatus,
             payload: connection_status,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit ConnectionStatus event: {:?}", e);
         }
     }
@@ -712,10 +699,7 @@ impl EventsEmitter {
             event_type: EventType::ExchangeIdChanged,
             payload: exchange_id,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit ExchangeIdChanged event: {:?}", e);
         }
     }
@@ -732,10 +716,7 @@ impl EventsEmitter {
             event_type: EventType::ExternalTariAddressChanged,
             payload: resolved_payload,
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit ExternalTariAddressChanged event: {:?}", e);
         }
     }
@@ -748,10 +729,7 @@ impl EventsEmitter {
                 tari_address_emoji: payload.to_emoji_string(),
             },
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit BaseTariAddressChanged event: {:?}", e);
         }
     }
@@ -762,10 +740,7 @@ impl EventsEmitter {
             event_type: EventType::DisabledPhases,
             payload: DisabledPhasesPayload { disabled_phases },
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit DisabledPhasesChanged event: {:?}", e);
         }
     }
@@ -775,10 +750,7 @@ impl EventsEmitter {
             event_type: EventType::ShouldShowExchangeMinerModal,
             payload: (),
         };
-        if let Err(e) = Self::get_app_handle()
-            .await
-            .emit(BACKEND_STATE_UPDATE, event)
-        {
+        if let Err(e) = Self::emit(BACKEND_STATE_UPDATE, event).await {
             error!(target: LOG_TARGET, "Failed to emit ShouldShowExchangeMinerModal event: {:?}", e);
         }
     }
@@ -29,9 +29,13 @@ use tauri::{AppHandle, Manager};
 use crate::airdrop::send_new_block_mined;
 use crate::configs::config_core::ConfigCore;
 use crate::configs::trait_config::ConfigImpl;
+use crate::mining_history::MiningHistory;
 use crate::setup::setup_manager::{SetupFeature, SetupManager};
 use crate::{
-    events::NodeTypeUpdatePayload, events_emitter::EventsEmitter, tasks_tracker::TasksTrackers,
+    events::NodeTypeUpdatePayload,
+    events_emitter::EventsEmitter,
+    tasks_tracker::TasksTrackers,
+    wallet_adapter::{TransactionInfo, WalletBalance},
     UniverseAppState,
 };
 
@@ -51,7 +55,7 @@ impl EventsManager {
         {
             info!(target: LOG_TARGET, "Firing new block height event but skipping wallet scan for seedless wallet feature");
 
-            EventsEmitter::emit_new_block_mined(block_height, None, None).await;
+            Self::record_and_emit_new_block(block_height, None, None).await;
 
             return;
         }
@@ -77,7 +81,7 @@ impl EventsManager {
                             None
                         };
 
-                        EventsEmitter::emit_new_block_mined(
+                        Self::record_and_emit_new_block(
                             block_height,
                             coinbase_tx.clone(),
                             Some(balance),
@@ -89,7 +93,7 @@ impl EventsManager {
                         }
                     } else {
                         error!(target: LOG_TARGET, "Wallet balance is None after new block height #{}", block_height);
-                        EventsEmitter::emit_new_block_mined(
+                        Self::record_and_emit_new_block(
 
                             block_height,
                             None,
@@ -100,7 +104,7 @@ impl EventsManager {
                 },
                 Err(e) => {
                     error!(target: LOG_TARGET, "Error waiting for wallet scan: {}", e);
-                    EventsEmitter::emit_new_block_mined(
+                    Self::record_and_emit_new_block(
                         block_height,
                         None,
                         None,
@@ -111,6 +115,23 @@ impl EventsManager {
         });
     }
 
+    async fn record_and_emit_new_block(
+        block_height: u64,
+        coinbase_transaction: Option<TransactionInfo>,
+        balance: Option<WalletBalance>,
+    ) {
+        MiningHistory::current()
+            .record_block(
+                block_height,
+                coinbase_transaction.as_ref().map(|tx| tx.amount.as_u64()),
+                balance
+                    .as_ref()
+                    .map(|balance| balance.available_balance.as_u64()),
+            )
+            .await;
+        EventsEmitter::emit_new_block_mined(block_height, coinbase_transaction, balance).await;
+    }
+
     pub async fn handle_node_type_update(app_handle: &AppHandle) {
         let node_manager = &app_handle.state::<UniverseAppState>().node_manager;
         let node_type = node_manager.get_node_type().await.ok();
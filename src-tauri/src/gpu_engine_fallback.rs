@@ -0,0 +1,209 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::time::Duration;
+
+use log::{error, info, warn};
+use tauri::{AppHandle, Manager};
+use tokio::select;
+use tokio::time::MissedTickBehavior;
+
+use crate::configs::config_mining::{ConfigMining, ConfigMiningContent};
+use crate::configs::trait_config::ConfigImpl;
+use crate::events::GpuEngineFallbackPayload;
+use crate::events_emitter::EventsEmitter;
+use crate::gpu_miner::EngineType;
+use crate::mining_benchmark::MiningBenchmark;
+use crate::tasks_tracker::TasksTrackers;
+use crate::utils::mining_control_utils;
+use crate::UniverseAppState;
+
+const LOG_TARGET: &str = "tari::universe::gpu_engine_fallback";
+const FALLBACK_CHECK_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Moves GPU mining to the next detected engine once the process watcher gave up on glytex, which
+/// usually means the engine itself is broken, e.g. a CUDA driver mismatch after an update
+pub struct GpuEngineFallback {
+    app_handle: AppHandle,
+    /// Engines that failed since GPU mining last produced hashes
+    failed_engines: Vec<EngineType>,
+}
+
+impl GpuEngineFallback {
+    pub async fn start(app_handle: AppHandle) {
+        let mut gpu_engine_fallback = Self {
+            app_handle,
+            failed_engines: Vec::new(),
+        };
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let mut interval = tokio::time::interval(FALLBACK_CHECK_INTERVAL);
+                interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+                loop {
+                    select! {
+                        _ = interval.tick() => {
+                            gpu_engine_fallback.check_gpu_engine().await;
+                        }
+                        _ = shutdown_signal.wait() => {
+                            info!(target: LOG_TARGET, "Shutdown signal received, stopping GPU engine fallback");
+                            break;
+                        }
+                    }
+                }
+            });
+    }
+
+    async fn check_gpu_engine(&mut self) {
+        // The benchmark starts and stops the miners itself while it runs
+        if MiningBenchmark::is_running() {
+            return;
+        }
+        let state = self.app_handle.state::<UniverseAppState>();
+        if state.gpu_latest_status.borrow().hash_rate > 0.0 {
+            self.failed_engines.clear();
+            return;
+        }
+        let failed_engine = {
+            let gpu_miner = state.gpu_miner.read().await;
+            if !gpu_miner.has_given_up().await {
+                return;
+            }
+            gpu_miner.running_engine().await
+        };
+        let config_dir = match self.app_handle.path().app_config_dir() {
+            Ok(config_dir) => config_dir,
+            Err(e) => {
+                error!(target: LOG_TARGET, "Could not get config dir: {}", e);
+                return;
+            }
+        };
+        let available_engines = match state
+            .gpu_miner
+            .read()
+            .await
+            .get_available_gpu_engines(config_dir.clone())
+            .await
+        {
+            Ok(available_engines) => available_engines,
+            Err(e) => {
+                error!(target: LOG_TARGET, "Could not read available GPU engines: {}", e);
+                return;
+            }
+        };
+
+        if !self.failed_engines.contains(&failed_engine) {
+            self.failed_engines.push(failed_engine.clone());
+        }
+        // Clears the given up process watcher so it isn't picked up again
+        mining_control_utils::stop_gpu_mining(&self.app_handle).await;
+
+        let Some(engine) = next_engine(&available_engines, &failed_engine, &self.failed_engines)
+        else {
+            error!(target: LOG_TARGET, "GPU engine {} failed and no other engine is left to try", failed_engine);
+            // Give every engine another chance when the user starts mining again
+            self.failed_engines.clear();
+            EventsEmitter::emit_gpu_engine_fallback(GpuEngineFallbackPayload {
+                failed_engine: failed_engine.to_string(),
+                engine: None,
+            })
+            .await;
+            return;
+        };
+
+        warn!(target: LOG_TARGET, "GPU engine {} keeps failing, switching to {}", failed_engine, engine);
+        if let Err(e) = state
+            .gpu_miner
+            .write()
+            .await
+            .set_selected_engine(engine.clone(), config_dir)
+            .await
+        {
+            error!(target: LOG_TARGET, "Could not switch GPU engine to {}: {}", engine, e);
+            return;
+        }
+        if let Err(e) =
+            ConfigMining::update_field(ConfigMiningContent::set_gpu_engine, engine.clone()).await
+        {
+            error!(target: LOG_TARGET, "Could not save GPU engine {}: {}", engine, e);
+        }
+        clear_engine_overrides(&failed_engine).await;
+        EventsEmitter::emit_detected_available_gpu_engines(
+            available_engines.iter().map(|x| x.to_string()).collect(),
+            engine.to_string(),
+        )
+        .await;
+        EventsEmitter::emit_gpu_engine_fallback(GpuEngineFallbackPayload {
+            failed_engine: failed_engine.to_string(),
+            engine: Some(engine.to_string()),
+        })
+        .await;
+        mining_control_utils::start_gpu_mining(&self.app_handle).await;
+    }
+}
+
+/// Devices pinned to the failed engine would keep resolving to it, so they follow the global engine from now on
+async fn clear_engine_overrides(failed_engine: &EngineType) {
+    let mut gpu_device_settings = ConfigMining::content().await.gpu_device_settings().clone();
+    let mut is_changed = false;
+    for settings in &mut gpu_device_settings {
+        if settings.engine.as_ref() == Some(failed_engine) {
+            info!(target: LOG_TARGET, "Clearing {} engine override of GPU {}", failed_engine, settings.device_index);
+            settings.engine = None;
+            is_changed = true;
+        }
+    }
+    if !is_changed {
+        return;
+    }
+    match ConfigMining::update_field(
+        ConfigMiningContent::set_gpu_device_settings,
+        gpu_device_settings,
+    )
+    .await
+    {
+        Ok(()) => EventsEmitter::emit_mining_config_loaded(ConfigMining::content().await).await,
+        Err(e) => error!(target: LOG_TARGET, "Could not clear GPU engine overrides: {}", e),
+    }
+}
+
+/// The first engine after `current` in the detected list that hasn't failed yet
+fn next_engine(
+    available_engines: &[EngineType],
+    current: &EngineType,
+    failed_engines: &[EngineType],
+) -> Option<EngineType> {
+    let start = available_engines
+        .iter()
+        .position(|engine| engine == current)
+        .map_or(0, |index| index + 1);
+    available_engines
+        .iter()
+        .cycle()
+        .skip(start)
+        .take(available_engines.len())
+        .find(|engine| !failed_engines.contains(engine))
+        .cloned()
+}
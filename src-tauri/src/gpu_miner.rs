@@ -22,6 +22,7 @@
 
 use log::{info, warn};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::fs::read_dir;
 use std::path::Path;
@@ -34,21 +35,32 @@ use tokio::select;
 use tokio::sync::{watch, RwLock};
 
 use crate::binaries::{Binaries, BinaryResolver};
-use crate::configs::config_mining::{GpuThreads, MiningMode};
+use crate::configs::config_mining::{GpuDeviceMiningSettings, GpuThreads, MiningMode};
+use crate::events::GpuDeviceUpdatePayload;
 use crate::events_emitter::EventsEmitter;
 use crate::gpu_miner_adapter::GpuNodeSource;
 use crate::gpu_status_file::{GpuDevice, GpuStatusFile};
+use crate::hardware::hardware_status_monitor::{HardwareStatusMonitor, PublicDeviceProperties};
+use crate::hardware::thermal_governor::ThermalGovernor;
 use crate::process_stats_collector::ProcessStatsCollectorBuilder;
 use crate::tasks_tracker::TasksTrackers;
 use crate::utils::math_utils::estimate_earning;
 use crate::{
     gpu_miner_adapter::{GpuMinerAdapter, GpuMinerStatus},
-    process_watcher::ProcessWatcher,
+    process_watcher::{ProcessWatcher, RestartPolicy},
 };
 use crate::{process_utils, BaseNodeStatus};
 
 const LOG_TARGET: &str = "tari::universe::gpu_miner";
 
+#[derive(Debug, Serialize, Clone, Copy, PartialEq)]
+pub enum GpuDeviceHealth {
+    Healthy,
+    /// The miner produces hashes but this device doesn't
+    Stalled,
+    Excluded,
+}
+
 #[derive(Debug, PartialEq, Clone, Serialize, Deserialize, Default)]
 pub enum EngineType {
     #[default]
@@ -86,6 +98,8 @@ pub(crate) struct GpuMiner {
     node_status_watch_rx: watch::Receiver<BaseNodeStatus>,
     gpu_raw_status_rx: watch::Receiver<Option<GpuMinerStatus>>,
     status_broadcast: watch::Sender<GpuMinerStatus>,
+    /// Index into `ConfigMiningContent::gpu_pool_urls`, picked up on the next start
+    active_pool_index: usize,
 }
 
 impl GpuMiner {
@@ -99,6 +113,14 @@ impl GpuMiner {
         let mut process_watcher = ProcessWatcher::new(adapter, stats_collector.take_gpu_miner());
         process_watcher.health_timeout = Duration::from_secs(9);
         process_watcher.poll_time = Duration::from_secs(10);
+        // A broken engine or driver makes glytex crash right after startup, so stop retrying early
+        process_watcher.restart_policy = RestartPolicy {
+            max_restarts: 3,
+            restart_window: Duration::from_secs(5 * 60),
+            initial_backoff: Duration::from_secs(2),
+            max_backoff: Duration::from_secs(30),
+            ..RestartPolicy::default()
+        };
 
         Self {
             watcher: Arc::new(RwLock::new(process_watcher)),
@@ -108,9 +130,18 @@ impl GpuMiner {
             status_broadcast,
             node_status_watch_rx,
             gpu_raw_status_rx,
+            active_pool_index: 0,
         }
     }
 
+    pub fn active_pool_index(&self) -> usize {
+        self.active_pool_index
+    }
+
+    pub fn select_pool(&mut self, index: usize) {
+        self.active_pool_index = index;
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub async fn start(
         &mut self,
@@ -122,6 +153,7 @@ impl GpuMiner {
         mining_mode: MiningMode,
         coinbase_extra: String,
         custom_gpu_grid_size: Vec<GpuThreads>,
+        device_settings: Vec<GpuDeviceMiningSettings>,
     ) -> Result<(), anyhow::Error> {
         let shutdown_signal = TasksTrackers::current().hardware_phase.get_signal().await;
         let task_tracker = TasksTrackers::current()
@@ -129,12 +161,34 @@ impl GpuMiner {
             .get_task_tracker()
             .await;
 
+        let engine = self.engine_for(&device_settings);
+        let gpu_devices = if engine == self.curent_selected_engine {
+            self.gpu_devices.clone()
+        } else {
+            let path = get_gpu_engines_statuses_path(&config_path)
+                .join(format!("{}_gpu_status.json", engine));
+            GpuStatusFile::load(&path)?.gpu_devices
+        };
+
         let mut process_watcher = self.watcher.write().await;
         process_watcher.adapter.tari_address = tari_address;
-        process_watcher.adapter.gpu_devices = self.gpu_devices.clone();
+        process_watcher.adapter.curent_selected_engine = engine;
+        process_watcher.adapter.gpu_devices = gpu_devices.clone();
         process_watcher
             .adapter
-            .set_mode(mining_mode, custom_gpu_grid_size);
+            .set_mode(mining_mode, custom_gpu_grid_size, &device_settings);
+        for (grid_size, gpu_device) in process_watcher
+            .adapter
+            .gpu_grid_size
+            .iter_mut()
+            .zip(&gpu_devices)
+        {
+            let load_percentage = ThermalGovernor::current()
+                .gpu_load_percentage(gpu_device.device_index)
+                .await;
+            grid_size.max_gpu_threads =
+                ThermalGovernor::scale(grid_size.max_gpu_threads, load_percentage);
+        }
         process_watcher.adapter.node_source = Some(node_source);
         process_watcher.adapter.coinbase_extra = coinbase_extra;
         info!(target: LOG_TARGET, "Starting xtrgpuminer");
@@ -150,11 +204,37 @@ impl GpuMiner {
             .await?;
         info!(target: LOG_TARGET, "xtrgpuminer started");
 
-        self.initialize_status_updates(shutdown_signal).await;
+        self.initialize_status_updates(shutdown_signal, gpu_devices)
+            .await;
 
         Ok(())
     }
 
+    /// glytex runs a single engine for all devices, so a per-device engine is only used when every
+    /// enabled device ends up asking for the same one
+    fn engine_for(&self, device_settings: &[GpuDeviceMiningSettings]) -> EngineType {
+        let mut engines = self
+            .gpu_devices
+            .iter()
+            .filter(|gpu_device| !gpu_device.settings.is_excluded)
+            .map(|gpu_device| {
+                device_settings
+                    .iter()
+                    .find(|settings| settings.device_index == gpu_device.device_index)
+                    .and_then(|settings| settings.engine.clone())
+                    .unwrap_or_else(|| self.curent_selected_engine.clone())
+            });
+        let Some(engine) = engines.next() else {
+            return self.curent_selected_engine.clone();
+        };
+        if engines.all(|other| other == engine) {
+            engine
+        } else {
+            warn!(target: LOG_TARGET, "GPU devices ask for different engines, using {}", self.curent_selected_engine);
+            self.curent_selected_engine.clone()
+        }
+    }
+
     pub async fn stop(&self) -> Result<(), anyhow::Error> {
         info!(target: LOG_TARGET, "Stopping xtrgpuminer");
         {
@@ -171,6 +251,18 @@ impl GpuMiner {
         let process_watcher = self.watcher.read().await;
         process_watcher.is_running()
     }
+
+    pub async fn has_given_up(&self) -> bool {
+        let process_watcher = self.watcher.read().await;
+        process_watcher.has_given_up()
+    }
+
+    /// The engine glytex was last started with, which can differ from the selected one when
+    /// every device overrides it
+    pub async fn running_engine(&self) -> EngineType {
+        let process_watcher = self.watcher.read().await;
+        process_watcher.adapter.curent_selected_engine.clone()
+    }
     #[allow(dead_code)]
     pub async fn is_pid_file_exists(&self, base_path: PathBuf) -> bool {
         let lock = self.watcher.read().await;
@@ -285,7 +377,11 @@ impl GpuMiner {
         Ok(available_engines)
     }
 
-    async fn initialize_status_updates(&self, mut app_shutdown: ShutdownSignal) {
+    async fn initialize_status_updates(
+        &self,
+        mut app_shutdown: ShutdownSignal,
+        gpu_devices: Vec<GpuDevice>,
+    ) {
         let mut gpu_raw_status_rx = self.gpu_raw_status_rx.clone();
         let node_status_watch_rx = self.node_status_watch_rx.clone();
         let status_broadcast = self.status_broadcast.clone();
@@ -316,6 +412,15 @@ impl GpuMiner {
                             }
                         };
 
+                        let hardware = HardwareStatusMonitor::current()
+                            .get_gpu_public_properties()
+                            .await
+                            .unwrap_or_default();
+                        EventsEmitter::emit_gpu_devices_update(
+                            device_updates(&gpu_devices, &gpu_status, &hardware),
+                        )
+                        .await;
+
                         let _result = status_broadcast.send(gpu_status);
                     },
                     _ = app_shutdown.wait() => {
@@ -381,12 +486,56 @@ impl GpuMiner {
     pub async fn get_gpu_devices(&self) -> Result<Vec<GpuDevice>, anyhow::Error> {
         Ok(self.gpu_devices.clone())
     }
+
+    pub async fn get_device_hashrates(&self) -> Result<HashMap<u32, f64>, anyhow::Error> {
+        let status_monitor = self.watcher.read().await.status_monitor.clone();
+        match status_monitor {
+            Some(status_monitor) => status_monitor.device_hashrates().await,
+            None => Err(anyhow::anyhow!("GPU miner is not running")),
+        }
+    }
     pub async fn get_port(&self) -> u16 {
         let lock = self.watcher.read().await;
         lock.adapter.http_api_port
     }
 }
 
+fn device_updates(
+    gpu_devices: &[GpuDevice],
+    gpu_status: &GpuMinerStatus,
+    hardware: &[PublicDeviceProperties],
+) -> Vec<GpuDeviceUpdatePayload> {
+    gpu_devices
+        .iter()
+        .map(|gpu_device| {
+            let stats = gpu_status
+                .devices
+                .iter()
+                .find(|stats| stats.device_index == gpu_device.device_index)
+                .cloned();
+            let health = if gpu_device.settings.is_excluded {
+                GpuDeviceHealth::Excluded
+            } else if gpu_status.hash_rate > 0.0
+                && !stats.as_ref().is_some_and(|stats| stats.hash_rate > 0.0)
+            {
+                GpuDeviceHealth::Stalled
+            } else {
+                GpuDeviceHealth::Healthy
+            };
+            GpuDeviceUpdatePayload {
+                device_index: gpu_device.device_index,
+                device_name: gpu_device.device_name.clone(),
+                health,
+                stats,
+                hardware: hardware
+                    .iter()
+                    .find(|device| device.device_index == gpu_device.device_index)
+                    .cloned(),
+            }
+        })
+        .collect()
+}
+
 fn get_gpu_engines_statuses_path(config_dir: &Path) -> PathBuf {
     config_dir.join("gpuminer").join("engine_statuses").clone()
 }
@@ -20,6 +20,7 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use crate::configs::config_mining::GpuDeviceMiningSettings;
 use crate::configs::config_mining::GpuThreads;
 use crate::configs::config_mining::MiningMode;
 use crate::gpu_miner::EngineType;
@@ -75,6 +76,7 @@ impl GpuMinerAdapter {
                 .iter()
                 .map(|gpu_device| GpuThreads {
                     gpu_name: gpu_device.device_name.clone(),
+                    device_index: Some(gpu_device.device_index),
                     max_gpu_threads: gpu_device.status.max_grid_size,
                 })
                 .collect(),
@@ -87,33 +89,58 @@ impl GpuMinerAdapter {
         }
     }
 
-    pub fn set_mode(&mut self, mode: MiningMode, custom_max_gpus_grid_size: Vec<GpuThreads>) {
-        match mode {
-            MiningMode::Eco => {
-                self.gpu_grid_size = self
-                    .gpu_devices
-                    .iter()
-                    .map(|gpu_device| GpuThreads {
-                        gpu_name: gpu_device.device_name.clone(),
-                        max_gpu_threads: 2,
-                    })
-                    .collect()
-            }
-            MiningMode::Ludicrous => {
-                self.gpu_grid_size = self
-                    .gpu_devices
+    /// Every device gets the grid size of its own mode, devices without one follow `mode`
+    pub fn set_mode(
+        &mut self,
+        mode: MiningMode,
+        custom_max_gpus_grid_size: Vec<GpuThreads>,
+        device_settings: &[GpuDeviceMiningSettings],
+    ) {
+        self.gpu_grid_size = self
+            .gpu_devices
+            .iter()
+            .enumerate()
+            .map(|(position, gpu_device)| {
+                let device_mode = device_settings
                     .iter()
-                    .map(|gpu_device| GpuThreads {
-                        gpu_name: gpu_device.device_name.clone(),
-                        max_gpu_threads: 1024,
-                    })
-                    .collect()
-            }
-            MiningMode::Custom => self.gpu_grid_size = custom_max_gpus_grid_size,
-        }
+                    .find(|settings| settings.device_index == gpu_device.device_index)
+                    .and_then(|settings| settings.mode)
+                    .unwrap_or(mode);
+                let max_gpu_threads = match device_mode {
+                    MiningMode::Eco => 2,
+                    MiningMode::Ludicrous => 1024,
+                    MiningMode::Custom => {
+                        custom_grid_size(&custom_max_gpus_grid_size, position, gpu_device)
+                            .unwrap_or(gpu_device.status.max_grid_size)
+                    }
+                };
+                GpuThreads {
+                    gpu_name: gpu_device.device_name.clone(),
+                    device_index: Some(gpu_device.device_index),
+                    max_gpu_threads,
+                }
+            })
+            .collect();
     }
 }
 
+fn custom_grid_size(
+    custom_max_gpus_grid_size: &[GpuThreads],
+    position: usize,
+    gpu_device: &GpuDevice,
+) -> Option<u32> {
+    custom_max_gpus_grid_size
+        .iter()
+        .find(|gpu_threads| gpu_threads.device_index == Some(gpu_device.device_index))
+        // Entries saved without a device index line up with the device list
+        .or_else(|| {
+            custom_max_gpus_grid_size
+                .get(position)
+                .filter(|gpu_threads| gpu_threads.device_index.is_none())
+        })
+        .map(|gpu_threads| gpu_threads.max_gpu_threads)
+}
+
 impl ProcessAdapter for GpuMinerAdapter {
     type StatusMonitor = GpuMinerStatusMonitor;
     type ProcessInstance = ProcessInstance;
@@ -244,6 +271,7 @@ impl ProcessAdapter for GpuMinerAdapter {
             },
             GpuMinerStatusMonitor {
                 http_api_port,
+                engine: self.curent_selected_engine.clone(),
                 gpu_raw_status_broadcast: self.gpu_raw_status_broadcast.clone(),
             },
         ))
@@ -261,6 +289,7 @@ impl ProcessAdapter for GpuMinerAdapter {
 #[derive(Clone)]
 pub struct GpuMinerStatusMonitor {
     http_api_port: u16,
+    engine: EngineType,
     gpu_raw_status_broadcast: watch::Sender<Option<GpuMinerStatus>>,
 }
 
@@ -306,18 +335,7 @@ impl GpuMinerStatusMonitor {
             Ok(response) => response,
             Err(e) => {
                 warn!(target: LOG_TARGET, "Error in getting response from XtrGpuMiner status: {}", e);
-                if e.is_connect() {
-                    return Ok(GpuMinerStatus {
-                        is_mining: false,
-                        hash_rate: 0.0,
-                        estimated_earnings: 0,
-                    });
-                }
-                return Ok(GpuMinerStatus {
-                    is_mining: false,
-                    hash_rate: 0.0,
-                    estimated_earnings: 0,
-                });
+                return Ok(GpuMinerStatus::default());
             }
         };
         let text = response.text().await?;
@@ -325,28 +343,72 @@ impl GpuMinerStatusMonitor {
             Ok(body) => body,
             Err(e) => {
                 warn!(target: LOG_TARGET, "Error decoding body from  in XtrGpuMiner status: {}", e);
-                return Ok(GpuMinerStatus {
-                    is_mining: false,
-                    hash_rate: 0.0,
-                    estimated_earnings: 0,
-                });
+                return Ok(GpuMinerStatus::default());
             }
         };
 
+        let engine = body
+            .engine
+            .clone()
+            .unwrap_or_else(|| self.engine.to_string());
+        let mut devices: Vec<GpuDeviceStats> = body
+            .hashrate_per_device
+            .iter()
+            .map(|(device_index, hashrate)| {
+                let stats = body.device_stats.get(device_index);
+                GpuDeviceStats {
+                    device_index: *device_index,
+                    hash_rate: hashrate.ten_seconds.unwrap_or(0.0),
+                    accepted_shares: stats.map_or(0, |stats| stats.accepted_shares),
+                    errors: stats.map_or(0, |stats| stats.errors),
+                    engine: engine.clone(),
+                }
+            })
+            .collect();
+        devices.sort_by_key(|device| device.device_index);
+
         Ok(GpuMinerStatus {
             is_mining: true,
             estimated_earnings: 0,
             hash_rate: body.total_hashrate.ten_seconds.unwrap_or(0.0),
+            devices,
         })
     }
+
+    /// Ten second average hashrate of every device, keyed by device index
+    pub async fn device_hashrates(&self) -> Result<HashMap<u32, f64>, anyhow::Error> {
+        let client = reqwest::Client::new();
+        let body: XtrGpuminerHttpApiStatus = client
+            .get(format!("http://127.0.0.1:{}/stats", self.http_api_port))
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        Ok(body
+            .hashrate_per_device
+            .into_iter()
+            .map(|(device_index, hashrate)| (device_index, hashrate.ten_seconds.unwrap_or(0.0)))
+            .collect())
+    }
 }
 
 #[derive(Debug, Deserialize)]
-#[allow(dead_code)]
 struct XtrGpuminerHttpApiStatus {
-    #[allow(dead_code)]
     hashrate_per_device: HashMap<u32, AverageHashrate>,
     total_hashrate: AverageHashrate,
+    // Only reported by newer glytex versions
+    #[serde(default)]
+    device_stats: HashMap<u32, XtrGpuminerDeviceStats>,
+    #[serde(default)]
+    engine: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+struct XtrGpuminerDeviceStats {
+    accepted_shares: u64,
+    errors: u64,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -361,4 +423,16 @@ pub(crate) struct GpuMinerStatus {
     pub is_mining: bool,
     pub hash_rate: f64,
     pub estimated_earnings: u64,
+    /// Sent per device through `GpuDevicesUpdate` together with the hardware readings
+    #[serde(skip)]
+    pub devices: Vec<GpuDeviceStats>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+pub(crate) struct GpuDeviceStats {
+    pub device_index: u32,
+    pub hash_rate: f64,
+    pub accepted_shares: u64,
+    pub errors: u64,
+    pub engine: String,
 }
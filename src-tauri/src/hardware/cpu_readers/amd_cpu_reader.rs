@@ -20,25 +20,30 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::ops::Deref;
-
 use anyhow::Error;
 use async_trait::async_trait;
-use sysinfo::{Component, Components, CpuRefreshKind, RefreshKind, System};
+use sysinfo::{CpuRefreshKind, RefreshKind, System};
 
 use crate::{
-    hardware::hardware_status_monitor::DeviceParameters,
+    hardware::{
+        hardware_status_monitor::DeviceParameters,
+        sysfs::{cpu_temperature, Sysfs},
+    },
     utils::platform_utils::{CurrentOperatingSystem, PlatformUtils},
 };
 
 use super::CpuParametersReader;
 
 #[derive(Clone)]
-pub struct AmdCpuParametersReader {}
+pub struct AmdCpuParametersReader {
+    sysfs: Sysfs,
+}
 
 impl AmdCpuParametersReader {
     pub fn new() -> Self {
-        Self {}
+        Self {
+            sysfs: Sysfs::new(),
+        }
     }
 }
 
@@ -58,19 +63,8 @@ impl CpuParametersReader for AmdCpuParametersReader {
     ) -> Result<DeviceParameters, Error> {
         let mut system =
             System::new_with_specifics(RefreshKind::new().with_cpu(CpuRefreshKind::everything()));
-        let components = Components::new_with_refreshed_list();
-
-        let available_cpu_components: Vec<&Component> = components
-            .deref()
-            .iter()
-            .filter(|c| c.label().contains("k10temp Tctl"))
-            .collect();
-
-        let avarage_temperature = available_cpu_components
-            .iter()
-            .map(|c| c.temperature())
-            .sum::<f32>()
-            / available_cpu_components.len() as f32;
+        let avarage_temperature =
+            cpu_temperature(&self.sysfs.hwmon_by_name("k10temp"), "Tctl").unwrap_or(0.0);
 
         std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
         system.refresh_cpu_all();
@@ -83,6 +77,8 @@ impl CpuParametersReader for AmdCpuParametersReader {
             max_temperature: old_device_parameters.map_or(avarage_temperature, |old| {
                 old.max_temperature.max(avarage_temperature)
             }),
+            power_draw: 0.0,
+            fan_speed_percentage: 0.0,
         };
 
         Ok(device_parameters)
@@ -82,6 +82,8 @@ impl CpuParametersReader for AppleCpuParametersReader {
             max_temperature: old_device_parameters.map_or(avarage_temperature, |old| {
                 old.max_temperature.max(avarage_temperature)
             }),
+            power_draw: 0.0,
+            fan_speed_percentage: 0.0,
         };
 
         Ok(device_parameters)
@@ -27,37 +27,28 @@ use async_trait::async_trait;
 use sysinfo::{Component, Components, CpuRefreshKind, RefreshKind, System};
 
 use crate::{
-    hardware::hardware_status_monitor::DeviceParameters,
+    hardware::{
+        hardware_status_monitor::DeviceParameters,
+        sysfs::{cpu_temperature, Sysfs},
+    },
     utils::platform_utils::{CurrentOperatingSystem, PlatformUtils},
 };
 
 use super::CpuParametersReader;
 
 #[derive(Clone)]
-pub struct IntelCpuParametersReader {}
+pub struct IntelCpuParametersReader {
+    sysfs: Sysfs,
+}
 
 impl IntelCpuParametersReader {
     pub fn new() -> Self {
-        Self {}
-    }
-}
-
-#[async_trait]
-impl CpuParametersReader for IntelCpuParametersReader {
-    fn get_is_reader_implemented(&self) -> bool {
-        match PlatformUtils::detect_current_os() {
-            CurrentOperatingSystem::Windows => true,
-            CurrentOperatingSystem::Linux => true,
-            CurrentOperatingSystem::MacOS => true,
+        Self {
+            sysfs: Sysfs::new(),
         }
     }
 
-    async fn get_device_parameters(
-        &self,
-        old_device_parameters: Option<DeviceParameters>,
-    ) -> Result<DeviceParameters, Error> {
-        let mut system =
-            System::new_with_specifics(RefreshKind::new().with_cpu(CpuRefreshKind::everything()));
+    fn sysinfo_temperature(&self) -> f32 {
         let components = Components::new_with_refreshed_list();
 
         let available_cpu_components: Vec<&Component> = match PlatformUtils::detect_current_os() {
@@ -78,11 +69,37 @@ impl CpuParametersReader for IntelCpuParametersReader {
                 .collect(),
         };
 
-        let avarage_temperature = available_cpu_components
+        available_cpu_components
             .iter()
             .map(|c| c.temperature())
             .sum::<f32>()
-            / available_cpu_components.len() as f32;
+            / available_cpu_components.len() as f32
+    }
+}
+
+#[async_trait]
+impl CpuParametersReader for IntelCpuParametersReader {
+    fn get_is_reader_implemented(&self) -> bool {
+        match PlatformUtils::detect_current_os() {
+            CurrentOperatingSystem::Windows => true,
+            CurrentOperatingSystem::Linux => true,
+            CurrentOperatingSystem::MacOS => true,
+        }
+    }
+
+    async fn get_device_parameters(
+        &self,
+        old_device_parameters: Option<DeviceParameters>,
+    ) -> Result<DeviceParameters, Error> {
+        let mut system =
+            System::new_with_specifics(RefreshKind::new().with_cpu(CpuRefreshKind::everything()));
+        let avarage_temperature = match PlatformUtils::detect_current_os() {
+            CurrentOperatingSystem::Linux => {
+                cpu_temperature(&self.sysfs.hwmon_by_name("coretemp"), "Package id")
+                    .unwrap_or_else(|| self.sysinfo_temperature())
+            }
+            _ => self.sysinfo_temperature(),
+        };
 
         std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
         system.refresh_cpu_all();
@@ -95,6 +112,8 @@ impl CpuParametersReader for IntelCpuParametersReader {
             max_temperature: old_device_parameters.map_or(avarage_temperature, |old| {
                 old.max_temperature.max(avarage_temperature)
             }),
+            power_draw: 0.0,
+            fan_speed_percentage: 0.0,
         };
 
         Ok(device_parameters)
@@ -20,22 +20,41 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use anyhow::{Error, Ok};
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Error};
 use async_trait::async_trait;
 
 use crate::{
-    hardware::hardware_status_monitor::DeviceParameters,
+    hardware::{
+        hardware_status_monitor::DeviceParameters,
+        sysfs::{gpu_parameters, Sysfs, INTEL_PCI_VENDOR_ID},
+    },
     utils::platform_utils::{CurrentOperatingSystem, PlatformUtils},
 };
 
 use super::GpuParametersReader;
 
 #[derive(Clone)]
-pub struct IntelGpuReader {}
+pub struct IntelGpuReader {
+    sysfs: Sysfs,
+    /// Position among the Intel GPUs, matching the order of their DRM cards
+    vendor_device_index: usize,
+}
 
 impl IntelGpuReader {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(vendor_device_index: usize) -> Self {
+        Self {
+            sysfs: Sysfs::new(),
+            vendor_device_index,
+        }
+    }
+
+    fn drm_device(&self) -> Option<PathBuf> {
+        self.sysfs
+            .drm_devices(INTEL_PCI_VENDOR_ID)
+            .into_iter()
+            .nth(self.vendor_device_index)
     }
 }
 
@@ -44,19 +63,27 @@ impl GpuParametersReader for IntelGpuReader {
     fn get_is_reader_implemented(&self) -> bool {
         match PlatformUtils::detect_current_os() {
             CurrentOperatingSystem::Windows => false,
-            CurrentOperatingSystem::Linux => false,
+            CurrentOperatingSystem::Linux => self.drm_device().is_some(),
             CurrentOperatingSystem::MacOS => false,
         }
     }
+
     async fn get_device_parameters(
         &self,
-        _old_device_parameters: Option<DeviceParameters>,
+        old_device_parameters: Option<DeviceParameters>,
     ) -> Result<DeviceParameters, Error> {
-        let device_parameters = DeviceParameters {
-            usage_percentage: 0.0,
-            current_temperature: 0.0,
-            max_temperature: 0.0,
-        };
-        Ok(device_parameters)
+        if !matches!(
+            PlatformUtils::detect_current_os(),
+            CurrentOperatingSystem::Linux
+        ) {
+            return Ok(DeviceParameters::default());
+        }
+        let drm_device = self.drm_device().ok_or_else(|| {
+            anyhow!(
+                "No Intel GPU found in sysfs for device {}",
+                self.vendor_device_index
+            )
+        })?;
+        Ok(gpu_parameters(&drm_device, old_device_parameters))
     }
 }
@@ -66,6 +66,8 @@ impl GpuParametersReader for DefaultGpuParametersReader {
             usage_percentage: 0.0,
             current_temperature: 0.0,
             max_temperature: 0.0,
+            power_draw: 0.0,
+            fan_speed_percentage: 0.0,
         };
         Ok(device_parameters)
     }
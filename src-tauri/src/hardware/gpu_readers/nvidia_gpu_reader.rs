@@ -81,6 +81,11 @@ impl GpuParametersReader for NvidiaGpuReader {
             .temperature(TemperatureSensor::Gpu)
             .map_err(|e| anyhow!("Failed to get Nvidia GPU temperature: {}", e))?
             as f32;
+        // Not every board reports these, so they don't fail the whole reading
+        let power_draw = main_device
+            .power_usage()
+            .map_or(0.0, |milliwatts| milliwatts as f32 / 1000.0);
+        let fan_speed_percentage = main_device.fan_speed(0).map_or(0.0, |speed| speed as f32);
 
         let device_parameters = DeviceParameters {
             usage_percentage,
@@ -88,6 +93,8 @@ impl GpuParametersReader for NvidiaGpuReader {
             max_temperature: old_device_parameters.map_or(current_temperature, |old| {
                 old.max_temperature.max(current_temperature)
             }),
+            power_draw,
+            fan_speed_percentage,
         };
         Ok(device_parameters)
     }
@@ -24,6 +24,7 @@ use std::{path::PathBuf, sync::LazyLock};
 
 use crate::{
     hardware::{cpu_readers::DefaultCpuParametersReader, gpu_readers::DefaultGpuParametersReader},
+    utils::platform_utils::{CurrentOperatingSystem, PlatformUtils},
     APPLICATION_FOLDER_ID,
 };
 
@@ -47,7 +48,7 @@ const LOG_TARGET: &str = "tari::universe::auto_launcher";
 
 static INSTANCE: LazyLock<HardwareStatusMonitor> = LazyLock::new(HardwareStatusMonitor::new);
 
-#[derive(Debug, Serialize, Clone, Default)]
+#[derive(Debug, Serialize, Clone, Default, PartialEq)]
 pub enum HardwareVendor {
     Nvidia,
     Amd,
@@ -99,6 +100,7 @@ struct GpuStatusFileContent {
 struct GpuStatusFileEntry {
     is_available: bool,
     device_name: String,
+    device_index: Option<u32>,
 }
 
 #[derive(Debug, Serialize, Clone, Default)]
@@ -106,6 +108,9 @@ pub struct DeviceParameters {
     pub usage_percentage: f32,
     pub current_temperature: f32,
     pub max_temperature: f32,
+    /// Watts, 0 when the driver doesn't report it
+    pub power_draw: f32,
+    pub fan_speed_percentage: f32,
 }
 #[derive(Debug, Serialize, Clone, Default)]
 pub struct DeviceStatus {
@@ -115,6 +120,8 @@ pub struct DeviceStatus {
 
 #[derive(Debug, Serialize, Clone, Default)]
 pub struct PublicDeviceProperties {
+    /// glytex device index for GPUs, core position for CPUs. Readings are matched on this, not on list order
+    pub device_index: u32,
     pub vendor: HardwareVendor,
     pub name: String,
     pub status: DeviceStatus,
@@ -173,11 +180,12 @@ impl HardwareStatusMonitor {
     async fn select_reader_for_gpu_device(
         &self,
         vendor: HardwareVendor,
+        vendor_device_index: usize,
     ) -> Box<dyn GpuParametersReader> {
         match vendor {
             HardwareVendor::Nvidia => Box::new(NvidiaGpuReader::new()),
-            HardwareVendor::Amd => Box::new(AmdGpuReader::new()),
-            HardwareVendor::Intel => Box::new(IntelGpuReader::new()),
+            HardwareVendor::Amd => Box::new(AmdGpuReader::new(vendor_device_index)),
+            HardwareVendor::Intel => Box::new(IntelGpuReader::new(vendor_device_index)),
             HardwareVendor::Apple => Box::new(AppleGpuReader::new()),
             _ => {
                 warn!("Unsupported GPU vendor: {:?}", vendor);
@@ -193,15 +201,26 @@ impl HardwareStatusMonitor {
         let gpu_status_file_content = self.load_gpu_devices_from_status_file(config_dir).await?;
         let mut platform_devices = Vec::new();
 
-        for gpu_device in &gpu_status_file_content.gpu_devices {
+        for (position, gpu_device) in gpu_status_file_content.gpu_devices.iter().enumerate() {
             debug!(target: LOG_TARGET, "GPU device name: {:?}", gpu_device.device_name);
             let vendor = HardwareVendor::from_string(&gpu_device.device_name);
-            let device_reader = self.select_reader_for_gpu_device(vendor.clone()).await;
+            // sysfs readers pick the n-th card of their vendor
+            let vendor_device_index = platform_devices
+                .iter()
+                .filter(|device: &&GpuDeviceProperties| device.public_properties.vendor == vendor)
+                .count();
+            let device_reader = self
+                .select_reader_for_gpu_device(vendor.clone(), vendor_device_index)
+                .await;
             let platform_device = GpuDeviceProperties {
                 private_properties: PrivateGpuDeviceProperties {
                     device_reader: device_reader.clone(),
                 },
                 public_properties: PublicDeviceProperties {
+                    device_index: gpu_device
+                        .device_index
+                        .or_else(|| u32::try_from(position).ok())
+                        .unwrap_or_default(),
                     vendor: vendor.clone(),
                     name: gpu_device.device_name.clone(),
                     status: DeviceStatus {
@@ -245,18 +264,25 @@ impl HardwareStatusMonitor {
 
         let mut cpu_devices = vec![];
 
-        for cpu_device in system.cpus() {
+        for (position, cpu_device) in system.cpus().iter().enumerate() {
             debug!(target: LOG_TARGET, "CPU brand: {:?}", cpu_device.brand());
             debug!(target: LOG_TARGET, "CPU vendor: {:?}", cpu_device.vendor_id());
             debug!(target: LOG_TARGET, "CPU model: {:?}", cpu_device.name());
 
-            let vendor = HardwareVendor::Intel;
+            let vendor = match PlatformUtils::detect_current_os() {
+                // The sysfs readers depend on the temperature driver of the vendor
+                CurrentOperatingSystem::Linux => {
+                    HardwareVendor::from_string(cpu_device.vendor_id())
+                }
+                _ => HardwareVendor::Intel,
+            };
             let device_reader = self.select_reader_for_cpu_device(vendor.clone()).await;
             let platform_device = CpuDeviceProperties {
                 private_properties: PrivateCpuDeviceProperties {
                     device_reader: device_reader.clone(),
                 },
                 public_properties: PublicDeviceProperties {
+                    device_index: u32::try_from(position).unwrap_or_default(),
                     vendor: vendor.clone(),
                     name: cpu_device.brand().to_string(),
                     status: DeviceStatus {
@@ -312,6 +338,7 @@ impl HardwareStatusMonitor {
 
         for device in gpu_devices.iter() {
             platform_devices.push(PublicDeviceProperties {
+                device_index: device.public_properties.device_index,
                 vendor: device.public_properties.vendor.clone(),
                 name: device.public_properties.name.clone(),
                 status: device.public_properties.status.clone(),
@@ -334,6 +361,7 @@ impl HardwareStatusMonitor {
 
         for device in cpu_devices.iter() {
             platform_devices.push(PublicDeviceProperties {
+                device_index: device.public_properties.device_index,
                 vendor: device.public_properties.vendor.clone(),
                 name: device.public_properties.name.clone(),
                 status: device.public_properties.status.clone(),
@@ -22,5 +22,7 @@
 
 mod cpu_readers;
 mod gpu_readers;
+mod sysfs;
 
 pub mod hardware_status_monitor;
+pub mod thermal_governor;
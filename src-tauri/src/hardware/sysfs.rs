@@ -0,0 +1,211 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::fs::read_dir;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use super::hardware_status_monitor::DeviceParameters;
+
+pub const AMD_PCI_VENDOR_ID: &str = "0x1002";
+pub const INTEL_PCI_VENDOR_ID: &str = "0x8086";
+
+/// Reads device sensors exposed by Linux drivers, rooted at `/sys` outside of tests
+#[derive(Clone, Debug)]
+pub struct Sysfs {
+    root: PathBuf,
+}
+
+impl Sysfs {
+    pub fn new() -> Self {
+        Self::with_root("/sys")
+    }
+
+    pub fn with_root(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// hwmon directories registered by the given driver, e.g. `k10temp` or `coretemp`
+    pub fn hwmon_by_name(&self, name: &str) -> Vec<PathBuf> {
+        sorted_entries(&self.root.join("class").join("hwmon"), "hwmon")
+            .into_iter()
+            .filter(|hwmon| read_value::<String>(&hwmon.join("name")).is_some_and(|n| n == name))
+            .collect()
+    }
+
+    /// PCI device directories of the DRM cards from the given vendor, in card order
+    pub fn drm_devices(&self, pci_vendor_id: &str) -> Vec<PathBuf> {
+        sorted_entries(&self.root.join("class").join("drm"), "card")
+            .into_iter()
+            .map(|card| card.join("device"))
+            .filter(|device| {
+                read_value::<String>(&device.join("vendor")).is_some_and(|v| v == pci_vendor_id)
+            })
+            .collect()
+    }
+}
+
+/// Sensor values of a GPU from its PCI device directory. Readings the driver doesn't expose are 0.
+pub fn gpu_parameters(
+    device: &Path,
+    old_device_parameters: Option<DeviceParameters>,
+) -> DeviceParameters {
+    let hwmon = sorted_entries(&device.join("hwmon"), "hwmon")
+        .into_iter()
+        .next();
+    let hwmon_value = |file: &str| {
+        hwmon
+            .as_ref()
+            .and_then(|hwmon| read_value::<f32>(&hwmon.join(file)))
+    };
+    let usage_percentage = read_value::<f32>(&device.join("gpu_busy_percent")).unwrap_or(0.0);
+    // amdgpu reports the edge sensor first, xe only has the package sensor as temp2
+    let current_temperature = (1..=3)
+        .find_map(|index| hwmon_value(&format!("temp{index}_input")))
+        .map_or(0.0, |millidegrees| millidegrees / 1000.0);
+    let power_draw = hwmon_value("power1_average")
+        .or_else(|| hwmon_value("power1_input"))
+        .map_or(0.0, |microwatts| microwatts / 1_000_000.0);
+    let fan_speed_percentage = hwmon_value("pwm1").map_or(0.0, |pwm| pwm * 100.0 / 255.0);
+
+    DeviceParameters {
+        usage_percentage,
+        current_temperature,
+        max_temperature: old_device_parameters.map_or(current_temperature, |old| {
+            old.max_temperature.max(current_temperature)
+        }),
+        power_draw,
+        fan_speed_percentage,
+    }
+}
+
+/// Average of the temperature sensors whose label starts with `label_prefix`, e.g. the `Tctl` of
+/// k10temp or the `Package id` sensors of coretemp
+pub fn cpu_temperature(hwmons: &[PathBuf], label_prefix: &str) -> Option<f32> {
+    let temperatures: Vec<f32> = hwmons
+        .iter()
+        .flat_map(|hwmon| {
+            (1..=64).filter_map(move |index| {
+                let label = read_value::<String>(&hwmon.join(format!("temp{index}_label")))?;
+                if !label.starts_with(label_prefix) {
+                    return None;
+                }
+                read_value::<f32>(&hwmon.join(format!("temp{index}_input")))
+            })
+        })
+        .map(|millidegrees| millidegrees / 1000.0)
+        .collect();
+    if temperatures.is_empty() {
+        return None;
+    }
+    Some(temperatures.iter().sum::<f32>() / temperatures.len() as f32)
+}
+
+pub fn read_value<T: FromStr>(path: &Path) -> Option<T> {
+    std::fs::read_to_string(path).ok()?.trim().parse().ok()
+}
+
+/// Entries named `<prefix><number>`, ordered by number so `card10` comes after `card2`
+fn sorted_entries(directory: &Path, prefix: &str) -> Vec<PathBuf> {
+    let Ok(entries) = read_dir(directory) else {
+        return Vec::new();
+    };
+    let mut entries: Vec<(u32, PathBuf)> = entries
+        .filter_map(|entry| {
+            let entry = entry.ok()?;
+            let number = entry
+                .file_name()
+                .to_str()?
+                .strip_prefix(prefix)?
+                .parse::<u32>()
+                .ok()?;
+            Some((number, entry.path()))
+        })
+        .collect();
+    entries.sort_by_key(|(number, _)| *number);
+    entries.into_iter().map(|(_, path)| path).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn fixture() -> Sysfs {
+        Sysfs::with_root(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/sysfs"))
+    }
+
+    #[test]
+    fn finds_drm_devices_by_vendor_in_card_order() {
+        let sysfs = fixture();
+        let amd_devices = sysfs.drm_devices(AMD_PCI_VENDOR_ID);
+        assert_eq!(amd_devices.len(), 2);
+        assert!(amd_devices[0].ends_with("card0/device"));
+        assert!(amd_devices[1].ends_with("card10/device"));
+        assert_eq!(sysfs.drm_devices(INTEL_PCI_VENDOR_ID).len(), 1);
+    }
+
+    #[test]
+    fn reads_amd_gpu_parameters() {
+        let device = fixture().drm_devices(AMD_PCI_VENDOR_ID).remove(0);
+        let parameters = gpu_parameters(
+            &device,
+            Some(DeviceParameters {
+                max_temperature: 80.0,
+                ..DeviceParameters::default()
+            }),
+        );
+        assert_eq!(parameters.usage_percentage, 97.0);
+        assert_eq!(parameters.current_temperature, 65.0);
+        assert_eq!(parameters.max_temperature, 80.0);
+        assert_eq!(parameters.power_draw, 187.0);
+        assert_eq!(parameters.fan_speed_percentage, 60.0);
+    }
+
+    #[test]
+    fn reads_intel_gpu_parameters_without_busy_percent() {
+        let device = fixture().drm_devices(INTEL_PCI_VENDOR_ID).remove(0);
+        let parameters = gpu_parameters(&device, None);
+        assert_eq!(parameters.usage_percentage, 0.0);
+        assert_eq!(parameters.current_temperature, 52.0);
+        assert_eq!(parameters.max_temperature, 52.0);
+        assert_eq!(parameters.power_draw, 35.5);
+        assert_eq!(parameters.fan_speed_percentage, 0.0);
+    }
+
+    #[test]
+    fn reads_cpu_temperatures() {
+        let sysfs = fixture();
+        assert_eq!(
+            cpu_temperature(&sysfs.hwmon_by_name("k10temp"), "Tctl"),
+            Some(71.5)
+        );
+        assert_eq!(
+            cpu_temperature(&sysfs.hwmon_by_name("coretemp"), "Package id"),
+            Some(58.0)
+        );
+        assert_eq!(
+            cpu_temperature(&sysfs.hwmon_by_name("zenpower"), "Tctl"),
+            None
+        );
+    }
+}
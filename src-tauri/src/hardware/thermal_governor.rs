@@ -0,0 +1,370 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        LazyLock,
+    },
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use log::{error, info};
+use serde::Serialize;
+use tauri::{AppHandle, Manager};
+use tokio::{select, sync::RwLock, time::MissedTickBehavior};
+
+use crate::{
+    commands,
+    configs::{config_mining::ConfigMining, trait_config::ConfigImpl},
+    events_emitter::EventsEmitter,
+    mining_benchmark::MiningBenchmark,
+    setup::setup_manager::SetupManager,
+    tasks_tracker::TasksTrackers,
+    utils::mining_control_utils,
+    UniverseAppState,
+};
+
+use super::hardware_status_monitor::{HardwareStatusMonitor, PublicDeviceProperties};
+
+const LOG_TARGET: &str = "tari::universe::thermal_governor";
+const GOVERNOR_CHECK_INTERVAL: Duration = Duration::from_secs(10);
+/// Gives the device time to react to the previous action before it is throttled or resumed again
+const MIN_TIME_BETWEEN_ACTIONS: Duration = Duration::from_secs(30);
+const MAX_RECORDED_ACTIONS: usize = 100;
+/// Every throttle level halves the load of the device, the last one pauses it
+const PAUSED_THROTTLE_LEVEL: u8 = 3;
+
+static INSTANCE: LazyLock<ThermalGovernor> = LazyLock::new(ThermalGovernor::new);
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq, Eq, Hash)]
+pub enum ThermalDevice {
+    Cpu,
+    Gpu(u32),
+}
+
+#[derive(Debug, Clone, Copy, Serialize, PartialEq)]
+pub enum ThermalActionKind {
+    Throttled,
+    Paused,
+    Resumed,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ThermalGovernorAction {
+    pub device: ThermalDevice,
+    pub kind: ThermalActionKind,
+    pub temperature: f32,
+    pub max_temperature: f32,
+    pub throttle_level: u8,
+    pub load_percentage: u32,
+    pub timestamp: u64,
+}
+
+#[derive(Default)]
+struct DeviceThrottle {
+    level: u8,
+    last_change: Option<Instant>,
+}
+
+/// Lowers the load of CPU and GPU miners when a device crosses its temperature ceiling from `ConfigMining`.
+/// The running xmrig takes a new thread count through its HTTP API, while glytex only reads its load
+/// on start, so GPU changes restart it.
+pub struct ThermalGovernor {
+    throttles: RwLock<HashMap<ThermalDevice, DeviceThrottle>>,
+    actions: RwLock<VecDeque<ThermalGovernorAction>>,
+    is_cpu_miner_paused: AtomicBool,
+    is_gpu_miner_paused: AtomicBool,
+}
+
+impl ThermalGovernor {
+    fn new() -> Self {
+        Self {
+            throttles: RwLock::new(HashMap::new()),
+            actions: RwLock::new(VecDeque::new()),
+            is_cpu_miner_paused: AtomicBool::new(false),
+            is_gpu_miner_paused: AtomicBool::new(false),
+        }
+    }
+
+    pub fn current() -> &'static ThermalGovernor {
+        &INSTANCE
+    }
+
+    pub async fn start(app_handle: AppHandle) {
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let mut interval = tokio::time::interval(GOVERNOR_CHECK_INTERVAL);
+                interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+                loop {
+                    select! {
+                        _ = interval.tick() => {
+                            Self::current().evaluate(&app_handle).await;
+                        }
+                        _ = shutdown_signal.wait() => {
+                            info!(target: LOG_TARGET, "Shutdown signal received, stopping thermal governor");
+                            break;
+                        }
+                    }
+                }
+            });
+    }
+
+    pub fn scale(value: u32, load_percentage: u32) -> u32 {
+        (value.saturating_mul(load_percentage) / 100).max(1)
+    }
+
+    pub async fn cpu_load_percentage(&self) -> u32 {
+        self.load_percentage(ThermalDevice::Cpu).await
+    }
+
+    pub async fn gpu_load_percentage(&self, device_index: u32) -> u32 {
+        self.load_percentage(ThermalDevice::Gpu(device_index)).await
+    }
+
+    pub async fn get_actions(&self) -> Vec<ThermalGovernorAction> {
+        self.actions.read().await.iter().cloned().collect()
+    }
+
+    async fn load_percentage(&self, device: ThermalDevice) -> u32 {
+        let level = self
+            .throttles
+            .read()
+            .await
+            .get(&device)
+            .map_or(0, |throttle| throttle.level);
+        100 >> level.min(PAUSED_THROTTLE_LEVEL)
+    }
+
+    /// Miners are not started while the device is paused, the governor starts them again once it has cooled down
+    pub async fn is_cpu_paused(&self) -> bool {
+        self.is_paused(ThermalDevice::Cpu).await
+    }
+
+    /// glytex runs all GPUs in a single process, so one paused GPU holds back the whole GPU miner
+    pub async fn is_gpu_paused(&self) -> bool {
+        self.throttles
+            .read()
+            .await
+            .iter()
+            .any(|(device, throttle)| {
+                matches!(device, ThermalDevice::Gpu(_)) && throttle.level >= PAUSED_THROTTLE_LEVEL
+            })
+    }
+
+    async fn is_paused(&self, device: ThermalDevice) -> bool {
+        self.throttles
+            .read()
+            .await
+            .get(&device)
+            .is_some_and(|throttle| throttle.level >= PAUSED_THROTTLE_LEVEL)
+    }
+
+    async fn evaluate(&self, app_handle: &AppHandle) {
+        // The benchmark starts and stops the miners itself while it runs
+        if MiningBenchmark::is_running() {
+            return;
+        }
+        let setup_manager = SetupManager::get_instance();
+        if !setup_manager.is_cpu_mining_unlocked().await
+            || !setup_manager.is_gpu_mining_unlocked().await
+        {
+            return;
+        }
+
+        let config = ConfigMining::content().await;
+        let mut readings = Vec::new();
+        if *config.thermal_governor_enabled() {
+            if let Some(max_temperature) = *config.cpu_max_temperature() {
+                let cpu_devices = HardwareStatusMonitor::current()
+                    .get_cpu_public_properties()
+                    .await
+                    .unwrap_or_default();
+                // Every core is reported as its own device, the hottest one decides
+                if let Some(temperature) = max_temperature_of(&cpu_devices) {
+                    readings.push((ThermalDevice::Cpu, temperature, max_temperature));
+                }
+            }
+
+            let gpu_devices = HardwareStatusMonitor::current()
+                .get_gpu_public_properties()
+                .await
+                .unwrap_or_default();
+            for ceiling in config.gpu_max_temperatures() {
+                let temperature = gpu_devices
+                    .iter()
+                    .find(|device| device.device_index == ceiling.device_index)
+                    .and_then(|device| max_temperature_of(std::slice::from_ref(device)));
+                if let Some(temperature) = temperature {
+                    readings.push((
+                        ThermalDevice::Gpu(ceiling.device_index),
+                        temperature,
+                        ceiling.max_temperature,
+                    ));
+                }
+            }
+        }
+
+        let mut is_cpu_changed = false;
+        let mut is_gpu_changed = false;
+        let mut actions = Vec::new();
+        let mut throttles = self.throttles.write().await;
+        for (device, temperature, max_temperature) in readings.iter().copied() {
+            let throttle = throttles.entry(device).or_default();
+            if throttle
+                .last_change
+                .is_some_and(|last_change| last_change.elapsed() < MIN_TIME_BETWEEN_ACTIONS)
+            {
+                continue;
+            }
+
+            let kind = if temperature >= max_temperature && throttle.level < PAUSED_THROTTLE_LEVEL {
+                throttle.level += 1;
+                if throttle.level == PAUSED_THROTTLE_LEVEL {
+                    ThermalActionKind::Paused
+                } else {
+                    ThermalActionKind::Throttled
+                }
+            } else if temperature <= max_temperature - *config.thermal_hysteresis()
+                && throttle.level > 0
+            {
+                throttle.level -= 1;
+                ThermalActionKind::Resumed
+            } else {
+                continue;
+            };
+            throttle.last_change = Some(Instant::now());
+
+            match device {
+                ThermalDevice::Cpu => is_cpu_changed = true,
+                ThermalDevice::Gpu(_) => is_gpu_changed = true,
+            }
+            actions.push(ThermalGovernorAction {
+                device,
+                kind,
+                temperature,
+                max_temperature,
+                throttle_level: throttle.level,
+                load_percentage: 100 >> throttle.level,
+                timestamp: SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .unwrap_or_default()
+                    .as_secs(),
+            });
+        }
+
+        // Devices that are no longer governed, e.g. after disabling the governor or removing a ceiling, are released at once
+        let governed_devices: Vec<ThermalDevice> =
+            readings.iter().map(|(device, _, _)| *device).collect();
+        throttles.retain(|device, throttle| {
+            let is_released = throttle.level > 0 && !governed_devices.contains(device);
+            if is_released {
+                info!(target: LOG_TARGET, "Releasing thermal throttling of {:?}", device);
+                match device {
+                    ThermalDevice::Cpu => is_cpu_changed = true,
+                    ThermalDevice::Gpu(_) => is_gpu_changed = true,
+                }
+            }
+            !is_released
+        });
+        drop(throttles);
+
+        for action in actions {
+            self.record_action(action).await;
+        }
+        if is_cpu_changed {
+            self.apply_cpu_throttle(app_handle).await;
+        }
+        if is_gpu_changed {
+            self.apply_gpu_throttle(app_handle).await;
+        }
+    }
+
+    async fn record_action(&self, action: ThermalGovernorAction) {
+        info!(target: LOG_TARGET, "{:?} {:?} at {:.1}°C (max {:.1}°C), load is now {}%", action.kind, action.device, action.temperature, action.max_temperature, action.load_percentage);
+        let mut actions = self.actions.write().await;
+        if actions.len() >= MAX_RECORDED_ACTIONS {
+            actions.pop_front();
+        }
+        actions.push_back(action.clone());
+        drop(actions);
+        EventsEmitter::emit_thermal_governor_action(action).await;
+    }
+
+    async fn apply_cpu_throttle(&self, app_handle: &AppHandle) {
+        let state = app_handle.state::<UniverseAppState>();
+        let is_running = state.cpu_miner.read().await.is_running().await;
+        let is_paused = self.is_cpu_paused().await;
+
+        match (is_paused, is_running) {
+            (true, true) => {
+                if let Err(e) = commands::stop_cpu_mining(state).await {
+                    error!(target: LOG_TARGET, "Could not stop CPU mining: {}", e);
+                    return;
+                }
+                self.is_cpu_miner_paused.store(true, Ordering::SeqCst);
+            }
+            (true, false) => {}
+            (false, true) => {
+                // Only the thread count changes, xmrig can take that without a restart
+                mining_control_utils::reconfigure_cpu_mining(app_handle).await;
+            }
+            (false, false) => {
+                if self.is_cpu_miner_paused.swap(false, Ordering::SeqCst) {
+                    // Goes through the shared start path so a battery policy hold is respected
+                    mining_control_utils::start_cpu_mining(app_handle).await;
+                }
+            }
+        }
+    }
+
+    /// glytex runs all GPUs in a single process, so pausing one of them stops the GPU miner until it cools down
+    async fn apply_gpu_throttle(&self, app_handle: &AppHandle) {
+        let state = app_handle.state::<UniverseAppState>();
+        let is_running = state.gpu_miner.read().await.is_running().await;
+        let is_paused = self.is_gpu_paused().await;
+
+        if is_running {
+            if let Err(e) = commands::stop_gpu_mining(state).await {
+                error!(target: LOG_TARGET, "Could not stop GPU mining: {}", e);
+                return;
+            }
+            self.is_gpu_miner_paused.store(is_paused, Ordering::SeqCst);
+        }
+        if !is_paused && (is_running || self.is_gpu_miner_paused.swap(false, Ordering::SeqCst)) {
+            mining_control_utils::start_gpu_mining(app_handle).await;
+        }
+    }
+}
+
+pub(crate) fn max_temperature_of(devices: &[PublicDeviceProperties]) -> Option<f32> {
+    devices
+        .iter()
+        .filter_map(|device| device.parameters.as_ref())
+        .map(|parameters| parameters.current_temperature)
+        .reduce(f32::max)
+}
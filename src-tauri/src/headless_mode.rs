@@ -0,0 +1,159 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    LazyLock,
+};
+
+use log::{error, info, warn};
+use serde::Serialize;
+use serde_json::Value;
+use tauri::{AppHandle, Manager};
+
+use crate::{
+    commands,
+    configs::{config_mining::ConfigMining, trait_config::ConfigImpl},
+    tasks_tracker::TasksTrackers,
+    UniverseAppState,
+};
+
+static LOG_TARGET: &str = "tari::universe::headless_mode";
+static INSTANCE: LazyLock<HeadlessMode> = LazyLock::new(HeadlessMode::new);
+
+const PROGRESS_TRACKER_UPDATE: &str = "progress_tracker_update";
+// Events that are worth printing when there is no UI to show them, everything else is too noisy for stdout
+const PRINTED_EVENT_TYPES: [&str; 14] = [
+    "CriticalProblem",
+    "ProcessWatcherGaveUp",
+    "CorePhaseFinished",
+    "HardwarePhaseFinished",
+    "NodePhaseFinished",
+    "WalletPhaseFinished",
+    "MiningPhaseFinished",
+    "InitialSetupFinished",
+    "RestartingPhases",
+    "UnlockCpuMining",
+    "UnlockGpuMining",
+    "LockCpuMining",
+    "LockGpuMining",
+    "NewBlockHeight",
+];
+
+pub struct HeadlessMode {
+    is_enabled: AtomicBool,
+}
+
+impl HeadlessMode {
+    fn new() -> Self {
+        Self {
+            is_enabled: AtomicBool::new(false),
+        }
+    }
+
+    pub fn current() -> &'static HeadlessMode {
+        &INSTANCE
+    }
+
+    pub fn enable(&self) {
+        self.is_enabled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.is_enabled.load(Ordering::SeqCst)
+    }
+
+    pub fn print_event<S: Serialize>(event_name: &str, event: &S) {
+        let Ok(event) = serde_json::to_value(event) else {
+            return;
+        };
+        let payload = event.get("payload").cloned().unwrap_or(Value::Null);
+
+        if event_name == PROGRESS_TRACKER_UPDATE {
+            println!(
+                "[{}] {:.0}% {}",
+                payload["phase_title"].as_str().unwrap_or_default(),
+                payload["progress"].as_f64().unwrap_or_default(),
+                payload["title"].as_str().unwrap_or_default()
+            );
+            return;
+        }
+
+        let event_type = event["event_type"].as_str().unwrap_or_default();
+        if PRINTED_EVENT_TYPES.contains(&event_type) {
+            println!("[{}] {}", event_type, payload);
+        }
+    }
+
+    /// Mirrors what the frontend does once the setup is finished, as there is nobody to press the start button
+    pub async fn start_mining(app_handle: &AppHandle) {
+        if !*ConfigMining::content().await.mine_on_app_start() {
+            info!(target: LOG_TARGET, "Mine on app start is disabled, not starting miners");
+            return;
+        }
+        if *ConfigMining::content().await.mining_schedule_enabled() {
+            info!(target: LOG_TARGET, "Mining schedule is enabled, leaving the miners to the scheduler");
+            return;
+        }
+
+        let state = app_handle.state::<UniverseAppState>();
+        if let Err(e) = commands::start_cpu_mining(state.clone(), app_handle.clone()).await {
+            error!(target: LOG_TARGET, "Could not start CPU mining in headless mode: {}", e);
+        }
+        if let Err(e) = commands::start_gpu_mining(state, app_handle.clone()).await {
+            error!(target: LOG_TARGET, "Could not start GPU mining in headless mode: {}", e);
+        }
+    }
+
+    pub fn spawn_termination_listener(app_handle: AppHandle) {
+        // Not spawned on a TasksTrackers tracker, as it is the one closing all of them
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = wait_for_termination_signal().await {
+                warn!(target: LOG_TARGET, "Could not listen for termination signals: {:?}", e);
+                return;
+            }
+            println!("Shutting down Tari Universe");
+            TasksTrackers::current().stop_all_processes().await;
+            app_handle.exit(0);
+        });
+    }
+}
+
+#[cfg(unix)]
+async fn wait_for_termination_signal() -> Result<(), anyhow::Error> {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let mut sigterm = signal(SignalKind::terminate())?;
+    let mut sigint = signal(SignalKind::interrupt())?;
+    tokio::select! {
+        _ = sigterm.recv() => info!(target: LOG_TARGET, "Received SIGTERM"),
+        _ = sigint.recv() => info!(target: LOG_TARGET, "Received SIGINT"),
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+async fn wait_for_termination_signal() -> Result<(), anyhow::Error> {
+    tokio::signal::ctrl_c().await?;
+    info!(target: LOG_TARGET, "Received Ctrl-C");
+    Ok(())
+}
@@ -0,0 +1,257 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::time::{Duration, Instant};
+
+use device_query::{DeviceQuery, DeviceState};
+use log::{info, warn};
+use tauri::AppHandle;
+use tokio::select;
+use tokio::sync::watch;
+use tokio::time::MissedTickBehavior;
+
+use crate::configs::config_mining::{ConfigMining, MiningMode};
+use crate::configs::trait_config::ConfigImpl;
+use crate::events::IdleMiningUpdatePayload;
+use crate::events_emitter::EventsEmitter;
+use crate::mining_benchmark::MiningBenchmark;
+use crate::setup::setup_manager::SetupManager;
+use crate::tasks_tracker::TasksTrackers;
+use crate::utils::mining_control_utils;
+
+const LOG_TARGET: &str = "tari::universe::idle_mining";
+const INPUT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+const IDLE_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// What was running before the user went idle, restored as soon as they return
+struct ActiveState {
+    mode: MiningMode,
+    cpu_mining: bool,
+    gpu_mining: bool,
+}
+
+enum IdleMiningEvent {
+    Tick,
+    UserInput,
+    InputWatcherStopped,
+    Shutdown,
+}
+
+/// Starts mining, or switches to the configured idle mode, while there is no keyboard or mouse input
+pub struct IdleMining {
+    app_handle: AppHandle,
+    active_state: Option<ActiveState>,
+    /// Only set while idle mining is enabled, dropping it stops the input polling thread
+    last_input_rx: Option<watch::Receiver<Instant>>,
+    is_input_unavailable: bool,
+}
+
+impl IdleMining {
+    pub async fn start(app_handle: AppHandle) {
+        let mut idle_mining = Self {
+            app_handle,
+            active_state: None,
+            last_input_rx: None,
+            is_input_unavailable: false,
+        };
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let mut interval = tokio::time::interval(IDLE_CHECK_INTERVAL);
+                interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+                loop {
+                    let event = select! {
+                        _ = interval.tick() => IdleMiningEvent::Tick,
+                        result = wait_for_input(&mut idle_mining.last_input_rx) => match result {
+                            Ok(()) => IdleMiningEvent::UserInput,
+                            Err(_) => IdleMiningEvent::InputWatcherStopped,
+                        },
+                        _ = shutdown_signal.wait() => IdleMiningEvent::Shutdown,
+                    };
+                    match event {
+                        IdleMiningEvent::Tick => idle_mining.check_idle_time().await,
+                        IdleMiningEvent::UserInput => {
+                            // Left to the next input while the benchmark owns the miners
+                            if idle_mining.active_state.is_some() && !MiningBenchmark::is_running() {
+                                idle_mining.handle_user_returned().await;
+                            }
+                        }
+                        IdleMiningEvent::InputWatcherStopped => {
+                            warn!(target: LOG_TARGET, "User input watcher stopped, idle mining is unavailable");
+                            idle_mining.last_input_rx = None;
+                            idle_mining.is_input_unavailable = true;
+                        }
+                        IdleMiningEvent::Shutdown => {
+                            info!(target: LOG_TARGET, "Shutdown signal received, stopping idle mining");
+                            break;
+                        }
+                    }
+                }
+            });
+    }
+
+    /// Input is only watched while idle mining is enabled, reading it prompts for input monitoring permissions on macOS
+    fn update_input_watcher(&mut self, is_enabled: bool) {
+        if !is_enabled {
+            if self.last_input_rx.take().is_some() {
+                info!(target: LOG_TARGET, "Idle mining disabled, stopping user input watcher");
+            }
+            return;
+        }
+        if self.last_input_rx.is_none() && !self.is_input_unavailable {
+            info!(target: LOG_TARGET, "Idle mining enabled, starting user input watcher");
+            let (last_input_tx, last_input_rx) = watch::channel(Instant::now());
+            // device_query handles are not Send, so the input is polled from its own thread
+            std::thread::spawn(move || watch_user_input(last_input_tx));
+            self.last_input_rx = Some(last_input_rx);
+        }
+    }
+
+    async fn check_idle_time(&mut self) {
+        // The benchmark starts and stops the miners itself while it runs
+        if MiningBenchmark::is_running() {
+            return;
+        }
+        let config = ConfigMining::content().await;
+        let is_enabled = *config.idle_mining_enabled();
+        self.update_input_watcher(is_enabled);
+        if !is_enabled {
+            // Hand control back right away when idle mining is turned off while the user is away
+            if self.active_state.is_some() {
+                self.handle_user_returned().await;
+            }
+            return;
+        }
+        let Some(idle_time) = self
+            .last_input_rx
+            .as_ref()
+            .map(|last_input_rx| last_input_rx.borrow().elapsed())
+        else {
+            return;
+        };
+        if self.active_state.is_some() {
+            return;
+        }
+        if idle_time < Duration::from_secs(*config.idle_mining_timeout_secs()) {
+            return;
+        }
+        let setup_manager = SetupManager::get_instance();
+        if !setup_manager.is_cpu_mining_unlocked().await
+            || !setup_manager.is_gpu_mining_unlocked().await
+        {
+            return;
+        }
+
+        let app_handle = &self.app_handle;
+        let active_state = ActiveState {
+            mode: *config.mode(),
+            cpu_mining: mining_control_utils::is_cpu_mining(app_handle).await,
+            gpu_mining: mining_control_utils::is_gpu_mining(app_handle).await,
+        };
+        let idle_mode = *config.idle_mining_mode();
+        info!(target: LOG_TARGET, "User idle for {:?}, mining with {:?} mode", idle_time, idle_mode);
+
+        if idle_mode != active_state.mode {
+            mining_control_utils::switch_mining_mode(app_handle, idle_mode).await;
+        }
+        // A miner that is turned off in the settings stays off and is reported as not mining
+        let cpu_mining = *config.cpu_mining_enabled()
+            && mining_control_utils::start_cpu_mining(app_handle).await;
+        let gpu_mining = *config.gpu_mining_enabled()
+            && mining_control_utils::start_gpu_mining(app_handle).await;
+        self.active_state = Some(active_state);
+
+        EventsEmitter::emit_idle_mining_update(IdleMiningUpdatePayload {
+            is_idle: true,
+            mode: idle_mode,
+            cpu_mining,
+            gpu_mining,
+        })
+        .await;
+    }
+
+    async fn handle_user_returned(&mut self) {
+        let Some(active_state) = self.active_state.take() else {
+            return;
+        };
+        info!(target: LOG_TARGET, "User returned, restoring {:?} mode", active_state.mode);
+
+        let app_handle = &self.app_handle;
+        let config = ConfigMining::content().await;
+        if active_state.mode != *config.mode() {
+            mining_control_utils::switch_mining_mode(app_handle, active_state.mode).await;
+        }
+        let cpu_mining = if active_state.cpu_mining && *config.cpu_mining_enabled() {
+            mining_control_utils::start_cpu_mining(app_handle).await
+        } else {
+            mining_control_utils::stop_cpu_mining(app_handle).await;
+            false
+        };
+        let gpu_mining = if active_state.gpu_mining && *config.gpu_mining_enabled() {
+            mining_control_utils::start_gpu_mining(app_handle).await
+        } else {
+            mining_control_utils::stop_gpu_mining(app_handle).await;
+            false
+        };
+
+        EventsEmitter::emit_idle_mining_update(IdleMiningUpdatePayload {
+            is_idle: false,
+            mode: active_state.mode,
+            cpu_mining,
+            gpu_mining,
+        })
+        .await;
+    }
+}
+
+async fn wait_for_input(
+    last_input_rx: &mut Option<watch::Receiver<Instant>>,
+) -> Result<(), watch::error::RecvError> {
+    match last_input_rx {
+        Some(last_input_rx) => last_input_rx.changed().await,
+        None => std::future::pending().await,
+    }
+}
+
+fn watch_user_input(last_input_tx: watch::Sender<Instant>) {
+    // Creating the device state panics when there is no display to read from, e.g. on a headless machine
+    let Ok(device_state) = std::panic::catch_unwind(DeviceState::new) else {
+        warn!(target: LOG_TARGET, "Could not read keyboard and mouse state, idle mining is unavailable");
+        return;
+    };
+
+    let mut last_mouse_position = device_state.get_mouse().coords;
+    let mut last_keys = device_state.get_keys();
+    while !last_input_tx.is_closed() {
+        std::thread::sleep(INPUT_POLL_INTERVAL);
+        let mouse_position = device_state.get_mouse().coords;
+        let keys = device_state.get_keys();
+        if mouse_position != last_mouse_position || keys != last_keys {
+            last_mouse_position = mouse_position;
+            last_keys = keys;
+            let _unused = last_input_tx.send(Instant::now());
+        }
+    }
+}
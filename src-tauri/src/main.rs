@@ -25,18 +25,20 @@
 
 use app_in_memory_config::AppInMemoryConfig;
 use commands::CpuMinerStatus;
+use configs::config_mining::PoolConnectionOptions;
 use cpu_miner::CpuMinerConfig;
 use events_emitter::EventsEmitter;
 use events_manager::EventsManager;
 use gpu_miner_adapter::GpuMinerStatus;
+use headless_mode::HeadlessMode;
 use log::{error, info, warn};
 use mining_status_manager::MiningStatusManager;
 use node::local_node_adapter::LocalNodeAdapter;
 use node::node_adapter::BaseNodeStatus;
 use node::node_manager::NodeType;
 use p2pool::models::Connections;
-use pool_status_watcher::{PoolStatus, PoolStatusWatcher};
-use process_stats_collector::ProcessStatsCollectorBuilder;
+use pool_status_watcher::{PoolApiType, PoolStatus, PoolStatusWatcher};
+use process_stats_collector::{ProcessStatsCollector, ProcessStatsCollectorBuilder};
 
 use node::remote_node_adapter::RemoteNodeAdapter;
 
@@ -67,6 +69,7 @@ use tauri_plugin_sentry::{minidump, sentry};
 use tokio::select;
 use tokio::sync::{Mutex, RwLock};
 use tokio::time;
+use utils::app_flow_utils::FrontendReadyChannel;
 use utils::logging_utils::setup_logging;
 
 #[cfg(all(feature = "exchange-ci", not(feature = "release-ci")))]
@@ -91,10 +94,12 @@ mod ab_test_selector;
 mod airdrop;
 mod app_in_memory_config;
 mod auto_launcher;
+mod battery_policy;
 mod binaries;
 mod commands;
 mod configs;
 mod consts;
+mod control_api_server;
 mod cpu_miner;
 mod credential_manager;
 mod download_utils;
@@ -104,11 +109,18 @@ mod events_manager;
 mod external_dependencies;
 mod feedback;
 mod github;
+mod gpu_engine_fallback;
 mod gpu_miner;
 mod gpu_miner_adapter;
 mod gpu_status_file;
 mod hardware;
+mod headless_mode;
+mod idle_mining;
 mod internal_wallet;
+mod metrics_exporter;
+mod mining_benchmark;
+mod mining_history;
+mod mining_scheduler;
 mod mining_status_manager;
 mod mm_proxy_adapter;
 mod mm_proxy_manager;
@@ -117,6 +129,7 @@ mod node;
 mod p2pool;
 mod p2pool_adapter;
 mod p2pool_manager;
+mod pool_failover;
 mod pool_status_watcher;
 mod port_allocator;
 mod process_adapter;
@@ -130,6 +143,7 @@ mod release_notes;
 mod setup;
 mod spend_wallet_adapter;
 mod spend_wallet_manager;
+mod stratum_proxy;
 mod systemtray_manager;
 mod tapplets;
 mod tasks_tracker;
@@ -246,6 +260,7 @@ struct UniverseAppState {
     cpu_miner_status_watch_rx: Arc<watch::Receiver<CpuMinerStatus>>,
     gpu_latest_status: Arc<watch::Receiver<GpuMinerStatus>>,
     p2pool_latest_status: Arc<watch::Receiver<Option<P2poolStats>>>,
+    process_stats_collector: ProcessStatsCollector,
     is_getting_p2pool_connections: Arc<AtomicBool>,
     in_memory_config: Arc<RwLock<AppInMemoryConfig>>,
     cpu_miner: Arc<RwLock<CpuMiner>>,
@@ -369,8 +384,12 @@ fn main() {
         ludicrous_mode_cpu_percentage: None,
         pool_host_name: None,
         pool_port: None,
+        pool_options: PoolConnectionOptions::default(),
+        pools: vec![],
+        active_pool_index: 0,
         monero_address: "".to_string(),
         pool_status_url: None,
+        pool_api: PoolApiType::default(),
     }));
 
     let app_in_memory_config = Arc::new(RwLock::new(AppInMemoryConfig::init()));
@@ -395,6 +414,7 @@ fn main() {
     let tor_manager = TorManager::new(tor_watch_tx, &mut stats_collector);
     let mm_proxy_manager = MmProxyManager::new(&mut stats_collector);
 
+    let process_stats_collector = stats_collector.build();
     let telemetry_manager: TelemetryManager = TelemetryManager::new(
         cpu_miner_status_watch_rx.clone(),
         app_in_memory_config.clone(),
@@ -403,7 +423,7 @@ fn main() {
         base_node_watch_rx.clone(),
         p2pool_stats_rx.clone(),
         tor_watch_rx.clone(),
-        stats_collector.build(),
+        process_stats_collector.clone(),
         node_manager.clone(),
     );
 
@@ -442,6 +462,7 @@ fn main() {
         cpu_miner_status_watch_rx: Arc::new(cpu_miner_status_watch_rx),
         gpu_latest_status: Arc::new(gpu_status_rx),
         p2pool_latest_status: Arc::new(p2pool_stats_rx),
+        process_stats_collector,
         in_memory_config: app_in_memory_config.clone(),
         cpu_miner: cpu_miner.clone(),
         gpu_miner: gpu_miner.clone(),
@@ -526,6 +547,13 @@ fn main() {
             app.manage(app_state_clone);
             match app.cli().matches() {
                 Ok(matches) => {
+                    if matches
+                        .args
+                        .get("headless")
+                        .is_some_and(|arg| arg.value.as_bool().unwrap_or(false))
+                    {
+                        HeadlessMode::current().enable();
+                    }
                     if let Some(backup_path) = matches.args.get("import-backup") {
                         if let Some(backup_path) = backup_path.value.as_str() {
                             info!(
@@ -583,6 +611,24 @@ fn main() {
                     return Err(Box::new(e));
                 }
             };
+            // The main window is not created from the config, so that headless mode never spawns a webview
+            if HeadlessMode::current().is_enabled() {
+                info!(target: LOG_TARGET, "Starting in headless mode");
+                FrontendReadyChannel::current().set_ready();
+            } else if let Some(main_window_config) = app
+                .config()
+                .app
+                .windows
+                .iter()
+                .find(|window| window.label == "main")
+                .cloned()
+            {
+                tauri::WebviewWindowBuilder::from_config(app.handle(), &main_window_config)?
+                    .build()?;
+            } else {
+                error!(target: LOG_TARGET, "Could not find main window config");
+            }
+
             // The start of needed restart operations. Break this out into a module if we need n+1
             let tcp_tor_toggled_file = config_path.join("tcp_tor_toggled");
             if tcp_tor_toggled_file.exists() {
@@ -653,7 +699,19 @@ fn main() {
             commands::set_display_mode,
             commands::set_gpu_mining_enabled,
             commands::set_mine_on_app_start,
+            commands::set_idle_mining,
+            commands::set_mining_schedule,
             commands::set_mode,
+            commands::set_thermal_governor,
+            commands::set_gpu_device_settings,
+            commands::get_thermal_governor_actions,
+            commands::set_battery_policy,
+            commands::run_mining_benchmark,
+            commands::get_mining_benchmark_results,
+            commands::export_mining_benchmark_results,
+            commands::get_mining_history,
+            commands::get_block_history,
+            commands::get_stratum_proxy_workers,
             commands::set_monero_address,
             commands::set_monerod_config,
             commands::set_external_tari_address,
@@ -694,6 +752,16 @@ fn main() {
             commands::validate_minotari_amount,
             commands::trigger_phases_restart,
             commands::set_node_type,
+            commands::set_custom_node,
+            commands::list_node_peers,
+            commands::ban_node_peer,
+            commands::unban_node_peer,
+            commands::set_node_seed_peers,
+            commands::set_node_dns_seeds,
+            commands::set_node_connection_limits,
+            commands::set_local_node_storage,
+            commands::is_node_snapshot_bootstrap_enabled,
+            commands::set_local_node_snapshot_mirror,
             commands::set_warmup_seen,
             commands::set_allow_notifications,
             commands::launch_builtin_tapplet,
@@ -733,6 +801,9 @@ fn main() {
             tauri::RunEvent::Ready => {
                 info!(target: LOG_TARGET, "RunEvent Ready");
                 let handle_clone = app_handle.clone();
+                if HeadlessMode::current().is_enabled() {
+                    HeadlessMode::spawn_termination_listener(app_handle.clone());
+                }
                 tauri::async_runtime::spawn(async move {
                     SetupManager::get_instance()
                         .start_setup(handle_clone.clone())
@@ -0,0 +1,346 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{fmt::Write, net::SocketAddr};
+
+use axum::{
+    extract::State, http::header::CONTENT_TYPE, response::IntoResponse, routing::get, Router,
+};
+use log::{error, info};
+use tauri::{AppHandle, Manager};
+
+use crate::{
+    configs::{config_core::ConfigCore, trait_config::ConfigImpl},
+    process_watcher::ProcessWatcherStats,
+    tasks_tracker::TasksTrackers,
+    UniverseAppState,
+};
+
+const LOG_TARGET: &str = "tari::universe::metrics_exporter";
+const METRICS_PREFIX: &str = "tari_universe";
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+enum MetricType {
+    Gauge,
+    Counter,
+}
+
+impl MetricType {
+    fn as_str(&self) -> &str {
+        match self {
+            MetricType::Gauge => "gauge",
+            MetricType::Counter => "counter",
+        }
+    }
+}
+
+/// Renders metrics in the Prometheus text exposition format
+#[derive(Default)]
+struct PrometheusWriter {
+    output: String,
+}
+
+impl PrometheusWriter {
+    fn metric(&mut self, name: &str, help: &str, metric_type: MetricType, value: f64) {
+        self.labeled_metric(name, help, metric_type, &[(None, value)]);
+    }
+
+    fn labeled_metric(
+        &mut self,
+        name: &str,
+        help: &str,
+        metric_type: MetricType,
+        samples: &[(Option<(&str, &str)>, f64)],
+    ) {
+        let _unused = writeln!(self.output, "# HELP {METRICS_PREFIX}_{name} {help}");
+        let _unused = writeln!(
+            self.output,
+            "# TYPE {METRICS_PREFIX}_{name} {}",
+            metric_type.as_str()
+        );
+        for (label, value) in samples {
+            match label {
+                Some((key, label_value)) => {
+                    let _unused = writeln!(
+                        self.output,
+                        "{METRICS_PREFIX}_{name}{{{key}=\"{label_value}\"}} {value}"
+                    );
+                }
+                None => {
+                    let _unused = writeln!(self.output, "{METRICS_PREFIX}_{name} {value}");
+                }
+            }
+        }
+    }
+}
+
+fn bool_to_f64(value: bool) -> f64 {
+    if value {
+        1.0
+    } else {
+        0.0
+    }
+}
+
+pub struct MetricsExporter;
+
+impl MetricsExporter {
+    /// Serves `/metrics` when the exporter is enabled in the core config.
+    /// The server lives until the common tasks are shut down.
+    pub async fn start(app_handle: AppHandle) -> Result<(), anyhow::Error> {
+        if !*ConfigCore::content().await.metrics_exporter_enabled() {
+            info!(target: LOG_TARGET, "Metrics exporter is disabled");
+            return Ok(());
+        }
+
+        let addr: SocketAddr = ConfigCore::content()
+            .await
+            .metrics_exporter_bind_address()
+            .parse()?;
+        let listener = tokio::net::TcpListener::bind(addr).await?;
+        info!(target: LOG_TARGET, "Metrics exporter listening on {}", addr);
+
+        let router = Router::new()
+            .route("/metrics", get(get_metrics))
+            .with_state(app_handle);
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let _unused = axum::serve(listener, router)
+                    .with_graceful_shutdown(async move { shutdown_signal.wait().await })
+                    .await
+                    .inspect_err(|e| error!(target: LOG_TARGET, "Metrics exporter error: {:?}", e));
+                info!(target: LOG_TARGET, "Metrics exporter stopped");
+            });
+
+        Ok(())
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn render(state: &UniverseAppState) -> String {
+        let mut writer = PrometheusWriter::default();
+
+        let cpu_status = state.cpu_miner_status_watch_rx.borrow().clone();
+        writer.metric(
+            "cpu_miner_is_mining",
+            "Whether the CPU miner is running",
+            MetricType::Gauge,
+            bool_to_f64(cpu_status.is_mining),
+        );
+        writer.metric(
+            "cpu_miner_hashrate",
+            "CPU miner hashrate in hashes per second",
+            MetricType::Gauge,
+            cpu_status.hash_rate,
+        );
+        writer.metric(
+            "cpu_miner_estimated_earnings",
+            "Estimated daily CPU mining earnings in microMinotari",
+            MetricType::Gauge,
+            cpu_status.estimated_earnings as f64,
+        );
+        writer.metric(
+            "cpu_miner_is_connected",
+            "Whether the CPU miner is connected to its pool or proxy",
+            MetricType::Gauge,
+            bool_to_f64(cpu_status.connection.is_connected),
+        );
+        if let Some(pool_status) = cpu_status.pool_status {
+            writer.metric(
+                "pool_accepted_shares",
+                "Shares accepted by the CPU mining pool",
+                MetricType::Gauge,
+                pool_status.accepted_shares as f64,
+            );
+            writer.metric(
+                "pool_unpaid",
+                "Unpaid balance reported by the CPU mining pool",
+                MetricType::Gauge,
+                pool_status.unpaid as f64,
+            );
+            writer.metric(
+                "pool_balance",
+                "Total balance reported by the CPU mining pool",
+                MetricType::Gauge,
+                pool_status.balance as f64,
+            );
+        }
+
+        let gpu_status = state.gpu_latest_status.borrow().clone();
+        writer.metric(
+            "gpu_miner_is_mining",
+            "Whether the GPU miner is running",
+            MetricType::Gauge,
+            bool_to_f64(gpu_status.is_mining),
+        );
+        writer.metric(
+            "gpu_miner_hashrate",
+            "GPU miner hashrate in hashes per second",
+            MetricType::Gauge,
+            gpu_status.hash_rate,
+        );
+        writer.metric(
+            "gpu_miner_estimated_earnings",
+            "Estimated daily GPU mining earnings in microMinotari",
+            MetricType::Gauge,
+            gpu_status.estimated_earnings as f64,
+        );
+
+        let node_status = *state.node_status_watch_rx.borrow();
+        writer.metric(
+            "node_block_height",
+            "Current block height of the base node",
+            MetricType::Gauge,
+            node_status.block_height as f64,
+        );
+        writer.metric(
+            "node_is_synced",
+            "Whether the base node is synced",
+            MetricType::Gauge,
+            bool_to_f64(node_status.is_synced),
+        );
+        writer.metric(
+            "node_connections",
+            "Number of peers connected to the base node",
+            MetricType::Gauge,
+            node_status.num_connections as f64,
+        );
+        writer.metric(
+            "node_block_reward",
+            "Current block reward in microMinotari",
+            MetricType::Gauge,
+            node_status.block_reward.as_u64() as f64,
+        );
+        writer.labeled_metric(
+            "network_hashrate",
+            "Network hashrate per algorithm",
+            MetricType::Gauge,
+            &[
+                (
+                    Some(("algorithm", "sha3x")),
+                    node_status.sha_network_hashrate as f64,
+                ),
+                (
+                    Some(("algorithm", "tari_randomx")),
+                    node_status.tari_randomx_network_hashrate as f64,
+                ),
+                (
+                    Some(("algorithm", "monero_randomx")),
+                    node_status.monero_randomx_network_hashrate as f64,
+                ),
+            ],
+        );
+
+        if let Some(p2pool_stats) = state.p2pool_latest_status.borrow().clone() {
+            writer.metric(
+                "p2pool_connected_peers",
+                "Number of peers connected to p2pool",
+                MetricType::Gauge,
+                p2pool_stats.connection_info.connected_peers as f64,
+            );
+            writer.labeled_metric(
+                "p2pool_share_chain_height",
+                "Height of the p2pool share chain per algorithm",
+                MetricType::Gauge,
+                &[
+                    (
+                        Some(("algorithm", "randomx")),
+                        p2pool_stats.randomx_stats.height as f64,
+                    ),
+                    (
+                        Some(("algorithm", "sha3x")),
+                        p2pool_stats.sha3x_stats.height as f64,
+                    ),
+                ],
+            );
+        }
+
+        let process_stats = state.process_stats_collector.get_all_stats();
+        let process_samples = |value: fn(&ProcessWatcherStats) -> f64| {
+            process_stats
+                .iter()
+                .map(|(process, stats)| (Some(("process", *process)), value(stats)))
+                .collect::<Vec<_>>()
+        };
+        writer.labeled_metric(
+            "process_uptime_seconds",
+            "Uptime of the process since its last (re)start",
+            MetricType::Gauge,
+            &process_samples(|stats| stats.current_uptime.as_secs_f64()),
+        );
+        writer.labeled_metric(
+            "process_health_checks_total",
+            "Number of health checks performed on the process",
+            MetricType::Counter,
+            &process_samples(|stats| stats.total_health_checks as f64),
+        );
+        writer.labeled_metric(
+            "process_warnings_total",
+            "Number of health checks that returned a warning",
+            MetricType::Counter,
+            &process_samples(|stats| stats.num_warnings as f64),
+        );
+        writer.labeled_metric(
+            "process_failures_total",
+            "Number of failed health checks",
+            MetricType::Counter,
+            &process_samples(|stats| stats.num_failures as f64),
+        );
+        writer.labeled_metric(
+            "process_restarts_total",
+            "Number of times the process was restarted by its watcher",
+            MetricType::Counter,
+            &process_samples(|stats| stats.num_restarts as f64),
+        );
+        writer.labeled_metric(
+            "process_gave_up",
+            "Whether the watcher stopped restarting the process after exhausting its restart policy",
+            MetricType::Gauge,
+            &process_samples(|stats| f64::from(u8::from(stats.has_given_up))),
+        );
+        writer.labeled_metric(
+            "process_health_check_duration_seconds_max",
+            "Longest health check duration",
+            MetricType::Gauge,
+            &process_samples(|stats| stats.max_health_check_duration.as_secs_f64()),
+        );
+        writer.labeled_metric(
+            "process_health_check_duration_seconds_total",
+            "Total time spent in health checks",
+            MetricType::Counter,
+            &process_samples(|stats| stats.total_health_check_duration.as_secs_f64()),
+        );
+
+        writer.output
+    }
+}
+
+async fn get_metrics(State(app_handle): State<AppHandle>) -> impl IntoResponse {
+    let state = app_handle.state::<UniverseAppState>();
+    (
+        [(CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)],
+        MetricsExporter::render(&state),
+    )
+}
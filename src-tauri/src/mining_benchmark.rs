@@ -0,0 +1,532 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::atomic::{AtomicBool, Ordering},
+    thread,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::anyhow;
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager};
+use tokio::time::sleep;
+
+use crate::{
+    configs::{
+        config_mining::{ConfigMining, ConfigMiningContent, GpuThreads, MiningMode},
+        trait_config::ConfigImpl,
+    },
+    cpu_miner::CpuMiner,
+    events::MiningBenchmarkProgressPayload,
+    events_emitter::EventsEmitter,
+    gpu_status_file::GpuDevice,
+    hardware::{
+        hardware_status_monitor::HardwareStatusMonitor, thermal_governor::max_temperature_of,
+    },
+    setup::setup_manager::SetupManager,
+    utils::mining_control_utils,
+    UniverseAppState,
+};
+
+const LOG_TARGET: &str = "tari::universe::mining_benchmark";
+const CPU_RUN_DURATION: Duration = Duration::from_secs(30);
+/// glytex reports a ten second average, so it needs some time to settle before sampling
+const GPU_WARMUP_DURATION: Duration = Duration::from_secs(20);
+const GPU_SAMPLE_DURATION: Duration = Duration::from_secs(20);
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(2);
+const RESULTS_FILE_NAME: &str = "mining_benchmark.json";
+
+static IS_RUNNING: AtomicBool = AtomicBool::new(false);
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkRun {
+    /// Number of CPU threads or GPU grid size
+    pub setting: u32,
+    pub hash_rate: f64,
+    pub max_temperature: Option<f32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CpuBenchmarkResult {
+    pub runs: Vec<BenchmarkRun>,
+    pub best_threads: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GpuBenchmarkResult {
+    pub device_index: u32,
+    pub device_name: String,
+    pub runs: Vec<BenchmarkRun>,
+    pub best_grid_size: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MiningBenchmarkResults {
+    pub timestamp: u64,
+    pub cpu: Option<CpuBenchmarkResult>,
+    pub gpus: Vec<GpuBenchmarkResult>,
+}
+
+/// Mining state before the benchmark, restored once it is done
+struct PreviousState {
+    mode: MiningMode,
+    cpu_mining: bool,
+    gpu_mining: bool,
+}
+
+/// Runs xmrig at several thread counts and glytex at several grid sizes per device,
+/// then stores the fastest settings as the Custom mode levels
+pub struct MiningBenchmark;
+
+impl MiningBenchmark {
+    pub fn is_running() -> bool {
+        IS_RUNNING.load(Ordering::SeqCst)
+    }
+
+    pub async fn run(
+        app_handle: &AppHandle,
+        benchmark_cpu: bool,
+        benchmark_gpu: bool,
+    ) -> Result<MiningBenchmarkResults, anyhow::Error> {
+        let setup_manager = SetupManager::get_instance();
+        if !setup_manager.is_cpu_mining_unlocked().await
+            || !setup_manager.is_gpu_mining_unlocked().await
+        {
+            return Err(anyhow!("Mining is not ready yet"));
+        }
+        if IS_RUNNING.swap(true, Ordering::SeqCst) {
+            return Err(anyhow!("Benchmark is already running"));
+        }
+
+        let previous_state = PreviousState {
+            mode: *ConfigMining::content().await.mode(),
+            cpu_mining: mining_control_utils::is_cpu_mining(app_handle).await,
+            gpu_mining: mining_control_utils::is_gpu_mining(app_handle).await,
+        };
+        mining_control_utils::stop_cpu_mining(app_handle).await;
+        mining_control_utils::stop_gpu_mining(app_handle).await;
+
+        let result = Self::run_benchmarks(app_handle, benchmark_cpu, benchmark_gpu).await;
+
+        if previous_state.mode != *ConfigMining::content().await.mode() {
+            mining_control_utils::switch_mining_mode(app_handle, previous_state.mode).await;
+        }
+        if previous_state.cpu_mining {
+            mining_control_utils::start_cpu_mining(app_handle).await;
+        }
+        if previous_state.gpu_mining {
+            mining_control_utils::start_gpu_mining(app_handle).await;
+        }
+        IS_RUNNING.store(false, Ordering::SeqCst);
+
+        let results = result?;
+        Self::save_results(app_handle, &results)?;
+        Ok(results)
+    }
+
+    async fn run_benchmarks(
+        app_handle: &AppHandle,
+        benchmark_cpu: bool,
+        benchmark_gpu: bool,
+    ) -> Result<MiningBenchmarkResults, anyhow::Error> {
+        let max_cpu_threads = thread::available_parallelism()
+            .ok()
+            .and_then(|threads| u32::try_from(threads.get()).ok())
+            .unwrap_or(1);
+        let cpu_candidates = if benchmark_cpu {
+            cpu_thread_candidates(max_cpu_threads)
+        } else {
+            vec![]
+        };
+
+        let state = app_handle.state::<UniverseAppState>();
+        let gpu_miner = state.gpu_miner.read().await;
+        let gpu_devices = if benchmark_gpu && gpu_miner.is_gpu_mining_available() {
+            gpu_miner.get_gpu_devices().await?
+        } else {
+            vec![]
+        };
+        drop(gpu_miner);
+        let gpu_candidates: Vec<Vec<u32>> = gpu_devices
+            .iter()
+            .map(|gpu_device| {
+                if gpu_device.settings.is_excluded {
+                    vec![]
+                } else {
+                    gpu_grid_candidates(gpu_device)
+                }
+            })
+            .collect();
+        let gpu_runs = gpu_candidates.iter().map(Vec::len).max().unwrap_or(0);
+
+        let mut progress = BenchmarkProgress {
+            completed_runs: 0,
+            total_runs: cpu_candidates.len() + gpu_runs,
+        };
+        progress.emit().await;
+
+        let cpu = if cpu_candidates.is_empty() {
+            None
+        } else {
+            Some(Self::benchmark_cpu(app_handle, &cpu_candidates, &mut progress).await?)
+        };
+        let gpus = if gpu_runs == 0 {
+            vec![]
+        } else {
+            Self::benchmark_gpus(app_handle, &gpu_devices, &gpu_candidates, &mut progress).await?
+        };
+
+        if let Some(best_threads) = cpu.as_ref().and_then(|cpu| cpu.best_threads) {
+            info!(target: LOG_TARGET, "Best CPU setting is {} threads", best_threads);
+            ConfigMining::update_field(
+                ConfigMiningContent::set_custom_max_cpu_usage,
+                Some(best_threads),
+            )
+            .await?;
+        }
+        if gpus.iter().any(|gpu| gpu.best_grid_size.is_some()) {
+            let best_grid_sizes: HashMap<u32, u32> = gpus
+                .iter()
+                .filter_map(|gpu| Some((gpu.device_index, gpu.best_grid_size?)))
+                .collect();
+            info!(target: LOG_TARGET, "Best GPU grid sizes are {:?}", best_grid_sizes);
+            let custom_max_gpu_usage = grid_sizes_for(&gpu_devices, |gpu_device| {
+                best_grid_sizes.get(&gpu_device.device_index).copied()
+            });
+            ConfigMining::update_field(
+                ConfigMiningContent::set_custom_max_gpu_usage,
+                custom_max_gpu_usage,
+            )
+            .await?;
+        }
+        EventsEmitter::emit_mining_config_loaded(ConfigMining::content().await).await;
+
+        Ok(MiningBenchmarkResults {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+            cpu,
+            gpus,
+        })
+    }
+
+    async fn benchmark_cpu(
+        app_handle: &AppHandle,
+        candidates: &[u32],
+        progress: &mut BenchmarkProgress,
+    ) -> Result<CpuBenchmarkResult, anyhow::Error> {
+        let state = app_handle.state::<UniverseAppState>();
+        let data_dir = app_handle.path().app_local_data_dir()?;
+        let config_dir = app_handle.path().app_config_dir()?;
+        let log_dir = app_handle.path().app_log_dir()?;
+
+        let mut runs = Vec::new();
+        for cpu_threads in candidates.iter().copied() {
+            info!(target: LOG_TARGET, "Benchmarking xmrig with {} threads", cpu_threads);
+            // Only hold the miner lock to start and stop the run so status reads aren't blocked meanwhile
+            let status = state
+                .cpu_miner
+                .read()
+                .await
+                .start_benchmark_run(
+                    cpu_threads,
+                    data_dir.clone(),
+                    config_dir.clone(),
+                    log_dir.clone(),
+                )
+                .await?;
+            let (hash_rate, max_temperature) = match status {
+                Some(status) => tokio::join!(
+                    CpuMiner::sample_benchmark_hashrate(status, CPU_RUN_DURATION),
+                    sample_cpu_temperature(CPU_RUN_DURATION)
+                ),
+                None => (0.0, None),
+            };
+            state.cpu_miner.write().await.stop().await?;
+
+            runs.push(BenchmarkRun {
+                setting: cpu_threads,
+                hash_rate,
+                max_temperature,
+            });
+            progress.completed_runs += 1;
+            progress.emit().await;
+        }
+
+        Ok(CpuBenchmarkResult {
+            best_threads: best_setting(&runs),
+            runs,
+        })
+    }
+
+    /// glytex mines on all devices at once, so every run tests the next grid size of each device in parallel
+    async fn benchmark_gpus(
+        app_handle: &AppHandle,
+        gpu_devices: &[GpuDevice],
+        candidates: &[Vec<u32>],
+        progress: &mut BenchmarkProgress,
+    ) -> Result<Vec<GpuBenchmarkResult>, anyhow::Error> {
+        let mut results: Vec<GpuBenchmarkResult> = gpu_devices
+            .iter()
+            .map(|gpu_device| GpuBenchmarkResult {
+                device_index: gpu_device.device_index,
+                device_name: gpu_device.device_name.clone(),
+                runs: vec![],
+                best_grid_size: None,
+            })
+            .collect();
+        let previous_gpu_usage = ConfigMining::content().await.custom_max_gpu_usage().clone();
+        ConfigMining::update_field(ConfigMiningContent::set_mode, MiningMode::Custom).await?;
+
+        let runs_result =
+            Self::run_gpu_grid_sizes(app_handle, gpu_devices, candidates, progress, &mut results)
+                .await;
+        // Put back what the user had even when a run failed, the best grid sizes are applied by the caller
+        let restore_result = ConfigMining::update_field(
+            ConfigMiningContent::set_custom_max_gpu_usage,
+            previous_gpu_usage,
+        )
+        .await;
+        runs_result?;
+        restore_result?;
+        for result in &mut results {
+            result.best_grid_size = best_setting(&result.runs);
+        }
+        Ok(results)
+    }
+
+    async fn run_gpu_grid_sizes(
+        app_handle: &AppHandle,
+        gpu_devices: &[GpuDevice],
+        candidates: &[Vec<u32>],
+        progress: &mut BenchmarkProgress,
+        results: &mut [GpuBenchmarkResult],
+    ) -> Result<(), anyhow::Error> {
+        let total_runs = candidates.iter().map(Vec::len).max().unwrap_or(0);
+        for run in 0..total_runs {
+            let grid_sizes: HashMap<u32, u32> = gpu_devices
+                .iter()
+                .zip(candidates)
+                .filter_map(|(gpu_device, device_candidates)| {
+                    Some((gpu_device.device_index, *device_candidates.get(run)?))
+                })
+                .collect();
+            info!(target: LOG_TARGET, "Benchmarking glytex with grid sizes {:?}", grid_sizes);
+            ConfigMining::update_field(
+                ConfigMiningContent::set_custom_max_gpu_usage,
+                grid_sizes_for(gpu_devices, |gpu_device| {
+                    grid_sizes.get(&gpu_device.device_index).copied()
+                }),
+            )
+            .await?;
+
+            if !mining_control_utils::start_gpu_mining(app_handle).await {
+                warn!(target: LOG_TARGET, "Could not start GPU miner, stopping the GPU benchmark");
+                break;
+            }
+            sleep(GPU_WARMUP_DURATION).await;
+
+            let (hash_rates, temperatures) = sample_gpus(app_handle, &grid_sizes).await;
+            mining_control_utils::stop_gpu_mining(app_handle).await;
+
+            for result in results.iter_mut() {
+                if let Some(grid_size) = grid_sizes.get(&result.device_index) {
+                    result.runs.push(BenchmarkRun {
+                        setting: *grid_size,
+                        hash_rate: hash_rates.get(&result.device_index).copied().unwrap_or(0.0),
+                        max_temperature: temperatures.get(&result.device_index).copied(),
+                    });
+                }
+            }
+            progress.completed_runs += 1;
+            progress.emit().await;
+        }
+
+        Ok(())
+    }
+
+    fn results_path(app_handle: &AppHandle) -> Result<PathBuf, anyhow::Error> {
+        Ok(app_handle.path().app_config_dir()?.join(RESULTS_FILE_NAME))
+    }
+
+    fn save_results(
+        app_handle: &AppHandle,
+        results: &MiningBenchmarkResults,
+    ) -> Result<(), anyhow::Error> {
+        let content = serde_json::to_string_pretty(results)?;
+        std::fs::write(Self::results_path(app_handle)?, content)
+            .map_err(|e| anyhow!("Failed to save benchmark results: {}", e))
+    }
+
+    pub fn load_results(
+        app_handle: &AppHandle,
+    ) -> Result<Option<MiningBenchmarkResults>, anyhow::Error> {
+        let path = Self::results_path(app_handle)?;
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(path)?;
+        Ok(Some(serde_json::from_str(&content)?))
+    }
+
+    /// Copies the last results to the downloads directory and returns the path of the copy
+    pub fn export_results(app_handle: &AppHandle) -> Result<PathBuf, anyhow::Error> {
+        let results = Self::load_results(app_handle)?
+            .ok_or_else(|| anyhow!("No benchmark results to export"))?;
+        let export_path = app_handle.path().download_dir()?.join(format!(
+            "tari-universe-benchmark-{}.json",
+            results.timestamp
+        ));
+        std::fs::write(&export_path, serde_json::to_string_pretty(&results)?).inspect_err(
+            |e| error!(target: LOG_TARGET, "Failed to export benchmark results: {}", e),
+        )?;
+        Ok(export_path)
+    }
+}
+
+struct BenchmarkProgress {
+    completed_runs: usize,
+    total_runs: usize,
+}
+
+impl BenchmarkProgress {
+    async fn emit(&self) {
+        EventsEmitter::emit_mining_benchmark_progress(MiningBenchmarkProgressPayload {
+            completed_runs: self.completed_runs,
+            total_runs: self.total_runs,
+        })
+        .await;
+    }
+}
+
+async fn sample_cpu_temperature(duration: Duration) -> Option<f32> {
+    let start_time = Instant::now();
+    let mut max_temperature: Option<f32> = None;
+    while start_time.elapsed() < duration {
+        sleep(SAMPLE_INTERVAL).await;
+        let cpu_properties = HardwareStatusMonitor::current()
+            .get_cpu_public_properties()
+            .await
+            .unwrap_or_default();
+        if let Some(temperature) = max_temperature_of(&cpu_properties) {
+            max_temperature = Some(max_temperature.map_or(temperature, |max| max.max(temperature)));
+        }
+    }
+    max_temperature
+}
+
+/// Highest hashrate and temperature of every benchmarked device while glytex is running
+async fn sample_gpus(
+    app_handle: &AppHandle,
+    grid_sizes: &HashMap<u32, u32>,
+) -> (HashMap<u32, f64>, HashMap<u32, f32>) {
+    let state = app_handle.state::<UniverseAppState>();
+    let mut hash_rates: HashMap<u32, f64> = HashMap::new();
+    let mut temperatures: HashMap<u32, f32> = HashMap::new();
+    let start_time = Instant::now();
+    while start_time.elapsed() < GPU_SAMPLE_DURATION {
+        sleep(SAMPLE_INTERVAL).await;
+        match state.gpu_miner.read().await.get_device_hashrates().await {
+            Ok(device_hashrates) => {
+                for (device_index, hash_rate) in device_hashrates {
+                    let max_hash_rate = hash_rates.entry(device_index).or_default();
+                    *max_hash_rate = max_hash_rate.max(hash_rate);
+                }
+            }
+            Err(e) => warn!(target: LOG_TARGET, "Could not read GPU hashrates: {}", e),
+        }
+        let gpu_properties = HardwareStatusMonitor::current()
+            .get_gpu_public_properties()
+            .await
+            .unwrap_or_default();
+        for device_index in grid_sizes.keys().copied() {
+            let temperature = gpu_properties
+                .iter()
+                .find(|device| device.device_index == device_index)
+                .and_then(|device| max_temperature_of(std::slice::from_ref(device)));
+            if let Some(temperature) = temperature {
+                let max_temperature = temperatures.entry(device_index).or_insert(temperature);
+                *max_temperature = max_temperature.max(temperature);
+            }
+        }
+    }
+    (hash_rates, temperatures)
+}
+
+fn cpu_thread_candidates(max_threads: u32) -> Vec<u32> {
+    let mut candidates: Vec<u32> = [
+        1,
+        max_threads / 4,
+        max_threads / 2,
+        max_threads * 3 / 4,
+        max_threads,
+    ]
+    .into_iter()
+    .filter(|threads| *threads > 0)
+    .collect();
+    candidates.dedup();
+    candidates
+}
+
+fn gpu_grid_candidates(gpu_device: &GpuDevice) -> Vec<u32> {
+    let max_grid_size = gpu_device.status.max_grid_size;
+    let mut candidates: Vec<u32> = [
+        gpu_device.status.recommended_grid_size,
+        max_grid_size / 4,
+        max_grid_size / 2,
+        max_grid_size,
+    ]
+    .into_iter()
+    .filter(|grid_size| *grid_size > 0)
+    .collect();
+    candidates.sort_unstable();
+    candidates.dedup();
+    candidates
+}
+
+/// Devices without a value keep their recommended grid size
+fn grid_sizes_for(
+    gpu_devices: &[GpuDevice],
+    grid_size_of: impl Fn(&GpuDevice) -> Option<u32>,
+) -> Vec<GpuThreads> {
+    gpu_devices
+        .iter()
+        .map(|gpu_device| GpuThreads {
+            gpu_name: gpu_device.device_name.clone(),
+            device_index: Some(gpu_device.device_index),
+            max_gpu_threads: grid_size_of(gpu_device)
+                .unwrap_or(gpu_device.status.recommended_grid_size),
+        })
+        .collect()
+}
+
+fn best_setting(runs: &[BenchmarkRun]) -> Option<u32> {
+    runs.iter()
+        .filter(|run| run.hash_rate > 0.0)
+        .max_by(|a, b| a.hash_rate.total_cmp(&b.hash_rate))
+        .map(|run| run.setting)
+}
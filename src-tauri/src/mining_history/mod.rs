@@ -0,0 +1,318 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+mod models;
+mod schema;
+
+use std::{
+    path::PathBuf,
+    sync::{LazyLock, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::anyhow;
+use diesel::{connection::SimpleConnection, prelude::*, sql_query, sql_types::BigInt};
+use log::{error, info};
+use tauri::{AppHandle, Manager};
+use tokio::{select, time::MissedTickBehavior};
+
+use crate::{
+    configs::{config_mining::ConfigMining, trait_config::ConfigImpl},
+    tasks_tracker::TasksTrackers,
+    UniverseAppState,
+};
+
+pub use models::{BlockEvent, HashrateSample};
+use models::{NewBlockEvent, NewHashrateSample};
+use schema::{block_events, hashrate_samples, CREATE_TABLES};
+
+const LOG_TARGET: &str = "tari::universe::mining_history";
+const DATABASE_FILE_NAME: &str = "mining_history.sqlite";
+const SAMPLE_INTERVAL: Duration = Duration::from_secs(60);
+const COMPACT_INTERVAL: Duration = Duration::from_secs(60 * 60);
+const RAW_RESOLUTION_SECS: i64 = 60;
+const DOWNSAMPLED_RESOLUTION_SECS: i64 = 60 * 60;
+/// Per minute samples older than this are merged into hourly averages
+const RAW_SAMPLES_RETENTION_SECS: i64 = 7 * 24 * 60 * 60;
+const AVERAGED_COLUMNS: &str = "AVG(cpu_hashrate) AS cpu_hashrate, \
+    AVG(gpu_hashrate) AS gpu_hashrate, \
+    CAST(AVG(cpu_estimated_earnings) AS INTEGER) AS cpu_estimated_earnings, \
+    CAST(AVG(gpu_estimated_earnings) AS INTEGER) AS gpu_estimated_earnings, \
+    MAX(block_height) AS block_height, \
+    CAST(AVG(sha_network_hashrate) AS INTEGER) AS sha_network_hashrate, \
+    CAST(AVG(randomx_network_hashrate) AS INTEGER) AS randomx_network_hashrate, \
+    MAX(pool_accepted_shares) AS pool_accepted_shares, \
+    MAX(pool_unpaid) AS pool_unpaid, \
+    MAX(pool_balance) AS pool_balance";
+
+static INSTANCE: LazyLock<MiningHistory> = LazyLock::new(MiningHistory::new);
+
+/// Local SQLite store of hashrate, earnings and block events, so trends survive restarts
+pub struct MiningHistory {
+    connection: Mutex<Option<SqliteConnection>>,
+}
+
+impl MiningHistory {
+    fn new() -> Self {
+        Self {
+            connection: Mutex::new(None),
+        }
+    }
+
+    pub fn current() -> &'static MiningHistory {
+        &INSTANCE
+    }
+
+    pub async fn start(app_handle: AppHandle) -> Result<(), anyhow::Error> {
+        let database_path = app_handle
+            .path()
+            .app_local_data_dir()?
+            .join(DATABASE_FILE_NAME);
+        Self::current().open(database_path).await?;
+
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let mut sample_interval = tokio::time::interval(SAMPLE_INTERVAL);
+                sample_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+                let mut compact_interval = tokio::time::interval(COMPACT_INTERVAL);
+                compact_interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+                loop {
+                    select! {
+                        _ = sample_interval.tick() => {
+                            if let Err(e) = Self::current().record_sample(&app_handle).await {
+                                error!(target: LOG_TARGET, "Could not record mining sample: {:?}", e);
+                            }
+                        }
+                        _ = compact_interval.tick() => {
+                            if let Err(e) = Self::current().compact().await {
+                                error!(target: LOG_TARGET, "Could not compact mining history: {:?}", e);
+                            }
+                        }
+                        _ = shutdown_signal.wait() => {
+                            info!(target: LOG_TARGET, "Shutdown signal received, stopping mining history");
+                            break;
+                        }
+                    }
+                }
+            });
+        Ok(())
+    }
+
+    async fn open(&'static self, database_path: PathBuf) -> Result<(), anyhow::Error> {
+        tokio::task::spawn_blocking(move || {
+            let mut connection = SqliteConnection::establish(&database_path.to_string_lossy())?;
+            connection.batch_execute(CREATE_TABLES)?;
+            *self
+                .connection
+                .lock()
+                .map_err(|_| anyhow!("Mining history connection lock poisoned"))? =
+                Some(connection);
+            info!(target: LOG_TARGET, "Mining history opened at {:?}", database_path);
+            Ok(())
+        })
+        .await?
+    }
+
+    /// Runs a query on the blocking thread pool, diesel connections are synchronous
+    async fn with_connection<T, F>(&'static self, query: F) -> Result<T, anyhow::Error>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut SqliteConnection) -> Result<T, anyhow::Error> + Send + 'static,
+    {
+        tokio::task::spawn_blocking(move || {
+            let mut connection = self
+                .connection
+                .lock()
+                .map_err(|_| anyhow!("Mining history connection lock poisoned"))?;
+            let connection = connection
+                .as_mut()
+                .ok_or_else(|| anyhow!("Mining history is not open"))?;
+            query(connection)
+        })
+        .await?
+    }
+
+    async fn record_sample(&'static self, app_handle: &AppHandle) -> Result<(), anyhow::Error> {
+        let state = app_handle.state::<UniverseAppState>();
+        let cpu_status = state.cpu_miner_status_watch_rx.borrow().clone();
+        let gpu_status = state.gpu_latest_status.borrow().clone();
+        let node_status = *state.node_status_watch_rx.borrow();
+        // Gaps in the history mean nothing was mining
+        if !cpu_status.is_mining && !gpu_status.is_mining {
+            return Ok(());
+        }
+
+        let pool_status = cpu_status.pool_status.as_ref();
+        let sample = NewHashrateSample {
+            timestamp: now_secs(),
+            resolution_secs: RAW_RESOLUTION_SECS,
+            cpu_hashrate: cpu_status.hash_rate,
+            gpu_hashrate: gpu_status.hash_rate,
+            cpu_estimated_earnings: to_i64(cpu_status.estimated_earnings),
+            gpu_estimated_earnings: to_i64(gpu_status.estimated_earnings),
+            block_height: to_i64(node_status.block_height),
+            sha_network_hashrate: to_i64(node_status.sha_network_hashrate),
+            randomx_network_hashrate: to_i64(node_status.monero_randomx_network_hashrate),
+            pool_accepted_shares: pool_status.map(|pool| to_i64(pool.accepted_shares)),
+            pool_unpaid: pool_status.map(|pool| to_i64(pool.unpaid)),
+            pool_balance: pool_status.map(|pool| to_i64(pool.balance)),
+        };
+        self.with_connection(move |connection| {
+            diesel::insert_into(hashrate_samples::table)
+                .values(&sample)
+                .execute(connection)?;
+            Ok(())
+        })
+        .await
+    }
+
+    pub async fn record_block(
+        &'static self,
+        block_height: u64,
+        coinbase_reward: Option<u64>,
+        available_balance: Option<u64>,
+    ) {
+        let block_event = NewBlockEvent {
+            timestamp: now_secs(),
+            block_height: to_i64(block_height),
+            coinbase_reward: coinbase_reward.map(to_i64),
+            available_balance: available_balance.map(to_i64),
+        };
+        let result = self
+            .with_connection(move |connection| {
+                diesel::insert_into(block_events::table)
+                    .values(&block_event)
+                    .execute(connection)?;
+                Ok(())
+            })
+            .await;
+        if let Err(e) = result {
+            error!(target: LOG_TARGET, "Could not record block #{}: {:?}", block_height, e);
+        }
+    }
+
+    /// Merges old per minute samples into hourly averages and drops everything past the retention period
+    async fn compact(&'static self) -> Result<(), anyhow::Error> {
+        let retention_days = *ConfigMining::content()
+            .await
+            .mining_history_retention_days();
+        let now = now_secs();
+        // Only whole hours are merged, so a bucket is never split between two compactions
+        let raw_cutoff = (now - RAW_SAMPLES_RETENTION_SECS) / DOWNSAMPLED_RESOLUTION_SECS
+            * DOWNSAMPLED_RESOLUTION_SECS;
+        let retention_cutoff =
+            now.saturating_sub(to_i64(retention_days).saturating_mul(24 * 60 * 60));
+
+        self.with_connection(move |connection| {
+            connection.transaction::<_, anyhow::Error, _>(|connection| {
+                let merged = sql_query(format!(
+                    "INSERT INTO hashrate_samples (timestamp, resolution_secs, cpu_hashrate, gpu_hashrate, \
+                     cpu_estimated_earnings, gpu_estimated_earnings, block_height, sha_network_hashrate, \
+                     randomx_network_hashrate, pool_accepted_shares, pool_unpaid, pool_balance) \
+                     SELECT (timestamp / ?) * ? AS bucket_start, ?, {AVERAGED_COLUMNS} \
+                     FROM hashrate_samples WHERE resolution_secs = ? AND timestamp < ? \
+                     GROUP BY bucket_start"
+                ))
+                .bind::<BigInt, _>(DOWNSAMPLED_RESOLUTION_SECS)
+                .bind::<BigInt, _>(DOWNSAMPLED_RESOLUTION_SECS)
+                .bind::<BigInt, _>(DOWNSAMPLED_RESOLUTION_SECS)
+                .bind::<BigInt, _>(RAW_RESOLUTION_SECS)
+                .bind::<BigInt, _>(raw_cutoff)
+                .execute(connection)?;
+                diesel::delete(
+                    hashrate_samples::table
+                        .filter(hashrate_samples::resolution_secs.eq(RAW_RESOLUTION_SECS))
+                        .filter(hashrate_samples::timestamp.lt(raw_cutoff)),
+                )
+                .execute(connection)?;
+
+                let expired_samples = diesel::delete(
+                    hashrate_samples::table.filter(hashrate_samples::timestamp.lt(retention_cutoff)),
+                )
+                .execute(connection)?;
+                let expired_blocks = diesel::delete(
+                    block_events::table.filter(block_events::timestamp.lt(retention_cutoff)),
+                )
+                .execute(connection)?;
+                info!(target: LOG_TARGET, "Compacted mining history: {} hourly samples merged, {} samples and {} blocks expired", merged, expired_samples, expired_blocks);
+                Ok(())
+            })
+        })
+        .await
+    }
+
+    /// Samples between `from` and `to` (unix seconds), averaged over buckets of `bucket_secs`
+    pub async fn get_samples(
+        &'static self,
+        from: u64,
+        to: u64,
+        bucket_secs: u64,
+    ) -> Result<Vec<HashrateSample>, anyhow::Error> {
+        let bucket_secs = to_i64(bucket_secs).max(RAW_RESOLUTION_SECS);
+        self.with_connection(move |connection| {
+            let samples = sql_query(format!(
+                "SELECT (timestamp / ?) * ? AS bucket_start, {AVERAGED_COLUMNS} \
+                 FROM hashrate_samples WHERE timestamp >= ? AND timestamp <= ? \
+                 GROUP BY bucket_start ORDER BY bucket_start"
+            ))
+            .bind::<BigInt, _>(bucket_secs)
+            .bind::<BigInt, _>(bucket_secs)
+            .bind::<BigInt, _>(to_i64(from))
+            .bind::<BigInt, _>(to_i64(to))
+            .load::<HashrateSample>(connection)?;
+            Ok(samples)
+        })
+        .await
+    }
+
+    pub async fn get_blocks(
+        &'static self,
+        from: u64,
+        to: u64,
+    ) -> Result<Vec<BlockEvent>, anyhow::Error> {
+        self.with_connection(move |connection| {
+            let blocks = block_events::table
+                .filter(block_events::timestamp.between(to_i64(from), to_i64(to)))
+                .order(block_events::timestamp.asc())
+                .select(BlockEvent::as_select())
+                .load(connection)?;
+            Ok(blocks)
+        })
+        .await
+    }
+}
+
+fn now_secs() -> i64 {
+    let secs = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    to_i64(secs)
+}
+
+fn to_i64(value: u64) -> i64 {
+    i64::try_from(value).unwrap_or(i64::MAX)
+}
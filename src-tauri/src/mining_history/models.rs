@@ -0,0 +1,93 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use diesel::{
+    prelude::*,
+    sql_types::{BigInt, Double, Nullable},
+};
+use serde::Serialize;
+
+use super::schema::{block_events, hashrate_samples};
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = hashrate_samples)]
+pub struct NewHashrateSample {
+    pub timestamp: i64,
+    pub resolution_secs: i64,
+    pub cpu_hashrate: f64,
+    pub gpu_hashrate: f64,
+    pub cpu_estimated_earnings: i64,
+    pub gpu_estimated_earnings: i64,
+    pub block_height: i64,
+    pub sha_network_hashrate: i64,
+    pub randomx_network_hashrate: i64,
+    pub pool_accepted_shares: Option<i64>,
+    pub pool_unpaid: Option<i64>,
+    pub pool_balance: Option<i64>,
+}
+
+/// Samples averaged over a bucket, `timestamp` is the start of the bucket
+#[derive(Debug, Clone, Serialize, QueryableByName)]
+pub struct HashrateSample {
+    #[diesel(sql_type = BigInt, column_name = bucket_start)]
+    pub timestamp: i64,
+    #[diesel(sql_type = Double)]
+    pub cpu_hashrate: f64,
+    #[diesel(sql_type = Double)]
+    pub gpu_hashrate: f64,
+    #[diesel(sql_type = BigInt)]
+    pub cpu_estimated_earnings: i64,
+    #[diesel(sql_type = BigInt)]
+    pub gpu_estimated_earnings: i64,
+    #[diesel(sql_type = BigInt)]
+    pub block_height: i64,
+    #[diesel(sql_type = BigInt)]
+    pub sha_network_hashrate: i64,
+    #[diesel(sql_type = BigInt)]
+    pub randomx_network_hashrate: i64,
+    #[diesel(sql_type = Nullable<BigInt>)]
+    pub pool_accepted_shares: Option<i64>,
+    #[diesel(sql_type = Nullable<BigInt>)]
+    pub pool_unpaid: Option<i64>,
+    #[diesel(sql_type = Nullable<BigInt>)]
+    pub pool_balance: Option<i64>,
+}
+
+#[derive(Debug, Insertable)]
+#[diesel(table_name = block_events)]
+pub struct NewBlockEvent {
+    pub timestamp: i64,
+    pub block_height: i64,
+    pub coinbase_reward: Option<i64>,
+    pub available_balance: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize, Queryable, Selectable)]
+#[diesel(table_name = block_events)]
+#[diesel(check_for_backend(diesel::sqlite::Sqlite))]
+pub struct BlockEvent {
+    pub timestamp: i64,
+    pub block_height: i64,
+    /// Set when the block was mined by this wallet
+    pub coinbase_reward: Option<i64>,
+    pub available_balance: Option<i64>,
+}
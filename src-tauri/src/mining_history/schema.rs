@@ -0,0 +1,77 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+/// Tables are created on startup, new columns need an `ALTER TABLE` next to the `CREATE TABLE`
+pub const CREATE_TABLES: &str = "
+    CREATE TABLE IF NOT EXISTS hashrate_samples (
+        id INTEGER PRIMARY KEY NOT NULL,
+        timestamp BIGINT NOT NULL,
+        resolution_secs BIGINT NOT NULL,
+        cpu_hashrate DOUBLE NOT NULL,
+        gpu_hashrate DOUBLE NOT NULL,
+        cpu_estimated_earnings BIGINT NOT NULL,
+        gpu_estimated_earnings BIGINT NOT NULL,
+        block_height BIGINT NOT NULL,
+        sha_network_hashrate BIGINT NOT NULL,
+        randomx_network_hashrate BIGINT NOT NULL,
+        pool_accepted_shares BIGINT,
+        pool_unpaid BIGINT,
+        pool_balance BIGINT
+    );
+    CREATE INDEX IF NOT EXISTS hashrate_samples_timestamp ON hashrate_samples (timestamp);
+    CREATE TABLE IF NOT EXISTS block_events (
+        id INTEGER PRIMARY KEY NOT NULL,
+        timestamp BIGINT NOT NULL,
+        block_height BIGINT NOT NULL,
+        coinbase_reward BIGINT,
+        available_balance BIGINT
+    );
+    CREATE INDEX IF NOT EXISTS block_events_timestamp ON block_events (timestamp);
+";
+
+diesel::table! {
+    hashrate_samples (id) {
+        id -> Integer,
+        timestamp -> BigInt,
+        resolution_secs -> BigInt,
+        cpu_hashrate -> Double,
+        gpu_hashrate -> Double,
+        cpu_estimated_earnings -> BigInt,
+        gpu_estimated_earnings -> BigInt,
+        block_height -> BigInt,
+        sha_network_hashrate -> BigInt,
+        randomx_network_hashrate -> BigInt,
+        pool_accepted_shares -> Nullable<BigInt>,
+        pool_unpaid -> Nullable<BigInt>,
+        pool_balance -> Nullable<BigInt>,
+    }
+}
+
+diesel::table! {
+    block_events (id) {
+        id -> Integer,
+        timestamp -> BigInt,
+        block_height -> BigInt,
+        coinbase_reward -> Nullable<BigInt>,
+        available_balance -> Nullable<BigInt>,
+    }
+}
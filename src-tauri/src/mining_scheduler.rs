@@ -0,0 +1,156 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::time::Duration;
+
+use chrono::Local;
+use log::info;
+use tauri::AppHandle;
+use tokio::select;
+use tokio::time::MissedTickBehavior;
+
+use crate::configs::config_mining::{ConfigMining, MiningScheduleRule};
+use crate::configs::trait_config::ConfigImpl;
+use crate::events::MiningScheduleUpdatePayload;
+use crate::events_emitter::EventsEmitter;
+use crate::mining_benchmark::MiningBenchmark;
+use crate::setup::setup_manager::SetupManager;
+use crate::tasks_tracker::TasksTrackers;
+use crate::utils::mining_control_utils;
+
+const LOG_TARGET: &str = "tari::universe::mining_scheduler";
+const SCHEDULE_CHECK_INTERVAL: Duration = Duration::from_secs(30);
+
+/// Starts and stops the miners according to the schedule rules stored in `ConfigMining`.
+/// Miners are only touched when entering or leaving a schedule window, so the user can still override the schedule manually.
+pub struct MiningScheduler {
+    app_handle: AppHandle,
+    /// Rule applied on the last evaluation, `None` if the schedule has not been applied yet
+    applied_rule: Option<Option<MiningScheduleRule>>,
+    is_first_evaluation: bool,
+}
+
+impl MiningScheduler {
+    pub async fn start(app_handle: AppHandle) {
+        let mut scheduler = Self {
+            app_handle,
+            applied_rule: None,
+            is_first_evaluation: true,
+        };
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let mut interval = tokio::time::interval(SCHEDULE_CHECK_INTERVAL);
+                interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+                loop {
+                    select! {
+                        _ = interval.tick() => {
+                            scheduler.evaluate().await;
+                        }
+                        _ = shutdown_signal.wait() => {
+                            info!(target: LOG_TARGET, "Shutdown signal received, stopping mining scheduler");
+                            break;
+                        }
+                    }
+                }
+            });
+    }
+
+    async fn evaluate(&mut self) {
+        // The benchmark starts and stops the miners itself while it runs
+        if MiningBenchmark::is_running() {
+            return;
+        }
+        let config = ConfigMining::content().await;
+        if !*config.mining_schedule_enabled() {
+            self.applied_rule = None;
+            return;
+        }
+
+        // Miners can't be started while their phases are (re)starting, so the schedule is re-applied once they are unlocked
+        let setup_manager = SetupManager::get_instance();
+        if !setup_manager.is_cpu_mining_unlocked().await
+            || !setup_manager.is_gpu_mining_unlocked().await
+        {
+            self.applied_rule = None;
+            return;
+        }
+
+        let now = Local::now().naive_local();
+        let active_rule = config
+            .mining_schedule()
+            .iter()
+            .find(|rule| rule.is_active_at(now))
+            .cloned();
+        if self.applied_rule.as_ref() == Some(&active_rule) {
+            return;
+        }
+
+        let is_first_evaluation = self.is_first_evaluation;
+        self.is_first_evaluation = false;
+        self.applied_rule = Some(active_rule.clone());
+        if is_first_evaluation && active_rule.is_some() && !*config.mine_on_app_start() {
+            info!(target: LOG_TARGET, "Mine on app start is disabled, waiting for the next schedule window");
+            return;
+        }
+
+        let app_handle = &self.app_handle;
+        let (cpu_mining, gpu_mining) = match &active_rule {
+            Some(rule) => {
+                info!(target: LOG_TARGET, "Entering mining schedule window {} - {} with mode {:?}", rule.start_time, rule.end_time, rule.mode);
+                if rule.mode != *config.mode() {
+                    mining_control_utils::switch_mining_mode(app_handle, rule.mode).await;
+                }
+
+                let cpu_mining = if rule.cpu_mining {
+                    mining_control_utils::start_cpu_mining(app_handle).await
+                } else {
+                    mining_control_utils::stop_cpu_mining(app_handle).await;
+                    false
+                };
+                let gpu_mining = if rule.gpu_mining {
+                    mining_control_utils::start_gpu_mining(app_handle).await
+                } else {
+                    mining_control_utils::stop_gpu_mining(app_handle).await;
+                    false
+                };
+                (cpu_mining, gpu_mining)
+            }
+            None => {
+                info!(target: LOG_TARGET, "Outside of the mining schedule, stopping miners");
+                mining_control_utils::stop_cpu_mining(app_handle).await;
+                mining_control_utils::stop_gpu_mining(app_handle).await;
+                (false, false)
+            }
+        };
+
+        EventsEmitter::emit_mining_schedule_update(MiningScheduleUpdatePayload {
+            active_rule,
+            cpu_mining,
+            gpu_mining,
+        })
+        .await;
+    }
+}
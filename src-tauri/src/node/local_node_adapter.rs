@@ -21,6 +21,7 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use crate::ab_test_selector::ABTestSelector;
+use crate::configs::config_core::ConfigCoreContent;
 use crate::node::node_adapter::{
     BaseNodeStatus, NodeAdapter, NodeAdapterService, NodeStatusMonitor,
 };
@@ -68,13 +69,85 @@ impl MinotariNodeMigrationInfo {
     }
 }
 
+/// User managed peer settings, applied on every node start since the node clears its peer db.
+/// Banned peers are only excluded from dialing, the node has no ban list it reads on start.
+#[derive(Clone, Default)]
+pub(crate) struct LocalNodePeerSettings {
+    pub banned_addresses: Vec<String>,
+    pub seed_peers: Vec<String>,
+    pub dns_seeds: Vec<String>,
+    pub max_inbound_connections: Option<u32>,
+    pub max_outbound_connections: Option<u32>,
+}
+
+impl LocalNodePeerSettings {
+    pub fn from_config(config: &ConfigCoreContent) -> Self {
+        Self {
+            banned_addresses: config
+                .local_node_banned_peers()
+                .iter()
+                .flat_map(|banned_peer| banned_peer.addresses.clone())
+                .collect(),
+            seed_peers: config.local_node_seed_peers().clone(),
+            dns_seeds: config.local_node_dns_seeds().clone(),
+            max_inbound_connections: *config.local_node_max_inbound_connections(),
+            max_outbound_connections: *config.local_node_max_outbound_connections(),
+        }
+    }
+
+    /// Node launch arguments for these settings, the user's DNS seeds are added after the network's
+    fn args(&self, network_key: &str, default_dns_seeds: &[String]) -> Vec<String> {
+        let dns_seeds = default_dns_seeds
+            .iter()
+            .chain(self.dns_seeds.iter())
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(",");
+        let mut args = vec![
+            "-p".to_string(),
+            format!("{network_key}.p2p.seeds.dns_seeds={dns_seeds}"),
+        ];
+
+        if !self.seed_peers.is_empty() {
+            args.push("-p".to_string());
+            args.push(format!(
+                "{network_key}.p2p.seeds.peer_seeds={}",
+                self.seed_peers.join(",")
+            ));
+        }
+        if !self.banned_addresses.is_empty() {
+            args.push("-p".to_string());
+            args.push(format!(
+                "base_node.p2p.dht.excluded_dial_addresses={}",
+                self.banned_addresses.join(",")
+            ));
+        }
+        if let Some(max_inbound_connections) = self.max_inbound_connections {
+            args.push("-p".to_string());
+            args.push(format!(
+                "base_node.p2p.max_inbound_connections={max_inbound_connections}"
+            ));
+        }
+        if let Some(max_outbound_connections) = self.max_outbound_connections {
+            args.push("-p".to_string());
+            args.push(format!(
+                "base_node.p2p.max_outbound_connections={max_outbound_connections}"
+            ));
+        }
+        args
+    }
+}
+
 #[derive(Clone)]
 pub(crate) struct LocalNodeAdapter {
     pub(crate) grpc_address: Option<(String, u16)>,
     status_broadcast: watch::Sender<BaseNodeStatus>,
     pub(crate) use_tor: bool,
     pub(crate) tcp_listener_port: u16,
-    pub(crate) use_pruned_mode: bool,
+    /// Blocks kept by a pruned node, `None` runs an archival node
+    pub(crate) pruning_horizon: Option<u64>,
+    pub(crate) custom_data_dir: Option<PathBuf>,
+    pub(crate) peer_settings: LocalNodePeerSettings,
     pub(crate) tor_control_port: Option<u16>,
     required_initial_peers: u32,
     pub(crate) ab_test_group: ABTestSelector,
@@ -88,7 +161,9 @@ impl LocalNodeAdapter {
             grpc_address: Some(("127.0.0.1".to_string(), grpc_port)),
             status_broadcast,
             tcp_listener_port,
-            use_pruned_mode: false,
+            pruning_horizon: None,
+            custom_data_dir: None,
+            peer_settings: LocalNodePeerSettings::default(),
             required_initial_peers: 3,
             use_tor: false,
             tor_control_port: None,
@@ -100,6 +175,13 @@ impl LocalNodeAdapter {
         self.grpc_address.clone()
     }
 
+    /// Directory holding the node's per network data, defaults to `node` in the app data dir
+    pub fn node_dir(&self, data_dir: &Path) -> PathBuf {
+        self.custom_data_dir
+            .clone()
+            .unwrap_or_else(|| data_dir.join("node"))
+    }
+
     pub fn tcp_address(&self) -> String {
         format!("/ip4/127.0.0.1/tcp/{}", self.tcp_listener_port)
     }
@@ -122,7 +204,7 @@ impl NodeAdapter for LocalNodeAdapter {
         self.get_grpc_address()
     }
 
-    fn set_grpc_address(&mut self, _grpc_address: String) -> Result<(), anyhow::Error> {
+    fn set_grpc_addresses(&mut self, _grpc_addresses: Vec<String>) -> Result<(), anyhow::Error> {
         log::error!(target: LOG_TARGET, "Attempted to set gRPC address for local node, which is fixed to localhost.");
         Ok(())
     }
@@ -171,7 +253,7 @@ impl ProcessAdapter for LocalNodeAdapter {
         let inner_shutdown = Shutdown::new();
 
         info!(target: LOG_TARGET, "Starting minotari node");
-        let working_dir: PathBuf = data_dir.join("node");
+        let working_dir: PathBuf = self.node_dir(&data_dir);
         let network_dir = working_dir.join(Network::get_current().to_string().to_lowercase());
         fs::create_dir_all(&network_dir)?;
         let migration_file = network_dir.join("migrations.json");
@@ -257,15 +339,19 @@ impl ProcessAdapter for LocalNodeAdapter {
             "-p".to_string(),
             "base_node.p2p.dht.network_discovery.min_desired_peers=12".to_string(),
         ];
-        if self.use_pruned_mode {
+        if let Some(pruning_horizon) = self.pruning_horizon {
             args.push("-p".to_string());
-            args.push("base_node.storage.pruning_horizon=100".to_string());
+            args.push(format!(
+                "base_node.storage.pruning_horizon={pruning_horizon}"
+            ));
         }
         // Uncomment to test winning blocks
         // if cfg!(debug_assertions) {
         // args.push("--network".to_string());
         // args.push("localnet".to_string());
         // }
+        let network = Network::get_current_or_user_setting_or_default();
+        let default_dns_seeds;
         if self.use_tor {
             // args.push("-p".to_string());
             // args.push(
@@ -293,22 +379,10 @@ impl ProcessAdapter for LocalNodeAdapter {
                     "base_node.p2p.transport.tor.control_address=/ip4/127.0.0.1/tcp/{tor_control_port}"
                 ));
             }
-            let network = Network::get_current_or_user_setting_or_default();
-            args.push("-p".to_string());
-            match network {
-                Network::MainNet => {
-                    args.push(format!(
-                        "{key}.p2p.seeds.dns_seeds=seeds.tari.com",
-                        key = network.as_key_str(),
-                    ));
-                }
-                _ => {
-                    args.push(format!(
-                        "{key}.p2p.seeds.dns_seeds=seeds.{key}.tari.com",
-                        key = network.as_key_str(),
-                    ));
-                }
-            }
+            default_dns_seeds = match network {
+                Network::MainNet => vec!["seeds.tari.com".to_string()],
+                _ => vec![format!("seeds.{}.tari.com", network.as_key_str())],
+            };
         } else {
             args.push("-p".to_string());
             args.push("base_node.p2p.transport.type=tcp".to_string());
@@ -322,24 +396,23 @@ impl ProcessAdapter for LocalNodeAdapter {
                 "base_node.p2p.transport.tcp.listener_address=/ip4/127.0.0.1/tcp/{}",
                 self.tcp_listener_port
             ));
-            let network = Network::get_current_or_user_setting_or_default();
-            args.push("-p".to_string());
-            match network {
-                Network::MainNet => {
-                    args.push(format!(
-                        "{key}.p2p.seeds.dns_seeds=ip4.seeds.tari.com,ip6.seeds.tari.com",
-                        key = network.as_key_str(),
-                    ));
-                }
-                _ => {
-                    args.push(format!(
-                        "{key}.p2p.seeds.dns_seeds=ip4.seeds.{key}.tari.com,ip6.seeds.{key}.tari.com",
-                        key = network.as_key_str(),
-                    ));
-                }
-            }
+            default_dns_seeds = match network {
+                Network::MainNet => vec![
+                    "ip4.seeds.tari.com".to_string(),
+                    "ip6.seeds.tari.com".to_string(),
+                ],
+                _ => vec![
+                    format!("ip4.seeds.{}.tari.com", network.as_key_str()),
+                    format!("ip6.seeds.{}.tari.com", network.as_key_str()),
+                ],
+            };
         }
 
+        args.extend(
+            self.peer_settings
+                .args(network.as_key_str(), &default_dns_seeds),
+        );
+
         // AB testing
         if self.ab_test_group == ABTestSelector::GroupB {
             info!(target: LOG_TARGET, "Using AB test group B");
@@ -381,7 +454,9 @@ impl ProcessAdapter for LocalNodeAdapter {
                 self.status_broadcast.clone(),
                 Arc::new(AtomicU64::new(0)),
                 Some(data_dir),
-            ),
+                None,
+            )
+            .with_node_dir(network_dir),
         ))
     }
 
@@ -393,3 +468,84 @@ impl ProcessAdapter for LocalNodeAdapter {
         "node_pid"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::configs::config_core::BannedPeer;
+
+    use super::*;
+
+    #[test]
+    fn banned_peer_addresses_are_excluded_from_dialing() {
+        let mut config = ConfigCoreContent::default();
+        config.set_local_node_banned_peers(vec![BannedPeer {
+            public_key: "aa".to_string(),
+            addresses: vec![
+                "/ip4/10.0.0.3/tcp/18189".to_string(),
+                "/ip4/10.0.0.4/tcp/18189".to_string(),
+            ],
+        }]);
+        let args = LocalNodePeerSettings::from_config(&config).args("mainnet", &[]);
+
+        assert!(args.contains(
+            &"base_node.p2p.dht.excluded_dial_addresses=/ip4/10.0.0.3/tcp/18189,/ip4/10.0.0.4/tcp/18189"
+                .to_string()
+        ));
+        assert!(
+            !LocalNodePeerSettings::from_config(&ConfigCoreContent::default())
+                .args("mainnet", &[])
+                .iter()
+                .any(|arg| arg.contains("excluded_dial_addresses"))
+        );
+    }
+
+    #[test]
+    fn peer_settings_args_append_user_dns_seeds() {
+        let peer_settings = LocalNodePeerSettings {
+            dns_seeds: vec!["seeds.example.com".to_string()],
+            ..Default::default()
+        };
+        let args = peer_settings.args("mainnet", &["ip4.seeds.tari.com".to_string()]);
+
+        assert_eq!(
+            args,
+            vec![
+                "-p".to_string(),
+                "mainnet.p2p.seeds.dns_seeds=ip4.seeds.tari.com,seeds.example.com".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn peer_settings_args_include_seed_peers_and_limits() {
+        let peer_settings = LocalNodePeerSettings {
+            seed_peers: vec![
+                "aa::/ip4/10.0.0.1/tcp/18189".to_string(),
+                "bb::/ip4/10.0.0.2/tcp/18189".to_string(),
+            ],
+            banned_addresses: vec!["/ip4/10.0.0.3/tcp/18189".to_string()],
+            max_inbound_connections: Some(20),
+            ..Default::default()
+        };
+        let args = peer_settings.args("esmeralda", &[]);
+
+        assert!(args.contains(
+            &"esmeralda.p2p.seeds.peer_seeds=aa::/ip4/10.0.0.1/tcp/18189,bb::/ip4/10.0.0.2/tcp/18189"
+                .to_string()
+        ));
+        assert!(args.contains(
+            &"base_node.p2p.dht.excluded_dial_addresses=/ip4/10.0.0.3/tcp/18189".to_string()
+        ));
+        assert!(args.contains(&"base_node.p2p.max_inbound_connections=20".to_string()));
+        assert!(!args
+            .iter()
+            .any(|arg| arg.starts_with("base_node.p2p.max_outbound_connections")));
+    }
+
+    #[test]
+    fn peer_settings_args_skip_empty_seed_peers() {
+        let args = LocalNodePeerSettings::default().args("mainnet", &[]);
+
+        assert!(!args.iter().any(|arg| arg.contains("peer_seeds")));
+    }
+}
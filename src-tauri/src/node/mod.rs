@@ -22,5 +22,7 @@
 
 pub mod local_node_adapter;
 pub mod node_adapter;
+pub mod node_disk_monitor;
 pub mod node_manager;
+pub mod node_snapshot;
 pub mod remote_node_adapter;
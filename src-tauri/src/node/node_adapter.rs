@@ -23,6 +23,8 @@
 use crate::ab_test_selector::ABTestSelector;
 use crate::node::node_manager::NodeType;
 use crate::process_adapter::{HealthStatus, StatusMonitor};
+use crate::setup::setup_manager::SetupManager;
+use crate::tasks_tracker::TasksTrackers;
 use anyhow::{anyhow, Error};
 use async_trait::async_trait;
 use minotari_node_grpc_client::grpc::{
@@ -36,6 +38,7 @@ use tokio::fs;
 use chrono::{NaiveDateTime, TimeZone, Utc};
 use log::{error, info, warn};
 use minotari_node_grpc_client::BaseNodeGrpcClient;
+use multiaddr::Multiaddr;
 use serde::Serialize;
 use std::collections::HashMap;
 use std::fmt::Write as _;
@@ -49,15 +52,17 @@ use tari_utilities::hex::Hex;
 use tari_utilities::ByteArray;
 use tokio::sync::watch;
 use tokio::time::timeout;
+use tonic::transport::Channel;
 
 use crate::network_utils::{get_best_block_from_block_scan, get_block_info_from_block_scan};
+use crate::RemoteNodeAdapter;
 
 const LOG_TARGET: &str = "tari::universe::minotari_node_adapter";
 
 #[async_trait]
 pub trait NodeAdapter {
     fn get_grpc_address(&self) -> Option<(String, u16)>;
-    fn set_grpc_address(&mut self, grpc_address: String) -> Result<(), anyhow::Error>;
+    fn set_grpc_addresses(&mut self, grpc_addresses: Vec<String>) -> Result<(), anyhow::Error>;
     fn get_service(&self) -> Option<NodeAdapterService>;
     async fn get_connection_details(&self) -> Result<(RistrettoPublicKey, String), anyhow::Error>;
     fn use_tor(&mut self, use_tor: bool);
@@ -79,8 +84,13 @@ impl NodeAdapterService {
         }
     }
 
+    async fn connect(&self) -> Result<BaseNodeGrpcClient<Channel>, anyhow::Error> {
+        Ok(BaseNodeGrpcClient::connect(self.connection_address.clone()).await?)
+    }
+
     pub async fn get_network_state(&self) -> Result<BaseNodeStatus, NodeStatusMonitorError> {
-        let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone())
+        let mut client = self
+            .connect()
             .await
             .map_err(|_| NodeStatusMonitorError::NodeNotStarted)?;
 
@@ -115,7 +125,7 @@ impl NodeAdapterService {
         &self,
         heights: Vec<u64>,
     ) -> Result<Vec<(u64, String)>, Error> {
-        let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone()).await?;
+        let mut client = self.connect().await?;
 
         let mut res = client
             .get_blocks(GetBlocksRequest { heights })
@@ -141,7 +151,7 @@ impl NodeAdapterService {
     }
 
     pub async fn get_identity(&self) -> Result<NodeIdentity, Error> {
-        let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone()).await?;
+        let mut client = self.connect().await?;
         let id = client.identify(Empty {}).await?;
         let res = id.into_inner();
 
@@ -159,7 +169,8 @@ impl NodeAdapterService {
         progress_percentage_tx: &watch::Sender<f64>,
         shutdown_signal: ShutdownSignal,
     ) -> Result<u64, NodeStatusMonitorError> {
-        let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone())
+        let mut client = self
+            .connect()
             .await
             .map_err(|_e| NodeStatusMonitorError::NodeNotStarted)?;
 
@@ -274,7 +285,7 @@ impl NodeAdapterService {
     }
 
     pub async fn list_connected_peers(&self) -> Result<Vec<String>, anyhow::Error> {
-        let mut client = BaseNodeGrpcClient::connect(self.connection_address.clone()).await?;
+        let mut client = self.connect().await?;
         let peers_list = client
             .list_connected_peers(Empty {})
             .await
@@ -307,6 +318,35 @@ impl NodeAdapterService {
         Ok(connected_peers)
     }
 
+    pub async fn list_peers(&self) -> Result<Vec<NodePeer>, anyhow::Error> {
+        let mut client = self.connect().await?;
+        let peers = client
+            .list_connected_peers(Empty {})
+            .await
+            .map_err(|e| anyhow::anyhow!("Error list_connected_peers: {}", e))?
+            .into_inner()
+            .connected_peers;
+
+        Ok(peers
+            .into_iter()
+            .map(|peer| NodePeer {
+                public_key: peer.public_key.to_hex(),
+                addresses: peer
+                    .addresses
+                    .iter()
+                    .map(|address| multiaddr_to_string(&address.address))
+                    .collect(),
+                latency_ms: peer
+                    .addresses
+                    .iter()
+                    .filter_map(|address| address.avg_latency.as_ref())
+                    .map(|avg_latency| avg_latency.latency)
+                    .min(),
+                user_agent: peer.user_agent,
+            })
+            .collect())
+    }
+
     pub async fn check_if_is_orphan_chain(&self) -> Result<bool, anyhow::Error> {
         let BaseNodeStatus { is_synced, .. } = self.get_network_state().await?;
         if !is_synced {
@@ -354,6 +394,10 @@ pub(crate) struct NodeStatusMonitor {
     status_broadcast: watch::Sender<BaseNodeStatus>,
     last_block_time: Arc<AtomicU64>,
     base_path: Option<PathBuf>,
+    /// Local node data for the current network, when kept outside `base_path`
+    node_dir: Option<PathBuf>,
+    /// Lets a remote node monitor move on to another remote node
+    remote_node_adapter: Option<RemoteNodeAdapter>,
 }
 
 impl NodeStatusMonitor {
@@ -363,6 +407,7 @@ impl NodeStatusMonitor {
         status_broadcast: watch::Sender<BaseNodeStatus>,
         last_block_time: Arc<AtomicU64>,
         base_path: Option<PathBuf>,
+        remote_node_adapter: Option<RemoteNodeAdapter>,
     ) -> Self {
         Self {
             node_type,
@@ -370,14 +415,29 @@ impl NodeStatusMonitor {
             status_broadcast,
             last_block_time,
             base_path,
+            node_dir: None,
+            remote_node_adapter,
         }
     }
+
+    pub fn with_node_dir(mut self, node_dir: PathBuf) -> Self {
+        self.node_dir = Some(node_dir);
+        self
+    }
+
+    fn node_service(&self) -> NodeAdapterService {
+        self.remote_node_adapter
+            .as_ref()
+            .and_then(|remote_node_adapter| remote_node_adapter.get_service())
+            .unwrap_or_else(|| self.node_service.clone())
+    }
 }
 
 #[async_trait]
 impl StatusMonitor for NodeStatusMonitor {
     async fn check_health(&self, uptime: Duration, timeout_duration: Duration) -> HealthStatus {
-        match timeout(timeout_duration, self.node_service.get_network_state()).await {
+        let node_service = self.node_service();
+        match timeout(timeout_duration, node_service.get_network_state()).await {
             Ok(res) => match res {
                 Ok(status) => {
                     let _res = self.status_broadcast.send(status);
@@ -433,7 +493,7 @@ impl StatusMonitor for NodeStatusMonitor {
                     "{:?} Node Health Check (get_network_state) error: {:?}",
                     self.node_type, e
                 );
-                match self.node_service.get_identity().await {
+                match node_service.get_identity().await {
                     Ok(identity) => {
                         info!(target: LOG_TARGET, "{:?} Node checking base node identity success: {:?}", self.node_type, identity);
                         return HealthStatus::Warning;
@@ -452,25 +512,32 @@ impl StatusMonitor for NodeStatusMonitor {
 
     async fn handle_unhealthy(&self) -> Result<(), anyhow::Error> {
         if self.node_type == NodeType::Remote {
-            // Do not clear local node files for remote nodes
+            // Do not clear local node files for remote nodes, try another remote node instead
+            if let Some(remote_node_adapter) = &self.remote_node_adapter {
+                if remote_node_adapter.fail_over().await {
+                    // The wallet and miners were handed the old address when their phases started
+                    TasksTrackers::current()
+                        .common
+                        .get_task_tracker()
+                        .await
+                        .spawn(async {
+                            SetupManager::get_instance()
+                                .handle_remote_node_failover()
+                                .await;
+                        });
+                }
+            }
             return Ok(());
         }
 
         if let Some(ref base_path) = self.base_path {
-            let _unused = fs::remove_dir_all(
-                base_path
-                    .join("node")
-                    .join(Network::get_current().to_string().to_lowercase())
-                    .join("peer_db"),
-            )
-            .await;
-            let _unused = fs::remove_dir_all(
+            let node_dir = self.node_dir.clone().unwrap_or_else(|| {
                 base_path
                     .join("node")
                     .join(Network::get_current().to_string().to_lowercase())
-                    .join("libtor"),
-            )
-            .await;
+            });
+            let _unused = fs::remove_dir_all(node_dir.join("peer_db")).await;
+            let _unused = fs::remove_dir_all(node_dir.join("libtor")).await;
             let _unused = fs::remove_dir_all(base_path.join("tor-data")).await;
         }
 
@@ -478,6 +545,21 @@ impl StatusMonitor for NodeStatusMonitor {
     }
 }
 
+#[derive(Clone, Debug, Serialize)]
+pub struct NodePeer {
+    pub public_key: String,
+    pub addresses: Vec<String>,
+    /// Lowest average latency across the peer's addresses, as measured by the node
+    pub latency_ms: Option<u64>,
+    pub user_agent: String,
+}
+
+fn multiaddr_to_string(address: &[u8]) -> String {
+    Multiaddr::try_from(address.to_vec())
+        .map(|address| address.to_string())
+        .unwrap_or_else(|_| address.to_hex())
+}
+
 #[derive(Clone, Debug, Serialize)]
 pub struct NodeIdentity {
     pub public_key: RistrettoPublicKey,
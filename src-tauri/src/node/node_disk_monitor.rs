@@ -0,0 +1,156 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use log::{info, warn};
+use sysinfo::Disks;
+use tauri::{AppHandle, Manager};
+use tokio::select;
+use tokio::time::MissedTickBehavior;
+
+use crate::configs::config_core::ConfigCore;
+use crate::configs::trait_config::ConfigImpl;
+use crate::events::NodeDiskSpaceLowPayload;
+use crate::events_emitter::EventsEmitter;
+use crate::tasks_tracker::TasksTrackers;
+use crate::UniverseAppState;
+
+const LOG_TARGET: &str = "tari::universe::node_disk_monitor";
+const DISK_CHECK_INTERVAL: Duration = Duration::from_secs(5 * 60);
+const BYTES_PER_GB: u64 = 1_000_000_000;
+
+/// Warns when the volume holding the local node's chain data is running out of space, an archival
+/// node keeps growing and a full disk corrupts its database
+pub struct NodeDiskMonitor {
+    app_handle: AppHandle,
+    /// Set after warning, so the warning is repeated only once space was freed and ran low again
+    has_warned: bool,
+}
+
+impl NodeDiskMonitor {
+    pub async fn start(app_handle: AppHandle) {
+        let mut node_disk_monitor = Self {
+            app_handle,
+            has_warned: false,
+        };
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let mut interval = tokio::time::interval(DISK_CHECK_INTERVAL);
+                interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+                loop {
+                    select! {
+                        _ = interval.tick() => {
+                            node_disk_monitor.check_disk_space().await;
+                        }
+                        _ = shutdown_signal.wait() => {
+                            info!(target: LOG_TARGET, "Shutdown signal received, stopping node disk monitor");
+                            break;
+                        }
+                    }
+                }
+            });
+    }
+
+    async fn check_disk_space(&mut self) {
+        let state = self.app_handle.state::<UniverseAppState>();
+        if !state.node_manager.is_local().await.unwrap_or(false) {
+            return;
+        }
+        let Ok(data_dir) = self.app_handle.path().app_local_data_dir() else {
+            return;
+        };
+        let node_dir = state.node_manager.get_local_node_dir(&data_dir).await;
+        let min_free_space = ConfigCore::content()
+            .await
+            .local_node_min_free_disk_space_gb()
+            .saturating_mul(BYTES_PER_GB);
+
+        let disks = Disks::new_with_refreshed_list();
+        let Some((mount_point, available_space)) = volume_for_path(
+            disks
+                .list()
+                .iter()
+                .map(|disk| (disk.mount_point().to_path_buf(), disk.available_space())),
+            &node_dir,
+        ) else {
+            return;
+        };
+
+        if available_space >= min_free_space {
+            self.has_warned = false;
+            return;
+        }
+        if self.has_warned {
+            return;
+        }
+        warn!(target: LOG_TARGET, "Only {} GB left on {} for the node data in {}", available_space / BYTES_PER_GB, mount_point.display(), node_dir.display());
+        self.has_warned = true;
+        EventsEmitter::emit_node_disk_space_low(NodeDiskSpaceLowPayload {
+            mount_point: mount_point.to_string_lossy().to_string(),
+            available_space,
+            min_free_space,
+        })
+        .await;
+    }
+}
+
+/// The volume with the deepest mount point containing `path`, with its available space
+fn volume_for_path(
+    volumes: impl Iterator<Item = (PathBuf, u64)>,
+    path: &Path,
+) -> Option<(PathBuf, u64)> {
+    volumes
+        .filter(|(mount_point, _)| path.starts_with(mount_point))
+        .max_by_key(|(mount_point, _)| mount_point.components().count())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn volume_for_path_picks_deepest_mount_point() {
+        let volumes = vec![
+            (PathBuf::from("/"), 1),
+            (PathBuf::from("/mnt/data"), 2),
+            (PathBuf::from("/mnt/database"), 3),
+        ];
+        let (mount_point, available_space) = volume_for_path(
+            volumes.clone().into_iter(),
+            Path::new("/mnt/data/tari/node/mainnet"),
+        )
+        .unwrap();
+        assert_eq!(mount_point, PathBuf::from("/mnt/data"));
+        assert_eq!(available_space, 2);
+
+        let (mount_point, _) =
+            volume_for_path(volumes.into_iter(), Path::new("/home/user/.local/share")).unwrap();
+        assert_eq!(mount_point, PathBuf::from("/"));
+    }
+}
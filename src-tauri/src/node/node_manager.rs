@@ -27,27 +27,30 @@ use std::sync::Arc;
 use std::time::Duration;
 
 use log::{error, info, warn};
+use multiaddr::Multiaddr;
 use serde::{Deserialize, Serialize};
 use tari_common::configuration::Network;
 use tari_crypto::ristretto::RistrettoPublicKey;
 use tari_shutdown::ShutdownSignal;
+use tari_utilities::ByteArray;
 use tokio::sync::watch::{self, Sender};
 use tokio::sync::RwLock;
 use tokio::time::sleep;
 use tokio::{fs, select};
 use tokio_util::task::TaskTracker;
 
-use crate::configs::config_core::ConfigCore;
+use crate::configs::config_core::{BannedPeer, ConfigCore, ConfigCoreContent};
 use crate::configs::trait_config::ConfigImpl;
 use crate::events_emitter::EventsEmitter;
+use crate::node::local_node_adapter::LocalNodePeerSettings;
 use crate::node::node_adapter::{
-    NodeAdapter, NodeAdapterService, NodeIdentity, NodeStatusMonitorError,
+    NodeAdapter, NodeAdapterService, NodeIdentity, NodePeer, NodeStatusMonitorError,
 };
 use crate::process_adapter::ProcessAdapter;
 use crate::process_stats_collector::ProcessStatsCollectorBuilder;
 use crate::process_watcher::ProcessWatcher;
 use crate::process_watcher::ProcessWatcherStats;
-use crate::setup::setup_manager::SetupManager;
+use crate::setup::setup_manager::{SetupManager, SetupPhase};
 use crate::tasks_tracker::TasksTrackers;
 use crate::{BaseNodeStatus, LocalNodeAdapter, RemoteNodeAdapter};
 
@@ -62,6 +65,9 @@ pub enum NodeManagerError {
 }
 
 pub const STOP_ON_ERROR_CODES: [i32; 2] = [114, 102];
+/// The wallet and miners follow the node, so they restart with it
+pub const LOCAL_NODE_SETTINGS_RESTART_PHASES: [SetupPhase; 3] =
+    [SetupPhase::Node, SetupPhase::Wallet, SetupPhase::Mining];
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub enum NodeType {
@@ -69,6 +75,8 @@ pub enum NodeType {
     Remote,
     RemoteUntilLocal,
     LocalAfterRemote,
+    /// A node run by the user, reached through `ConfigCoreContent::custom_node`
+    Custom,
 }
 
 impl NodeType {
@@ -79,7 +87,10 @@ impl NodeType {
         )
     }
     pub fn is_remote(&self) -> bool {
-        matches!(self, NodeType::Remote | NodeType::RemoteUntilLocal)
+        matches!(
+            self,
+            NodeType::Remote | NodeType::RemoteUntilLocal | NodeType::Custom
+        )
     }
 }
 
@@ -121,7 +132,9 @@ impl NodeManager {
 
         let current_adapter: Box<dyn NodeAdapter + Send + Sync> = match node_type {
             NodeType::Local | NodeType::LocalAfterRemote => Box::new(local_node_adapter),
-            NodeType::Remote | NodeType::RemoteUntilLocal => Box::new(remote_node_adapter),
+            NodeType::Remote | NodeType::RemoteUntilLocal | NodeType::Custom => {
+                Box::new(remote_node_adapter)
+            }
         };
 
         Self {
@@ -144,7 +157,7 @@ impl NodeManager {
         log_path: PathBuf,
         use_tor: bool,
         tor_control_port: Option<u16>,
-        remote_grpc_address: Option<String>,
+        remote_grpc_addresses: Option<Vec<String>>,
     ) -> Result<(), NodeManagerError> {
         let shutdown_signal = TasksTrackers::current().node_phase.get_signal().await;
         let task_tracker = TasksTrackers::current().node_phase.get_task_tracker().await;
@@ -172,11 +185,20 @@ impl NodeManager {
             self.configure_adapter(
                 self.remote_node_watcher.clone(),
                 self.is_remote_current().await?,
-                remote_grpc_address,
+                remote_grpc_addresses,
                 use_tor,
                 None, // no control port needed
             )
             .await?;
+            let remote_node_adapter = self
+                .remote_node_watcher
+                .read()
+                .await
+                .as_ref()
+                .map(|remote_node_watcher| remote_node_watcher.adapter.clone());
+            if let Some(remote_node_adapter) = remote_node_adapter {
+                remote_node_adapter.select_best_grpc_address().await;
+            }
             start_watcher(
                 &self.remote_node_watcher,
                 base_path,
@@ -209,7 +231,7 @@ impl NodeManager {
         &self,
         node_watcher: Arc<RwLock<Option<ProcessWatcher<T>>>>,
         is_current: bool,
-        remote_grpc_address: Option<String>,
+        remote_grpc_addresses: Option<Vec<String>>,
         use_tor: bool,
         tor_control_port: Option<u16>,
     ) -> Result<(), anyhow::Error>
@@ -223,8 +245,10 @@ impl NodeManager {
             let ab_group = *ConfigCore::content().await.ab_group();
             node_watcher.adapter.set_ab_group(ab_group);
 
-            if let Some(remote_grpc_address) = remote_grpc_address {
-                node_watcher.adapter.set_grpc_address(remote_grpc_address)?;
+            if let Some(remote_grpc_addresses) = remote_grpc_addresses {
+                node_watcher
+                    .adapter
+                    .set_grpc_addresses(remote_grpc_addresses)?;
             }
 
             if is_current {
@@ -316,13 +340,98 @@ impl NodeManager {
         Ok(())
     }
 
-    pub async fn clean_data_folder(&self, base_path: &Path) -> Result<(), anyhow::Error> {
-        fs::remove_dir_all(
-            base_path
-                .join("node")
-                .join(Network::get_current().to_string().to_lowercase()),
+    pub async fn set_local_node_storage(
+        &self,
+        pruning_horizon: Option<u64>,
+        custom_data_dir: Option<PathBuf>,
+    ) {
+        let mut local_node_watcher = self.local_node_watcher.write().await;
+        if let Some(local_node_watcher) = local_node_watcher.as_mut() {
+            local_node_watcher.adapter.pruning_horizon = pruning_horizon;
+            local_node_watcher.adapter.custom_data_dir = custom_data_dir;
+        }
+    }
+
+    pub async fn set_local_node_peer_settings(&self, peer_settings: LocalNodePeerSettings) {
+        let mut local_node_watcher = self.local_node_watcher.write().await;
+        if let Some(local_node_watcher) = local_node_watcher.as_mut() {
+            local_node_watcher.adapter.peer_settings = peer_settings;
+        }
+    }
+
+    /// Where the local node keeps its chain data for the current network
+    pub async fn get_local_node_dir(&self, base_path: &Path) -> PathBuf {
+        let node_dir = match self.local_node_watcher.read().await.as_ref() {
+            Some(local_node_watcher) => local_node_watcher.adapter.node_dir(base_path),
+            None => base_path.join("node"),
+        };
+        node_dir.join(Network::get_current().to_string().to_lowercase())
+    }
+
+    /// Archival and pruned databases can't be converted into one another, so switching the mode of
+    /// the chain data already in the node dir means syncing from scratch. A different data dir is
+    /// taken as is, it may hold a database the user prepared for the new mode.
+    pub async fn storage_switch_needs_resync(
+        &self,
+        base_path: &Path,
+        pruned_mode: bool,
+        data_dir: &Option<PathBuf>,
+    ) -> bool {
+        let config = ConfigCore::content().await;
+        pruned_mode != *config.local_node_pruned_mode()
+            && data_dir == config.local_node_data_dir()
+            && self
+                .get_local_node_dir(base_path)
+                .await
+                .join("data")
+                .exists()
+    }
+
+    pub fn validate_local_node_storage_settings(
+        pruned_mode: bool,
+        pruning_horizon: u64,
+        data_dir: &Option<PathBuf>,
+    ) -> Result<(), anyhow::Error> {
+        if pruned_mode && pruning_horizon == 0 {
+            return Err(anyhow::anyhow!(
+                "Pruning horizon must be at least one block"
+            ));
+        }
+        if let Some(data_dir) = data_dir {
+            if !data_dir.is_absolute() {
+                return Err(anyhow::anyhow!(
+                    "Node data dir {} is not an absolute path",
+                    data_dir.display()
+                ));
+            }
+            if data_dir.exists() && !data_dir.is_dir() {
+                return Err(anyhow::anyhow!(
+                    "Node data dir {} is not a directory",
+                    data_dir.display()
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub async fn set_local_node_storage_settings(
+        &self,
+        pruned_mode: bool,
+        pruning_horizon: u64,
+        data_dir: Option<PathBuf>,
+    ) -> Result<(), anyhow::Error> {
+        ConfigCore::update_field(ConfigCoreContent::set_local_node_pruned_mode, pruned_mode)
+            .await?;
+        ConfigCore::update_field(
+            ConfigCoreContent::set_local_node_pruning_horizon,
+            pruning_horizon,
         )
         .await?;
+        ConfigCore::update_field(ConfigCoreContent::set_local_node_data_dir, data_dir).await
+    }
+
+    pub async fn clean_data_folder(&self, base_path: &Path) -> Result<(), anyhow::Error> {
+        fs::remove_dir_all(self.get_local_node_dir(base_path).await).await?;
         self.local_node_db_cleared
             .store(true, std::sync::atomic::Ordering::SeqCst);
         Ok(())
@@ -388,6 +497,114 @@ impl NodeManager {
         current_service.list_connected_peers().await
     }
 
+    // Peer management, changes are persisted and applied when the node phase restarts
+
+    pub async fn list_peers(&self) -> Result<Vec<NodePeer>, anyhow::Error> {
+        let current_service = self.get_current_service().await?;
+        current_service.list_peers().await
+    }
+
+    /// A ban only stops the node from dialing the peer's known addresses, the peer can still connect
+    /// inbound. The node has no ban list it reads on start and its peer db is cleared on every start,
+    /// so there is no ban by public key to apply. Peers that aren't connected can only be banned while
+    /// addresses from an earlier ban are known.
+    pub async fn ban_peer(&self, public_key: String) -> Result<(), anyhow::Error> {
+        let public_key = public_key.trim().to_lowercase();
+        validate_public_key(&public_key)?;
+        let connected_addresses = match self.list_peers().await {
+            Ok(peers) => peers
+                .into_iter()
+                .find(|peer| peer.public_key == public_key)
+                .map(|peer| peer.addresses),
+            Err(e) => {
+                warn!(target: LOG_TARGET, "Could not list peers while banning {}: {}", public_key, e);
+                None
+            }
+        };
+        let mut banned_peers = ConfigCore::content()
+            .await
+            .local_node_banned_peers()
+            .clone();
+        let known_addresses = banned_peers
+            .iter()
+            .find(|banned_peer| banned_peer.public_key == public_key)
+            .map(|banned_peer| banned_peer.addresses.clone());
+        let addresses = connected_addresses
+            .filter(|addresses| !addresses.is_empty())
+            .or(known_addresses)
+            .unwrap_or_default();
+        if addresses.is_empty() {
+            return Err(anyhow::anyhow!(
+                "No addresses are known for peer {}, it has to be connected to be banned",
+                public_key
+            ));
+        }
+        banned_peers.retain(|banned_peer| banned_peer.public_key != public_key);
+        banned_peers.push(BannedPeer {
+            public_key,
+            addresses,
+        });
+        ConfigCore::update_field_requires_restart(
+            ConfigCoreContent::set_local_node_banned_peers,
+            banned_peers,
+            LOCAL_NODE_SETTINGS_RESTART_PHASES.to_vec(),
+        )
+        .await
+    }
+
+    pub async fn unban_peer(&self, public_key: &str) -> Result<(), anyhow::Error> {
+        let mut banned_peers = ConfigCore::content()
+            .await
+            .local_node_banned_peers()
+            .clone();
+        banned_peers.retain(|banned_peer| banned_peer.public_key != public_key);
+        ConfigCore::update_field_requires_restart(
+            ConfigCoreContent::set_local_node_banned_peers,
+            banned_peers,
+            LOCAL_NODE_SETTINGS_RESTART_PHASES.to_vec(),
+        )
+        .await
+    }
+
+    pub async fn set_seed_peers(&self, seed_peers: Vec<String>) -> Result<(), anyhow::Error> {
+        seed_peers
+            .iter()
+            .try_for_each(|seed_peer| validate_seed_peer(seed_peer))?;
+        ConfigCore::update_field_requires_restart(
+            ConfigCoreContent::set_local_node_seed_peers,
+            seed_peers,
+            LOCAL_NODE_SETTINGS_RESTART_PHASES.to_vec(),
+        )
+        .await
+    }
+
+    pub async fn set_dns_seeds(&self, dns_seeds: Vec<String>) -> Result<(), anyhow::Error> {
+        ConfigCore::update_field_requires_restart(
+            ConfigCoreContent::set_local_node_dns_seeds,
+            dns_seeds,
+            LOCAL_NODE_SETTINGS_RESTART_PHASES.to_vec(),
+        )
+        .await
+    }
+
+    pub async fn set_connection_limits(
+        &self,
+        max_inbound_connections: Option<u32>,
+        max_outbound_connections: Option<u32>,
+    ) -> Result<(), anyhow::Error> {
+        ConfigCore::update_field(
+            ConfigCoreContent::set_local_node_max_inbound_connections,
+            max_inbound_connections,
+        )
+        .await?;
+        ConfigCore::update_field_requires_restart(
+            ConfigCoreContent::set_local_node_max_outbound_connections,
+            max_outbound_connections,
+            LOCAL_NODE_SETTINGS_RESTART_PHASES.to_vec(),
+        )
+        .await
+    }
+
     // Self Checks
     pub async fn is_local(&self) -> Result<bool, anyhow::Error> {
         let node_type = self.get_node_type().await?;
@@ -413,6 +630,35 @@ impl NodeManager {
 }
 
 // Helpers
+fn validate_public_key(public_key: &str) -> Result<(), anyhow::Error> {
+    let bytes = hex::decode(public_key)
+        .map_err(|_| anyhow::anyhow!("Public key {} is not hex encoded", public_key))?;
+    RistrettoPublicKey::from_canonical_bytes(&bytes)
+        .map_err(|e| anyhow::anyhow!("Public key {} is invalid: {}", public_key, e))?;
+    Ok(())
+}
+
+/// Seed peers are given as `<public key>::<address>`, with one or more `::` separated addresses
+fn validate_seed_peer(seed_peer: &str) -> Result<(), anyhow::Error> {
+    let mut parts = seed_peer.split("::");
+    let public_key = parts.next().unwrap_or_default();
+    validate_public_key(public_key)
+        .map_err(|e| anyhow::anyhow!("Seed peer {}: {}", seed_peer, e))?;
+    let addresses = parts.collect::<Vec<_>>();
+    if addresses.is_empty() {
+        return Err(anyhow::anyhow!(
+            "Seed peer {} is not in the <public key>::<address> format",
+            seed_peer
+        ));
+    }
+    for address in addresses {
+        address.parse::<Multiaddr>().map_err(|e| {
+            anyhow::anyhow!("Seed peer {} has an invalid address: {}", seed_peer, e)
+        })?;
+    }
+    Ok(())
+}
+
 fn construct_process_watcher<T: NodeAdapter + ProcessAdapter + Send + Sync + 'static>(
     stats_broadcast: Sender<ProcessWatcherStats>,
     node_adapter: T,
@@ -735,3 +981,37 @@ async fn switch_to_local(node_manager: NodeManager, node_type: Arc<RwLock<NodeTy
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Compressed ristretto255 base point, a valid public key
+    const PUBLIC_KEY: &str = "e2f2ae0a6abc4e71a884a961c500515f58e30b6aa582dd8db6a65945e08d2d76";
+
+    #[test]
+    fn validate_seed_peer_accepts_key_and_addresses() {
+        assert!(validate_seed_peer(&format!("{PUBLIC_KEY}::/ip4/10.0.0.1/tcp/18189")).is_ok());
+        assert!(validate_seed_peer(&format!(
+            "{PUBLIC_KEY}::/ip4/10.0.0.1/tcp/18189::/dns4/seed.example.com/tcp/18189"
+        ))
+        .is_ok());
+    }
+
+    #[test]
+    fn validate_seed_peer_rejects_malformed_entries() {
+        assert!(validate_seed_peer(PUBLIC_KEY).is_err());
+        assert!(validate_seed_peer("/ip4/10.0.0.1/tcp/18189").is_err());
+        assert!(validate_seed_peer("not-a-key::/ip4/10.0.0.1/tcp/18189").is_err());
+        assert!(validate_seed_peer(&format!("{PUBLIC_KEY}::10.0.0.1:18189")).is_err());
+        assert!(
+            validate_seed_peer(&format!("{}::/ip4/10.0.0.1/tcp/18189", &PUBLIC_KEY[2..])).is_err()
+        );
+    }
+
+    #[test]
+    fn validate_public_key_requires_a_ristretto_point() {
+        assert!(validate_public_key(PUBLIC_KEY).is_ok());
+        assert!(validate_public_key(&"ff".repeat(32)).is_err());
+    }
+}
@@ -0,0 +1,283 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use anyhow::anyhow;
+use base64::prelude::*;
+use log::{info, warn};
+use ring::signature::{UnparsedPublicKey, ED25519};
+use tari_common::configuration::Network;
+use tokio::fs;
+use tokio::sync::watch;
+
+use crate::download_utils::{extract, validate_checksum};
+use crate::github::request_client::RequestClient;
+use crate::progress_trackers::progress_stepper::ChanneledStepUpdate;
+use crate::tasks_tracker::TasksTrackers;
+
+const LOG_TARGET: &str = "tari::universe::node_snapshot";
+/// Directory the node keeps its chain database in, relative to the network dir
+const NODE_DB_DIR: &str = "data";
+/// Hex encoded ed25519 key the snapshot checksums are signed with, set by the release CI.
+/// Builds without it have snapshot bootstrapping disabled.
+const SNAPSHOT_SIGNING_PUBLIC_KEY: Option<&str> = option_env!("NODE_SNAPSHOT_SIGNING_PUBLIC_KEY");
+
+fn snapshot_signing_public_key() -> Option<&'static str> {
+    // CI passes an empty string when the variable isn't configured
+    SNAPSHOT_SIGNING_PUBLIC_KEY.filter(|public_key| !public_key.is_empty())
+}
+
+/// Whether this build can bootstrap a local node from a snapshot at all
+pub fn is_snapshot_bootstrap_enabled() -> bool {
+    snapshot_signing_public_key().is_some()
+}
+
+/// Snapshots are only fetched over HTTPS, the signature covers the content but not which snapshot is served
+pub fn validate_snapshot_mirror(mirror: &str) -> Result<(), anyhow::Error> {
+    let url = reqwest::Url::parse(mirror)?;
+    if url.scheme() != "https" || url.host_str().is_none() {
+        return Err(anyhow!("Snapshot mirror {} should be an https URL", mirror));
+    }
+    Ok(())
+}
+
+/// Downloads a chain snapshot from `mirror` and extracts it into `network_dir`, so a fresh local node
+/// starts from a recent height instead of syncing from genesis.
+///
+/// Expected mirror layout: `<mirror>/<network>/<archival|pruned>.tar.gz`, next to a `.sha256` file
+/// in the `sha256sum` format and a `.sha256.sig` file holding the base64 encoded ed25519 signature
+/// of that checksum file. Nodes that already have a database are left untouched.
+pub async fn bootstrap_from_snapshot(
+    mirror: &str,
+    network_dir: &Path,
+    is_pruned: bool,
+    progress_channel: Option<ChanneledStepUpdate>,
+) -> Result<(), anyhow::Error> {
+    if network_dir.join(NODE_DB_DIR).exists() {
+        info!(target: LOG_TARGET, "Node database already exists, skipping snapshot download");
+        return Ok(());
+    }
+    let public_key = snapshot_signing_public_key()
+        .ok_or_else(|| anyhow!("Snapshot bootstrapping is disabled in this build"))?;
+
+    let snapshot_name = format!("{}.tar.gz", if is_pruned { "pruned" } else { "archival" });
+    let snapshot_url = format!(
+        "{}/{}/{}",
+        mirror.trim_end_matches('/'),
+        Network::get_current().to_string().to_lowercase(),
+        snapshot_name
+    );
+    let in_progress_dir = network_dir.join("snapshot_in_progress");
+    if in_progress_dir.exists() {
+        fs::remove_dir_all(&in_progress_dir).await?;
+    }
+    fs::create_dir_all(&in_progress_dir).await?;
+
+    let result = download_and_extract(
+        public_key,
+        &snapshot_url,
+        &snapshot_name,
+        network_dir,
+        &in_progress_dir,
+        progress_channel,
+    )
+    .await;
+    let _unused = fs::remove_dir_all(&in_progress_dir).await.inspect_err(|e| {
+        warn!(target: LOG_TARGET, "Failed to remove snapshot download dir: {}", e);
+    });
+    result
+}
+
+async fn download_and_extract(
+    public_key: &str,
+    snapshot_url: &str,
+    snapshot_name: &str,
+    network_dir: &Path,
+    in_progress_dir: &Path,
+    progress_channel: Option<ChanneledStepUpdate>,
+) -> Result<(), anyhow::Error> {
+    let checksum_path = in_progress_dir.join(format!("{snapshot_name}.sha256"));
+    RequestClient::current()
+        .download_file_with_retries(
+            &format!("{snapshot_url}.sha256"),
+            &checksum_path,
+            false,
+            None,
+        )
+        .await?;
+    let signature_path = in_progress_dir.join(format!("{snapshot_name}.sha256.sig"));
+    RequestClient::current()
+        .download_file_with_retries(
+            &format!("{snapshot_url}.sha256.sig"),
+            &signature_path,
+            false,
+            None,
+        )
+        .await?;
+    let checksums = fs::read_to_string(&checksum_path).await?;
+    verify_signature(
+        public_key,
+        checksums.as_bytes(),
+        &fs::read_to_string(&signature_path).await?,
+    )
+    .map_err(|e| {
+        anyhow!(
+            "Untrusted checksums for node snapshot {}: {}",
+            snapshot_url,
+            e
+        )
+    })?;
+    let expected_checksum = expected_checksum(&checksums, snapshot_name).ok_or_else(|| {
+        anyhow!(
+            "No checksum for {} in {}",
+            snapshot_name,
+            checksum_path.display()
+        )
+    })?;
+
+    info!(target: LOG_TARGET, "Downloading node snapshot from {}", snapshot_url);
+    let snapshot_path = in_progress_dir.join(snapshot_name);
+    RequestClient::current()
+        .download_file_with_retries(
+            snapshot_url,
+            &snapshot_path,
+            false,
+            forward_progress(progress_channel).await,
+        )
+        .await?;
+
+    if !validate_checksum(snapshot_path.clone(), expected_checksum).await? {
+        return Err(anyhow!(
+            "Checksum mismatch for node snapshot {}",
+            snapshot_url
+        ));
+    }
+
+    // Extracted next to the download first, so a failed extraction never leaves a partial database
+    let extract_dir = in_progress_dir.join("extracted");
+    extract(&snapshot_path, &extract_dir).await?;
+    let extracted_db_dir = extract_dir.join(NODE_DB_DIR);
+    if !extracted_db_dir.exists() {
+        return Err(anyhow!("Node snapshot has no {} directory", NODE_DB_DIR));
+    }
+    fs::rename(extracted_db_dir, network_dir.join(NODE_DB_DIR)).await?;
+    info!(target: LOG_TARGET, "Node snapshot extracted into {}", network_dir.display());
+
+    Ok(())
+}
+
+/// Checks `message` against a base64 encoded ed25519 `signature` made by the hex encoded `public_key`
+fn verify_signature(
+    public_key: &str,
+    message: &[u8],
+    signature: &str,
+) -> Result<(), anyhow::Error> {
+    let public_key = hex::decode(public_key.trim())?;
+    let signature = BASE64_STANDARD.decode(signature.trim())?;
+    UnparsedPublicKey::new(&ED25519, public_key)
+        .verify(message, &signature)
+        .map_err(|_| anyhow!("Signature does not match the pinned key"))
+}
+
+/// Finds the hash for `file_name` in a `sha256sum` formatted file
+fn expected_checksum(checksums: &str, file_name: &str) -> Option<String> {
+    checksums.lines().find_map(|line| {
+        let (hash, name) = line.trim().split_once(char::is_whitespace)?;
+        (name.trim().trim_start_matches('*') == file_name).then(|| hash.to_lowercase())
+    })
+}
+
+async fn forward_progress(
+    progress_channel: Option<ChanneledStepUpdate>,
+) -> Option<watch::Sender<f64>> {
+    let step_update_channel = progress_channel?;
+    let (sender, mut receiver) = watch::channel(0.0);
+    TasksTrackers::current()
+        .node_phase
+        .get_task_tracker()
+        .await
+        .spawn(async move {
+            // Ends once the download drops the sender
+            while receiver.changed().await.is_ok() {
+                let percentage = *receiver.borrow();
+                let mut params = HashMap::new();
+                params.insert("progress".to_string(), percentage.to_string());
+                step_update_channel
+                    .send_update(params, percentage / 100.0)
+                    .await;
+            }
+        });
+    Some(sender)
+}
+
+#[cfg(test)]
+mod tests {
+    use ring::rand::SystemRandom;
+    use ring::signature::{Ed25519KeyPair, KeyPair};
+
+    use super::*;
+
+    #[test]
+    fn verify_signature_accepts_only_the_pinned_key() {
+        let rng = SystemRandom::new();
+        let key_pair = |rng: &SystemRandom| {
+            Ed25519KeyPair::from_pkcs8(
+                Ed25519KeyPair::generate_pkcs8(rng)
+                    .expect("key generation failed")
+                    .as_ref(),
+            )
+            .expect("invalid pkcs8")
+        };
+        let signer = key_pair(&rng);
+        let other = key_pair(&rng);
+        let checksums = b"abc123  archival.tar.gz\n";
+        let signature = BASE64_STANDARD.encode(signer.sign(checksums));
+        let public_key = hex::encode(signer.public_key());
+
+        assert!(verify_signature(&public_key, checksums, &signature).is_ok());
+        assert!(verify_signature(&public_key, b"def456  archival.tar.gz\n", &signature).is_err());
+        assert!(verify_signature(&hex::encode(other.public_key()), checksums, &signature).is_err());
+    }
+
+    #[test]
+    fn expected_checksum_matches_file_name() {
+        let checksums = "ABC123  archival.tar.gz\ndef456 *pruned.tar.gz\n";
+        assert_eq!(
+            expected_checksum(checksums, "archival.tar.gz"),
+            Some("abc123".to_string())
+        );
+        assert_eq!(
+            expected_checksum(checksums, "pruned.tar.gz"),
+            Some("def456".to_string())
+        );
+        assert_eq!(expected_checksum(checksums, "other.tar.gz"), None);
+    }
+
+    #[test]
+    fn snapshot_mirror_must_be_https() {
+        assert!(validate_snapshot_mirror("https://snapshots.example.com/tari").is_ok());
+        assert!(validate_snapshot_mirror("http://snapshots.example.com").is_err());
+        assert!(validate_snapshot_mirror("snapshots.example.com").is_err());
+    }
+}
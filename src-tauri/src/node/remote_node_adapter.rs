@@ -20,9 +20,12 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
+use futures::future::join_all;
+use log::{info, warn};
 use tari_crypto::ristretto::RistrettoPublicKey;
 use tari_shutdown::Shutdown;
 use tokio::sync::watch;
+use tokio::time::{timeout, Instant};
 use tokio_util::task::TaskTracker;
 use tonic::async_trait;
 
@@ -35,17 +38,25 @@ use crate::{
     process_adapter::{ProcessAdapter, ProcessInstanceTrait},
     BaseNodeStatus,
 };
-use anyhow::Error;
+use anyhow::{anyhow, Error};
 use std::{
     path::PathBuf,
-    sync::{atomic::AtomicU64, Arc},
+    sync::{atomic::AtomicU64, Arc, Mutex},
+    time::Duration,
 };
 
 const LOG_TARGET: &str = "tari::universe::remote_node_adapter";
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+/// Synced nodes within this many blocks of the highest one are considered up to date
+const MAX_BLOCKS_BEHIND: u64 = 2;
 
 #[derive(Clone)]
 pub(crate) struct RemoteNodeAdapter {
-    pub grpc_address: Option<(String, u16)>,
+    /// Candidates in the configured order, primary first
+    grpc_addresses: Vec<(String, u16)>,
+    /// Shared with every clone of the adapter, so a failover is picked up by the status monitor and
+    /// the node manager alike
+    selected_grpc_address: Arc<Mutex<Option<(String, u16)>>>,
     pub(crate) use_tor: bool,
     ab_group: ABTestSelector,
     status_broadcast: watch::Sender<BaseNodeStatus>,
@@ -54,7 +65,8 @@ pub(crate) struct RemoteNodeAdapter {
 impl RemoteNodeAdapter {
     pub fn new(status_broadcast: watch::Sender<BaseNodeStatus>) -> Self {
         Self {
-            grpc_address: None,
+            grpc_addresses: Vec::new(),
+            selected_grpc_address: Arc::new(Mutex::new(None)),
             status_broadcast,
             use_tor: false,
             ab_group: ABTestSelector::GroupA,
@@ -62,41 +74,147 @@ impl RemoteNodeAdapter {
     }
 
     pub fn get_grpc_address(&self) -> Option<(String, u16)> {
-        self.grpc_address.clone()
+        self.selected_grpc_address.lock().ok()?.clone()
+    }
+
+    fn select_grpc_address(&self, grpc_address: (String, u16)) {
+        if let Ok(mut selected_grpc_address) = self.selected_grpc_address.lock() {
+            *selected_grpc_address = Some(grpc_address);
+        }
     }
 
     pub fn get_service(&self) -> Option<NodeAdapterService> {
-        if let Some(grpc_address) = self.get_grpc_address() {
-            let address = if grpc_address.0.starts_with("http") {
-                format!("{}:{}", grpc_address.0, grpc_address.1)
-            } else {
-                format!("http://{}:{}", grpc_address.0, grpc_address.1)
-            };
-            Some(NodeAdapterService::new(address, 1))
-        } else {
-            None
+        self.get_grpc_address()
+            .map(|grpc_address| NodeAdapterService::new(connection_address(&grpc_address), 1))
+    }
+
+    pub fn set_grpc_addresses(&mut self, grpc_addresses: Vec<String>) -> Result<(), anyhow::Error> {
+        self.grpc_addresses = grpc_addresses
+            .iter()
+            .filter_map(|grpc_address| {
+                parse_grpc_address(grpc_address)
+                    .inspect_err(|e| warn!(target: LOG_TARGET, "Skipping remote node {}: {}", grpc_address, e))
+                    .ok()
+            })
+            .collect();
+        let primary = self
+            .grpc_addresses
+            .first()
+            .cloned()
+            .ok_or_else(|| anyhow!("No valid remote node address in {:?}", grpc_addresses))?;
+        self.select_grpc_address(primary);
+        Ok(())
+    }
+
+    /// Probes the candidates and switches to the best responding one, keeping the current selection
+    /// when none of them respond
+    pub async fn select_best_grpc_address(&self) {
+        self.select_best_excluding(None).await;
+    }
+
+    /// Moves away from the selected remote node, used once it was reported unhealthy.
+    /// Returns whether another node was selected.
+    pub async fn fail_over(&self) -> bool {
+        if self.grpc_addresses.len() < 2 {
+            return false;
         }
+        let current = self.get_grpc_address();
+        self.select_best_excluding(current.as_ref()).await
     }
 
-    // Expected format currently: https://grpc.<network>.tari.com:443
-    pub fn set_grpc_address(&mut self, grpc_address: String) -> Result<(), anyhow::Error> {
-        let has_scheme = grpc_address.starts_with("http");
-        let is_https = grpc_address.starts_with("https");
-        if !has_scheme {
-            let parts = grpc_address.split(':').collect::<Vec<&str>>();
-            let port = parts[1].parse::<u16>()?;
-            let scheme = if port == 443 { "https://" } else { "http://" };
-            self.grpc_address = Some((
-                format!("{}{}", scheme, parts[0]),
-                if is_https { 443 } else { 80 },
-            ));
-            return Ok(());
+    async fn select_best_excluding(&self, excluded: Option<&(String, u16)>) -> bool {
+        let probes: Vec<RemoteNodeProbe> = join_all(
+            self.grpc_addresses
+                .iter()
+                .filter(|grpc_address| Some(*grpc_address) != excluded)
+                .map(|grpc_address| probe(grpc_address.clone())),
+        )
+        .await
+        .into_iter()
+        .flatten()
+        .collect();
+
+        match best_probe(&probes) {
+            Some(best) => {
+                info!(target: LOG_TARGET, "Using remote node {} | latency: {:?} | height: {} | synced: {}", connection_address(&best.grpc_address), best.latency, best.block_height, best.is_synced);
+                let is_changed = self.get_grpc_address().as_ref() != Some(&best.grpc_address);
+                self.select_grpc_address(best.grpc_address.clone());
+                is_changed
+            }
+            None => {
+                warn!(target: LOG_TARGET, "No remote node responded, keeping {:?}", self.get_grpc_address());
+                false
+            }
         }
+    }
+}
 
-        let parts = grpc_address.split(':').collect::<Vec<&str>>();
-        self.grpc_address = Some((format!("{}:{}", parts[0], parts[1]), parts[2].parse()?));
-        Ok(())
+#[derive(Debug, Clone)]
+struct RemoteNodeProbe {
+    grpc_address: (String, u16),
+    latency: Duration,
+    block_height: u64,
+    is_synced: bool,
+}
+
+async fn probe(grpc_address: (String, u16)) -> Option<RemoteNodeProbe> {
+    let service = NodeAdapterService::new(connection_address(&grpc_address), 1);
+    let started = Instant::now();
+    match timeout(PROBE_TIMEOUT, service.get_network_state()).await {
+        Ok(Ok(status)) => Some(RemoteNodeProbe {
+            grpc_address,
+            latency: started.elapsed(),
+            block_height: status.block_height,
+            is_synced: status.is_synced,
+        }),
+        Ok(Err(e)) => {
+            warn!(target: LOG_TARGET, "Remote node {} failed to respond: {}", connection_address(&grpc_address), e);
+            None
+        }
+        Err(_) => {
+            warn!(target: LOG_TARGET, "Remote node {} timed out", connection_address(&grpc_address));
+            None
+        }
+    }
+}
+
+/// The fastest synced node that isn't behind the others, or the highest one if none is synced
+fn best_probe(probes: &[RemoteNodeProbe]) -> Option<&RemoteNodeProbe> {
+    let best_height = probes
+        .iter()
+        .filter(|probe| probe.is_synced)
+        .map(|probe| probe.block_height)
+        .max();
+    let Some(best_height) = best_height else {
+        return probes.iter().max_by_key(|probe| probe.block_height);
+    };
+    probes
+        .iter()
+        .filter(|probe| {
+            probe.is_synced && probe.block_height.saturating_add(MAX_BLOCKS_BEHIND) >= best_height
+        })
+        .min_by_key(|probe| probe.latency)
+}
+
+fn connection_address(grpc_address: &(String, u16)) -> String {
+    if grpc_address.0.starts_with("http") {
+        format!("{}:{}", grpc_address.0, grpc_address.1)
+    } else {
+        format!("http://{}:{}", grpc_address.0, grpc_address.1)
+    }
+}
+
+// Expected format currently: https://grpc.<network>.tari.com:443
+fn parse_grpc_address(grpc_address: &str) -> Result<(String, u16), anyhow::Error> {
+    let (host, port) = grpc_address
+        .rsplit_once(':')
+        .ok_or_else(|| anyhow!("Missing port"))?;
+    let port = port.parse::<u16>()?;
+    if grpc_address.starts_with("http") {
+        return Ok((host.to_string(), port));
     }
+    let scheme = if port == 443 { "https://" } else { "http://" };
+    Ok((format!("{scheme}{host}"), port))
 }
 
 #[async_trait]
@@ -105,8 +223,8 @@ impl NodeAdapter for RemoteNodeAdapter {
         self.get_grpc_address()
     }
 
-    fn set_grpc_address(&mut self, grpc_address: String) -> Result<(), anyhow::Error> {
-        self.set_grpc_address(grpc_address)
+    fn set_grpc_addresses(&mut self, grpc_addresses: Vec<String>) -> Result<(), anyhow::Error> {
+        self.set_grpc_addresses(grpc_addresses)
     }
 
     fn get_service(&self) -> Option<NodeAdapterService> {
@@ -170,24 +288,20 @@ impl ProcessAdapter for RemoteNodeAdapter {
         _is_first_start: bool,
     ) -> Result<(Self::ProcessInstance, Self::StatusMonitor), anyhow::Error> {
         let inner_shutdown = Shutdown::new();
-        let grpc_address = self
-            .get_grpc_address()
+        let node_service = self
+            .get_service()
             .ok_or_else(|| anyhow::anyhow!("GRPC address not set"))?;
-        let address = if grpc_address.0.starts_with("http") {
-            format!("{}:{}", grpc_address.0, grpc_address.1)
-        } else {
-            format!("http://{}:{}", grpc_address.0, grpc_address.1)
-        };
         Ok((
             NullProcessInstance {
                 shutdown: inner_shutdown,
             },
             NodeStatusMonitor::new(
                 NodeType::Remote,
-                NodeAdapterService::new(address, 1),
+                node_service,
                 self.status_broadcast.clone(),
                 Arc::new(AtomicU64::new(0)),
                 None, // Used only by Local Node
+                Some(self.clone()),
             ),
         ))
     }
@@ -237,3 +351,53 @@ impl ProcessInstanceTrait for NullProcessInstance {
             .map(|exit_code| (exit_code, Vec::new(), Vec::new()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn node(host: &str, latency_ms: u64, block_height: u64, is_synced: bool) -> RemoteNodeProbe {
+        RemoteNodeProbe {
+            grpc_address: (host.to_string(), 443),
+            latency: Duration::from_millis(latency_ms),
+            block_height,
+            is_synced,
+        }
+    }
+
+    #[test]
+    fn best_probe_prefers_fastest_up_to_date_node() {
+        let probes = vec![
+            node("lagging", 10, 90, true),
+            node("slow", 300, 100, true),
+            node("fast", 50, 99, true),
+            node("syncing", 5, 100, false),
+        ];
+        assert_eq!(best_probe(&probes).unwrap().grpc_address.0, "fast");
+    }
+
+    #[test]
+    fn best_probe_falls_back_to_highest_unsynced_node() {
+        let probes = vec![node("a", 10, 50, false), node("b", 100, 80, false)];
+        assert_eq!(best_probe(&probes).unwrap().grpc_address.0, "b");
+        assert!(best_probe(&[]).is_none());
+    }
+
+    #[test]
+    fn parse_grpc_address_adds_missing_scheme() {
+        assert_eq!(
+            parse_grpc_address("https://grpc.esmeralda.tari.com:443").unwrap(),
+            ("https://grpc.esmeralda.tari.com".to_string(), 443)
+        );
+        assert_eq!(
+            parse_grpc_address("node.example.com:443").unwrap(),
+            ("https://node.example.com".to_string(), 443)
+        );
+        assert_eq!(
+            parse_grpc_address("10.0.0.2:18142").unwrap(),
+            ("http://10.0.0.2".to_string(), 18142)
+        );
+        assert!(parse_grpc_address("node.example.com").is_err());
+    }
+}
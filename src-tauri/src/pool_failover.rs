@@ -0,0 +1,307 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::time::{Duration, Instant};
+
+use log::{info, warn};
+use tauri::{AppHandle, Manager};
+use tokio::net::TcpStream;
+use tokio::select;
+use tokio::time::{timeout, MissedTickBehavior};
+
+use crate::configs::config_mining::ConfigMining;
+use crate::configs::trait_config::ConfigImpl;
+use crate::mining_benchmark::MiningBenchmark;
+use crate::tasks_tracker::TasksTrackers;
+use crate::utils::mining_control_utils;
+use crate::utils::pool_url_utils::PoolUrl;
+use crate::UniverseAppState;
+
+const LOG_TARGET: &str = "tari::universe::pool_failover";
+const FAILOVER_CHECK_INTERVAL: Duration = Duration::from_secs(20);
+/// Consecutive unhealthy checks before moving on to the next pool, gives a restarted miner time to connect
+const FAILED_CHECKS_BEFORE_FAILOVER: u32 = 3;
+const PRIMARY_POOL_PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+/// Consecutive successful probes of the primary pool before moving back to it
+const HEALTHY_CHECKS_BEFORE_FAILBACK: u32 = 6;
+/// Time on a failover pool before moving back to the primary pool is considered at all
+const FAILBACK_HOLD_DOWN: Duration = Duration::from_secs(10 * 60);
+
+/// Moves the miners down the configured pool list when the current pool stops working,
+/// and back to the primary pool once it has accepted connections for a while
+pub struct PoolFailover {
+    app_handle: AppHandle,
+    cpu_pool_health: PoolHealth,
+    gpu_pool_health: PoolHealth,
+}
+
+impl PoolFailover {
+    pub async fn start(app_handle: AppHandle) {
+        let mut pool_failover = Self {
+            app_handle,
+            cpu_pool_health: PoolHealth::default(),
+            gpu_pool_health: PoolHealth::default(),
+        };
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let mut interval = tokio::time::interval(FAILOVER_CHECK_INTERVAL);
+                interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+                loop {
+                    select! {
+                        _ = interval.tick() => {
+                            // The benchmark starts and stops the miners itself while it runs
+                            if MiningBenchmark::is_running() {
+                                continue;
+                            }
+                            pool_failover.check_cpu_pool().await;
+                            pool_failover.check_gpu_pool().await;
+                        }
+                        _ = shutdown_signal.wait() => {
+                            info!(target: LOG_TARGET, "Shutdown signal received, stopping pool failover");
+                            break;
+                        }
+                    }
+                }
+            });
+    }
+
+    async fn check_cpu_pool(&mut self) {
+        if !mining_control_utils::is_cpu_mining(&self.app_handle).await {
+            self.cpu_pool_health = PoolHealth::default();
+            return;
+        }
+        let state = self.app_handle.state::<UniverseAppState>();
+        let (pool_urls, active_pool_index) = {
+            let cpu_miner_config = state.cpu_miner_config.read().await;
+            (
+                cpu_miner_config
+                    .pools
+                    .iter()
+                    .map(|pool| pool.url.clone())
+                    .collect::<Vec<_>>(),
+                cpu_miner_config.active_pool_index,
+            )
+        };
+        let is_connected = state
+            .cpu_miner_status_watch_rx
+            .borrow()
+            .connection
+            .is_connected;
+
+        let Some(pool_index) = next_pool_index(
+            &mut self.cpu_pool_health,
+            is_connected,
+            &pool_urls,
+            active_pool_index,
+        )
+        .await
+        else {
+            return;
+        };
+
+        warn!(target: LOG_TARGET, "Switching CPU mining to pool {}", pool_urls[pool_index]);
+        state.cpu_miner_config.write().await.select_pool(pool_index);
+        mining_control_utils::reconfigure_cpu_mining(&self.app_handle).await;
+    }
+
+    async fn check_gpu_pool(&mut self) {
+        if !mining_control_utils::is_gpu_mining(&self.app_handle).await {
+            self.gpu_pool_health = PoolHealth::default();
+            return;
+        }
+        let state = self.app_handle.state::<UniverseAppState>();
+        let pool_urls = ConfigMining::content().await.gpu_pool_urls();
+        let active_pool_index = state.gpu_miner.read().await.active_pool_index();
+        // glytex keeps running without a node source, it just stops producing hashes
+        let is_mining = state.gpu_latest_status.borrow().hash_rate > 0.0;
+
+        let Some(pool_index) = next_pool_index(
+            &mut self.gpu_pool_health,
+            is_mining,
+            &pool_urls,
+            active_pool_index,
+        )
+        .await
+        else {
+            return;
+        };
+
+        warn!(target: LOG_TARGET, "Switching GPU mining to pool {}", pool_urls[pool_index]);
+        mining_control_utils::stop_gpu_mining(&self.app_handle).await;
+        state.gpu_miner.write().await.select_pool(pool_index);
+        mining_control_utils::start_gpu_mining(&self.app_handle).await;
+    }
+}
+
+/// Returns the pool to switch to, if any
+async fn next_pool_index(
+    pool_health: &mut PoolHealth,
+    is_healthy: bool,
+    pool_urls: &[String],
+    active_pool_index: usize,
+) -> Option<usize> {
+    if pool_urls.len() < 2 {
+        return None;
+    }
+    let is_primary_reachable = match pool_urls.first() {
+        Some(primary_pool) if is_healthy && active_pool_index != 0 => {
+            is_reachable(primary_pool).await
+        }
+        _ => false,
+    };
+    pool_health.next_pool_index(
+        is_healthy,
+        is_primary_reachable,
+        pool_urls.len(),
+        active_pool_index,
+        Instant::now(),
+    )
+}
+
+/// Check counters for one miner. A TCP connect says little about whether the primary pool hands out work,
+/// so moving back to it takes a run of successful probes and a minimum time on the failover pool.
+#[derive(Default)]
+struct PoolHealth {
+    failed_checks: u32,
+    primary_healthy_checks: u32,
+    switched_at: Option<Instant>,
+}
+
+impl PoolHealth {
+    fn next_pool_index(
+        &mut self,
+        is_healthy: bool,
+        is_primary_reachable: bool,
+        pool_count: usize,
+        active_pool_index: usize,
+        now: Instant,
+    ) -> Option<usize> {
+        if !is_healthy {
+            self.primary_healthy_checks = 0;
+            self.failed_checks += 1;
+            if self.failed_checks < FAILED_CHECKS_BEFORE_FAILOVER {
+                return None;
+            }
+            return Some(self.switch_to((active_pool_index + 1) % pool_count, now));
+        }
+
+        self.failed_checks = 0;
+        if active_pool_index == 0 || !is_primary_reachable {
+            self.primary_healthy_checks = 0;
+            return None;
+        }
+        self.primary_healthy_checks += 1;
+        let is_held_down = self
+            .switched_at
+            .is_some_and(|switched_at| now.duration_since(switched_at) < FAILBACK_HOLD_DOWN);
+        if self.primary_healthy_checks < HEALTHY_CHECKS_BEFORE_FAILBACK || is_held_down {
+            return None;
+        }
+        info!(target: LOG_TARGET, "Primary pool has been reachable for {} checks", self.primary_healthy_checks);
+        Some(self.switch_to(0, now))
+    }
+
+    fn switch_to(&mut self, pool_index: usize, now: Instant) -> usize {
+        self.failed_checks = 0;
+        self.primary_healthy_checks = 0;
+        self.switched_at = Some(now);
+        pool_index
+    }
+}
+
+async fn is_reachable(pool_url: &str) -> bool {
+    let Ok(pool_url) = PoolUrl::parse(pool_url) else {
+        return false;
+    };
+    matches!(
+        timeout(
+            PRIMARY_POOL_PROBE_TIMEOUT,
+            TcpStream::connect(pool_url.to_string())
+        )
+        .await,
+        Ok(Ok(_))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fails_over_after_consecutive_failed_checks() {
+        let mut pool_health = PoolHealth::default();
+        let now = Instant::now();
+        for _ in 1..FAILED_CHECKS_BEFORE_FAILOVER {
+            assert_eq!(pool_health.next_pool_index(false, false, 3, 0, now), None);
+        }
+        assert_eq!(
+            pool_health.next_pool_index(false, false, 3, 0, now),
+            Some(1)
+        );
+        assert_eq!(pool_health.failed_checks, 0);
+    }
+
+    #[test]
+    fn fails_back_only_after_hold_down_and_healthy_checks() {
+        let mut pool_health = PoolHealth::default();
+        let switched_at = Instant::now();
+        pool_health.switch_to(1, switched_at);
+
+        // Reachable the whole time, but still within the hold-down
+        for _ in 0..HEALTHY_CHECKS_BEFORE_FAILBACK {
+            assert_eq!(
+                pool_health.next_pool_index(true, true, 3, 1, switched_at),
+                None
+            );
+        }
+        let after_hold_down = switched_at + FAILBACK_HOLD_DOWN;
+        assert_eq!(
+            pool_health.next_pool_index(true, true, 3, 1, after_hold_down),
+            Some(0)
+        );
+    }
+
+    #[test]
+    fn unreachable_probe_restarts_the_healthy_run() {
+        let mut pool_health = PoolHealth::default();
+        let now = Instant::now();
+        for _ in 1..HEALTHY_CHECKS_BEFORE_FAILBACK {
+            assert_eq!(pool_health.next_pool_index(true, true, 3, 1, now), None);
+        }
+        assert_eq!(pool_health.next_pool_index(true, false, 3, 1, now), None);
+        assert_eq!(pool_health.next_pool_index(true, true, 3, 1, now), None);
+        assert_eq!(pool_health.primary_healthy_checks, 1);
+    }
+
+    #[test]
+    fn stays_on_the_primary_pool_while_it_is_healthy() {
+        let mut pool_health = PoolHealth::default();
+        assert_eq!(
+            pool_health.next_pool_index(true, false, 3, 0, Instant::now()),
+            None
+        );
+    }
+}
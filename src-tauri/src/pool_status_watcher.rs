@@ -21,20 +21,57 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use anyhow::Error;
+use chrono::DateTime;
+use log::warn;
 use serde::{Deserialize, Serialize};
 
-#[allow(dead_code)]
 const LOG_TARGET: &str = "tari::universe::pool_status_watcher";
-#[derive(Clone, Debug, Serialize)]
+/// MiningCore reports amounts in whole coins, the rest of the app uses atomic units
+const MONERO_ATOMIC_UNITS: f64 = 1e12;
+
+#[derive(Clone, Debug, Default, Serialize)]
 pub(crate) struct PoolStatus {
     pub accepted_shares: u64,
     pub unpaid: u64,
     pub balance: u64,
     pub min_payout: u64,
+    /// Hashrate as seen by the pool, based on submitted shares
+    pub pool_hashrate: Option<f64>,
+    pub invalid_shares: Option<u64>,
+    /// Unix timestamp in seconds
+    pub last_share_time: Option<u64>,
+    pub payouts: Vec<PoolPayout>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub(crate) struct PoolPayout {
+    /// Unix timestamp in seconds
+    pub timestamp: u64,
+    pub amount: u64,
+    pub tx_hash: Option<String>,
+}
+
+/// Pool API used to read `cpu_mining_pool_status_url` or a failover pool's `status_url`
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub enum PoolApiType {
+    /// nodejs-pool API, as run by SupportXMR and most Monero pools
+    #[default]
+    SupportXmr,
+    MiningCore,
+    P2PoolObserver,
 }
 
 pub(crate) trait PoolApiAdapter: Clone {
     fn convert_api_data(&self, data: &str) -> Result<PoolStatus, Error>;
+
+    /// Some APIs serve the payout history from a separate endpoint
+    fn payouts_url(&self, _status_url: &str) -> Option<String> {
+        None
+    }
+
+    fn convert_payouts_data(&self, _data: &str) -> Result<Vec<PoolPayout>, Error> {
+        Ok(vec![])
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -53,9 +90,88 @@ impl<T: PoolApiAdapter + Send + Sync + 'static> PoolStatusWatcher<T> {
     pub async fn get_pool_status(&self) -> Result<PoolStatus, Error> {
         let response = reqwest::get(&self.url).await?;
         let data = response.text().await?;
-        let pool_status = self.adapter.convert_api_data(&data)?;
+        let mut pool_status = self.adapter.convert_api_data(&data)?;
+
+        if let Some(payouts_url) = self.adapter.payouts_url(&self.url) {
+            match self.get_payouts(&payouts_url).await {
+                Ok(payouts) => pool_status.payouts = payouts,
+                Err(e) => warn!(target: LOG_TARGET, "Error fetching pool payouts: {}", e),
+            }
+        }
         Ok(pool_status)
     }
+
+    async fn get_payouts(&self, payouts_url: &str) -> Result<Vec<PoolPayout>, Error> {
+        let data = reqwest::get(payouts_url).await?.text().await?;
+        self.adapter.convert_payouts_data(&data)
+    }
+}
+
+/// Adapter selected at runtime from `PoolApiType`
+#[derive(Clone, Debug)]
+pub(crate) enum ConfiguredPoolApiAdapter {
+    SupportXmr(SupportXmrStyleAdapter),
+    MiningCore(MiningCoreAdapter),
+    P2PoolObserver(P2PoolObserverAdapter),
+}
+
+impl From<PoolApiType> for ConfiguredPoolApiAdapter {
+    fn from(api_type: PoolApiType) -> Self {
+        match api_type {
+            PoolApiType::SupportXmr => Self::SupportXmr(SupportXmrStyleAdapter {}),
+            PoolApiType::MiningCore => Self::MiningCore(MiningCoreAdapter {}),
+            PoolApiType::P2PoolObserver => Self::P2PoolObserver(P2PoolObserverAdapter {}),
+        }
+    }
+}
+
+impl PoolApiAdapter for ConfiguredPoolApiAdapter {
+    fn convert_api_data(&self, data: &str) -> Result<PoolStatus, Error> {
+        match self {
+            Self::SupportXmr(adapter) => adapter.convert_api_data(data),
+            Self::MiningCore(adapter) => adapter.convert_api_data(data),
+            Self::P2PoolObserver(adapter) => adapter.convert_api_data(data),
+        }
+    }
+
+    fn payouts_url(&self, status_url: &str) -> Option<String> {
+        match self {
+            Self::SupportXmr(adapter) => adapter.payouts_url(status_url),
+            Self::MiningCore(adapter) => adapter.payouts_url(status_url),
+            Self::P2PoolObserver(adapter) => adapter.payouts_url(status_url),
+        }
+    }
+
+    fn convert_payouts_data(&self, data: &str) -> Result<Vec<PoolPayout>, Error> {
+        match self {
+            Self::SupportXmr(adapter) => adapter.convert_payouts_data(data),
+            Self::MiningCore(adapter) => adapter.convert_payouts_data(data),
+            Self::P2PoolObserver(adapter) => adapter.convert_payouts_data(data),
+        }
+    }
+}
+
+/// Swaps the last path segment of an API url, keeping the query string
+fn replace_last_path_segment(url: &str, from: &str, to: &str) -> Option<String> {
+    let (path, query) = url.split_once('?').unwrap_or((url, ""));
+    let base = path.trim_end_matches('/').strip_suffix(from)?;
+    if query.is_empty() {
+        Some(format!("{base}{to}"))
+    } else {
+        Some(format!("{base}{to}?{query}"))
+    }
+}
+
+fn coins_to_atomic_units(amount: f64) -> u64 {
+    #[allow(clippy::cast_possible_truncation)]
+    let atomic_units = (amount.max(0.0) * MONERO_ATOMIC_UNITS).round() as u64;
+    atomic_units
+}
+
+fn parse_timestamp(value: &str) -> Option<u64> {
+    DateTime::parse_from_rfc3339(value)
+        .ok()
+        .and_then(|date_time| u64::try_from(date_time.timestamp()).ok())
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -79,6 +195,15 @@ pub struct PoolStatusResponseBody {
     pub txn_count: u32,
 }
 
+#[derive(Deserialize, Debug)]
+struct SupportXmrPaymentResponse {
+    /// Unix timestamp in milliseconds
+    ts: u64,
+    amount: u64,
+    #[serde(rename = "txnHash")]
+    txn_hash: Option<String>,
+}
+
 #[derive(Clone, Debug)]
 pub struct SupportXmrStyleAdapter {}
 
@@ -90,7 +215,349 @@ impl PoolApiAdapter for SupportXmrStyleAdapter {
             unpaid: response.amt_due,
             balance: response.amt_paid + response.amt_due,
             min_payout: 0,
+            pool_hashrate: Some(response.hash as f64),
+            invalid_shares: Some(response.invalid_shares),
+            last_share_time: Some(response.last_hash).filter(|last_hash| *last_hash > 0),
+            payouts: vec![],
         };
         Ok(pool_status)
     }
+
+    /// `.../miner/{address}/stats` has its payments at `.../miner/{address}/payments`
+    fn payouts_url(&self, status_url: &str) -> Option<String> {
+        replace_last_path_segment(status_url, "stats", "payments")
+    }
+
+    fn convert_payouts_data(&self, data: &str) -> Result<Vec<PoolPayout>, Error> {
+        let response: Vec<SupportXmrPaymentResponse> = serde_json::from_str(data)?;
+        Ok(response
+            .into_iter()
+            .map(|payment| PoolPayout {
+                timestamp: payment.ts / 1000,
+                amount: payment.amount,
+                tx_hash: payment.txn_hash,
+            })
+            .collect())
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct MiningCoreMinerStatsResponse {
+    pending_shares: f64,
+    pending_balance: f64,
+    total_paid: f64,
+    performance: Option<MiningCoreWorkersPerformance>,
+}
+
+#[derive(Deserialize, Debug)]
+struct MiningCoreWorkersPerformance {
+    created: String,
+    workers: std::collections::HashMap<String, MiningCoreWorkerPerformance>,
+}
+
+#[derive(Deserialize, Debug)]
+struct MiningCoreWorkerPerformance {
+    hashrate: f64,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct MiningCorePaymentResponse {
+    amount: f64,
+    transaction_confirmation_data: Option<String>,
+    created: String,
+}
+
+/// MiningCore `/api/pools/{pool_id}/miners/{address}` endpoint
+#[derive(Clone, Debug)]
+pub struct MiningCoreAdapter {}
+
+impl PoolApiAdapter for MiningCoreAdapter {
+    fn convert_api_data(&self, data: &str) -> Result<PoolStatus, Error> {
+        let response: MiningCoreMinerStatsResponse = serde_json::from_str(data)?;
+        let pending_balance = coins_to_atomic_units(response.pending_balance);
+        // The performance sample is taken from submitted shares, so it is the closest to a last share time
+        let (pool_hashrate, last_share_time) = match response.performance {
+            Some(performance) => (
+                Some(
+                    performance
+                        .workers
+                        .values()
+                        .map(|worker| worker.hashrate)
+                        .sum(),
+                ),
+                parse_timestamp(&performance.created),
+            ),
+            None => (None, None),
+        };
+
+        #[allow(clippy::cast_possible_truncation)]
+        let accepted_shares = response.pending_shares.max(0.0).round() as u64;
+        Ok(PoolStatus {
+            accepted_shares,
+            unpaid: pending_balance,
+            balance: coins_to_atomic_units(response.total_paid) + pending_balance,
+            min_payout: 0,
+            pool_hashrate,
+            invalid_shares: None,
+            last_share_time,
+            payouts: vec![],
+        })
+    }
+
+    fn payouts_url(&self, status_url: &str) -> Option<String> {
+        let (path, query) = status_url.split_once('?').unwrap_or((status_url, ""));
+        let payouts_path = format!("{}/payments", path.trim_end_matches('/'));
+        if query.is_empty() {
+            Some(payouts_path)
+        } else {
+            Some(format!("{payouts_path}?{query}"))
+        }
+    }
+
+    fn convert_payouts_data(&self, data: &str) -> Result<Vec<PoolPayout>, Error> {
+        let response: Vec<MiningCorePaymentResponse> = serde_json::from_str(data)?;
+        Ok(response
+            .into_iter()
+            .map(|payment| PoolPayout {
+                timestamp: parse_timestamp(&payment.created).unwrap_or_default(),
+                amount: coins_to_atomic_units(payment.amount),
+                tx_hash: payment.transaction_confirmation_data,
+            })
+            .collect())
+    }
+}
+
+#[derive(Deserialize, Debug)]
+struct P2PoolObserverMinerInfoResponse {
+    shares: Vec<P2PoolObserverShareCount>,
+    last_share_timestamp: u64,
+}
+
+#[derive(Deserialize, Debug)]
+struct P2PoolObserverShareCount {
+    shares: u64,
+    uncles: u64,
+}
+
+#[derive(Deserialize, Debug)]
+struct P2PoolObserverPayoutResponse {
+    timestamp: u64,
+    coinbase_reward: u64,
+    coinbase_id: Option<String>,
+}
+
+/// P2Pool observer `/api/miner_info/{address}` endpoint.
+/// P2Pool pays out directly in the coinbase, so there is never an unpaid balance.
+#[derive(Clone, Debug)]
+pub struct P2PoolObserverAdapter {}
+
+impl PoolApiAdapter for P2PoolObserverAdapter {
+    fn convert_api_data(&self, data: &str) -> Result<PoolStatus, Error> {
+        let response: P2PoolObserverMinerInfoResponse = serde_json::from_str(data)?;
+        Ok(PoolStatus {
+            accepted_shares: response
+                .shares
+                .iter()
+                .map(|count| count.shares + count.uncles)
+                .sum(),
+            last_share_time: Some(response.last_share_timestamp).filter(|timestamp| *timestamp > 0),
+            ..PoolStatus::default()
+        })
+    }
+
+    fn payouts_url(&self, status_url: &str) -> Option<String> {
+        let (path, query) = status_url.split_once('?').unwrap_or((status_url, ""));
+        let (base, address) = path.trim_end_matches('/').rsplit_once('/')?;
+        let base = base.strip_suffix("miner_info")?;
+        if query.is_empty() {
+            Some(format!("{base}payouts/{address}"))
+        } else {
+            Some(format!("{base}payouts/{address}?{query}"))
+        }
+    }
+
+    fn convert_payouts_data(&self, data: &str) -> Result<Vec<PoolPayout>, Error> {
+        let response: Vec<P2PoolObserverPayoutResponse> = serde_json::from_str(data)?;
+        Ok(response
+            .into_iter()
+            .map(|payout| PoolPayout {
+                timestamp: payout.timestamp,
+                amount: payout.coinbase_reward,
+                tx_hash: payout.coinbase_id,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn converts_coins_to_atomic_units() {
+        assert_eq!(coins_to_atomic_units(1.5), 1_500_000_000_000);
+        assert_eq!(coins_to_atomic_units(0.1 + 0.2), 300_000_000_000);
+        assert_eq!(coins_to_atomic_units(0.000_000_000_000_4), 0);
+        assert_eq!(coins_to_atomic_units(0.000_000_000_000_6), 1);
+        assert_eq!(coins_to_atomic_units(-2.0), 0);
+    }
+
+    #[test]
+    fn parses_support_xmr_stats() {
+        let data = r#"{
+            "hash": 1520, "identifier": "global", "lastHash": 1719500000, "totalHashes": 900000,
+            "validShares": 42, "invalidShares": 3, "expiry": 0, "amtPaid": 5000, "amtDue": 1200,
+            "txnCount": 2
+        }"#;
+        let status = SupportXmrStyleAdapter {}.convert_api_data(data).unwrap();
+        assert_eq!(status.accepted_shares, 42);
+        assert_eq!(status.invalid_shares, Some(3));
+        assert_eq!(status.unpaid, 1200);
+        assert_eq!(status.balance, 6200);
+        assert_eq!(status.pool_hashrate, Some(1520.0));
+        assert_eq!(status.last_share_time, Some(1_719_500_000));
+    }
+
+    #[test]
+    fn rejects_incomplete_support_xmr_stats() {
+        let never_shared = r#"{
+            "hash": 0, "identifier": "global", "lastHash": 0, "totalHashes": 0,
+            "validShares": 0, "invalidShares": 0, "expiry": 0, "amtPaid": 0, "amtDue": 0,
+            "txnCount": 0
+        }"#;
+        let status = SupportXmrStyleAdapter {}
+            .convert_api_data(never_shared)
+            .unwrap();
+        assert_eq!(status.last_share_time, None);
+
+        let missing_fields = r#"{ "hash": 1520, "validShares": 42 }"#;
+        assert!(SupportXmrStyleAdapter {}
+            .convert_api_data(missing_fields)
+            .is_err());
+        let negative_balance = never_shared.replace(r#""amtDue": 0"#, r#""amtDue": -5"#);
+        assert!(SupportXmrStyleAdapter {}
+            .convert_api_data(&negative_balance)
+            .is_err());
+    }
+
+    #[test]
+    fn parses_support_xmr_payments() {
+        let adapter = SupportXmrStyleAdapter {};
+        assert_eq!(
+            adapter.payouts_url("https://pool.example/api/miner/ADDR/stats?coin=xmr"),
+            Some("https://pool.example/api/miner/ADDR/payments?coin=xmr".to_string())
+        );
+        let data = r#"[
+            { "ts": 1719500000123, "amount": 3000000000, "txnHash": "abcd" },
+            { "ts": 1719400000000, "amount": 2000000000 }
+        ]"#;
+        let payouts = adapter.convert_payouts_data(data).unwrap();
+        assert_eq!(payouts.len(), 2);
+        assert_eq!(payouts[0].timestamp, 1_719_500_000);
+        assert_eq!(payouts[0].tx_hash.as_deref(), Some("abcd"));
+        assert_eq!(payouts[1].tx_hash, None);
+    }
+
+    #[test]
+    fn parses_mining_core_miner_stats() {
+        let data = r#"{
+            "pendingShares": 12.6,
+            "pendingBalance": 0.001234567890123,
+            "totalPaid": 1.5,
+            "performance": {
+                "created": "2024-06-27T15:00:00Z",
+                "workers": { "rig-1": { "hashrate": 1000.5 }, "rig-2": { "hashrate": 500.0 } }
+            }
+        }"#;
+        let status = MiningCoreAdapter {}.convert_api_data(data).unwrap();
+        assert_eq!(status.accepted_shares, 13);
+        assert_eq!(status.unpaid, 1_234_567_890);
+        assert_eq!(status.balance, 1_500_000_000_000 + 1_234_567_890);
+        assert_eq!(status.pool_hashrate, Some(1500.5));
+        assert_eq!(status.last_share_time, Some(1_719_500_400));
+        assert_eq!(status.invalid_shares, None);
+    }
+
+    #[test]
+    fn clamps_negative_mining_core_amounts() {
+        let data = r#"{ "pendingShares": -1.0, "pendingBalance": -0.5, "totalPaid": 0.25 }"#;
+        let status = MiningCoreAdapter {}.convert_api_data(data).unwrap();
+        assert_eq!(status.accepted_shares, 0);
+        assert_eq!(status.unpaid, 0);
+        assert_eq!(status.balance, 250_000_000_000);
+        assert_eq!(status.pool_hashrate, None);
+        assert_eq!(status.last_share_time, None);
+
+        assert!(MiningCoreAdapter {}
+            .convert_api_data(r#"{ "pendingBalance": 0.5 }"#)
+            .is_err());
+    }
+
+    #[test]
+    fn parses_mining_core_payments() {
+        let adapter = MiningCoreAdapter {};
+        assert_eq!(
+            adapter.payouts_url("https://pool.example/api/pools/xmr/miners/ADDR/"),
+            Some("https://pool.example/api/pools/xmr/miners/ADDR/payments".to_string())
+        );
+        let data = r#"[
+            { "amount": 0.0123, "transactionConfirmationData": "abcd", "created": "2024-06-27T15:00:00Z" },
+            { "amount": 0.5, "created": "not a date" }
+        ]"#;
+        let payouts = adapter.convert_payouts_data(data).unwrap();
+        assert_eq!(payouts[0].amount, 12_300_000_000);
+        assert_eq!(payouts[0].timestamp, 1_719_500_400);
+        assert_eq!(payouts[1].timestamp, 0);
+        assert_eq!(payouts[1].tx_hash, None);
+    }
+
+    #[test]
+    fn parses_p2pool_observer_miner_info() {
+        let data = r#"{
+            "shares": [{ "shares": 10, "uncles": 2 }, { "shares": 3, "uncles": 0 }],
+            "last_share_timestamp": 1719500000
+        }"#;
+        let status = P2PoolObserverAdapter {}.convert_api_data(data).unwrap();
+        assert_eq!(status.accepted_shares, 15);
+        assert_eq!(status.last_share_time, Some(1_719_500_000));
+        assert_eq!(status.unpaid, 0);
+
+        let no_shares = r#"{ "shares": [], "last_share_timestamp": 0 }"#;
+        let status = P2PoolObserverAdapter {}
+            .convert_api_data(no_shares)
+            .unwrap();
+        assert_eq!(status.accepted_shares, 0);
+        assert_eq!(status.last_share_time, None);
+
+        assert!(P2PoolObserverAdapter {}
+            .convert_api_data(r#"{ "last_share_timestamp": 1719500000 }"#)
+            .is_err());
+        assert!(P2PoolObserverAdapter {}
+            .convert_api_data(
+                r#"{ "shares": [{ "shares": -1, "uncles": 0 }], "last_share_timestamp": 0 }"#
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn parses_p2pool_observer_payouts() {
+        let adapter = P2PoolObserverAdapter {};
+        assert_eq!(
+            adapter.payouts_url("https://mini.p2pool.observer/api/miner_info/ADDR"),
+            Some("https://mini.p2pool.observer/api/payouts/ADDR".to_string())
+        );
+        assert_eq!(
+            adapter.payouts_url("https://mini.p2pool.observer/api/other/ADDR"),
+            None
+        );
+        let data =
+            r#"[{ "timestamp": 1719500000, "coinbase_reward": 600000000, "coinbase_id": "abcd" }]"#;
+        let payouts = adapter.convert_payouts_data(data).unwrap();
+        assert_eq!(payouts[0].timestamp, 1_719_500_000);
+        assert_eq!(payouts[0].amount, 600_000_000);
+        assert_eq!(payouts[0].tx_hash.as_deref(), Some("abcd"));
+    }
 }
@@ -165,6 +165,9 @@ pub(crate) trait ProcessInstanceTrait: Sync + Send + 'static {
     ) -> Result<(i32, Vec<String>, Vec<String>), anyhow::Error>;
 }
 
+/// Arguments carrying a secret, their values are left out of the logs
+const SECRET_ARG_PREFIXES: [&str; 1] = ["--http-access-token="];
+
 #[derive(Clone)]
 pub(crate) struct ProcessStartupSpec {
     pub file_path: PathBuf,
@@ -175,6 +178,24 @@ pub(crate) struct ProcessStartupSpec {
     pub name: String,
 }
 
+impl ProcessStartupSpec {
+    fn loggable_args(&self) -> String {
+        self.args
+            .iter()
+            .map(|arg| {
+                match SECRET_ARG_PREFIXES
+                    .iter()
+                    .find(|prefix| arg.starts_with(*prefix))
+                {
+                    Some(prefix) => format!("{prefix}<redacted>"),
+                    None => arg.clone(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
 pub(crate) struct ProcessInstance {
     pub shutdown: Shutdown,
     pub handle: Option<JoinHandle<Result<i32, anyhow::Error>>>,
@@ -195,7 +216,7 @@ impl ProcessInstanceTrait for ProcessInstance {
             warn!(target: LOG_TARGET, "Process is already running");
             return Ok(());
         }
-        info!(target: LOG_TARGET, "Starting {} process with args: {}", self.startup_spec.name, self.startup_spec.args.join(" "));
+        info!(target: LOG_TARGET, "Starting {} process with args: {}", self.startup_spec.name, self.startup_spec.loggable_args());
         let spec = self.startup_spec.clone();
         // Reset the shutdown each time.
         self.shutdown = Shutdown::new();
@@ -270,7 +291,7 @@ impl ProcessInstanceTrait for ProcessInstance {
             warn!(target: LOG_TARGET, "Process is already running");
             return Ok((0, vec![], vec![]));
         }
-        info!(target: LOG_TARGET, "Starting {} process with args: {}", self.startup_spec.name, self.startup_spec.args.join(" "));
+        info!(target: LOG_TARGET, "Starting {} process with args: {}", self.startup_spec.name, self.startup_spec.loggable_args());
         let spec = self.startup_spec.clone();
         self.shutdown = Shutdown::new();
         let shutdown_signal = self.shutdown.to_signal();
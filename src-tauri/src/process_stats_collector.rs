@@ -163,4 +163,16 @@ impl ProcessStatsCollector {
     pub fn get_wallet_stats(&self) -> ProcessWatcherStats {
         self.wallet_rx.borrow().clone()
     }
+
+    pub fn get_all_stats(&self) -> Vec<(&'static str, ProcessWatcherStats)> {
+        vec![
+            ("cpu_miner", self.get_cpu_miner_stats()),
+            ("gpu_miner", self.get_gpu_miner_stats()),
+            ("mm_proxy", self.get_mm_proxy_stats()),
+            ("minotari_node", self.get_minotari_node_stats()),
+            ("p2pool", self.get_p2pool_stats()),
+            ("tor", self.get_tor_stats()),
+            ("wallet", self.get_wallet_stats()),
+        ]
+    }
 }
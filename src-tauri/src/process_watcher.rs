@@ -21,10 +21,14 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use crate::binaries::{Binaries, BinaryResolver};
+use crate::events::ProcessWatcherGaveUpPayload;
+use crate::events_emitter::EventsEmitter;
 use crate::process_adapter::ProcessInstanceTrait;
 use crate::process_adapter::{HealthStatus, ProcessAdapter, StatusMonitor};
 use futures_util::future::FusedFuture;
 use log::{error, info, warn};
+use rand::Rng;
+use std::collections::VecDeque;
 use std::path::{Path, PathBuf};
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
@@ -49,6 +53,46 @@ pub(crate) struct ProcessWatcherStats {
     pub num_restarts: u64,
     pub max_health_check_duration: Duration,
     pub total_health_check_duration: Duration,
+    /// Set once the restart policy was exhausted and the watcher stopped restarting the process
+    pub has_given_up: bool,
+}
+
+/// Controls how often a crashing process is restarted before the watcher gives up on it
+#[derive(Debug, Clone)]
+pub struct RestartPolicy {
+    /// Maximum number of restarts allowed within `restart_window`
+    pub max_restarts: usize,
+    pub restart_window: Duration,
+    /// Delay before the first restart, doubled for every restart within the window
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    /// Fraction of the backoff that is randomly added or subtracted, between 0.0 and 1.0
+    pub jitter: f64,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            max_restarts: 5,
+            restart_window: Duration::from_secs(10 * 60),
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            jitter: 0.2,
+        }
+    }
+}
+
+impl RestartPolicy {
+    pub fn backoff_for(&self, attempt: u32) -> Duration {
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(2_u32.saturating_pow(attempt))
+            .min(self.max_backoff)
+            .as_secs_f64();
+        let jitter =
+            backoff * self.jitter.clamp(0.0, 1.0) * rand::thread_rng().gen_range(-1.0..=1.0);
+        Duration::from_secs_f64((backoff + jitter).max(0.0))
+    }
 }
 
 pub struct ProcessWatcher<TAdapter: ProcessAdapter> {
@@ -61,6 +105,7 @@ pub struct ProcessWatcher<TAdapter: ProcessAdapter> {
     pub expected_startup_time: tokio::time::Duration,
     pub(crate) status_monitor: Option<TAdapter::StatusMonitor>,
     pub stop_on_exit_codes: Vec<i32>,
+    pub restart_policy: RestartPolicy,
     stats_broadcast: watch::Sender<ProcessWatcherStats>,
     is_first_start: Arc<AtomicBool>,
 }
@@ -76,6 +121,7 @@ impl<TAdapter: ProcessAdapter> ProcessWatcher<TAdapter> {
             expected_startup_time: tokio::time::Duration::from_secs(20),
             status_monitor: None,
             stop_on_exit_codes: Vec::new(),
+            restart_policy: RestartPolicy::default(),
             stats_broadcast,
             is_first_start: Arc::new(AtomicBool::new(true)),
         }
@@ -113,6 +159,8 @@ impl<TAdapter: ProcessAdapter> ProcessWatcher<TAdapter> {
             self.stop().await?;
         }
         info!(target: LOG_TARGET, "Starting process watcher for {}", name);
+        self.stats_broadcast
+            .send_modify(|stats| stats.has_given_up = false);
         let binary_path = BinaryResolver::current()
             .resolve_path_to_binary_files(binary)
             .await?;
@@ -143,6 +191,7 @@ impl<TAdapter: ProcessAdapter> ProcessWatcher<TAdapter> {
         let mut global_shutdown_signal: ShutdownSignal = global_shutdown_signal.clone();
         let task_tracker = task_tracker.clone();
         let stop_on_exit_codes = self.stop_on_exit_codes.clone();
+        let restart_policy = self.restart_policy.clone();
         let stats_broadcast = self.stats_broadcast.clone();
         self.watcher_task = Some(task_tracker.clone().spawn(async move {
             child.start(task_tracker.clone()).await?;
@@ -155,7 +204,9 @@ impl<TAdapter: ProcessAdapter> ProcessWatcher<TAdapter> {
                 num_restarts: 0,
                 max_health_check_duration: Duration::from_secs(0),
                 total_health_check_duration: Duration::from_secs(0),
+                has_given_up: false,
             };
+            let mut recent_restarts: VecDeque<Instant> = VecDeque::new();
             // sleep(Duration::from_secs(10)).await;
             info!(target: LOG_TARGET, "Starting process watcher for {}", name);
             let mut watch_timer = tokio::time::interval(poll_time);
@@ -179,8 +230,13 @@ impl<TAdapter: ProcessAdapter> ProcessWatcher<TAdapter> {
                             inner_shutdown.clone(),
                             &mut warning_count,
                             &stop_on_exit_codes,
+                            &restart_policy,
+                            &mut recent_restarts,
                             &mut stats
                         ).await? {
+                            if let Err(_unused) = stats_broadcast.send(stats.clone()) {
+                                warn!(target: LOG_TARGET, "Failed to broadcast process watcher stats");
+                            }
                             return Ok(exit_code);
                         }
                     },
@@ -208,6 +264,14 @@ impl<TAdapter: ProcessAdapter> ProcessWatcher<TAdapter> {
         }
     }
 
+    /// Whether the process was left stopped because the restart policy was exhausted
+    pub fn has_given_up(&self) -> bool {
+        self.watcher_task
+            .as_ref()
+            .is_some_and(|task| task.is_finished())
+            && self.stats_broadcast.borrow().has_given_up
+    }
+
     #[allow(dead_code)]
     pub fn is_pid_file_exists(&self, base_path: PathBuf) -> bool {
         self.adapter.pid_file_exisits(base_path)
@@ -248,6 +312,8 @@ async fn do_health_check<TStatusMonitor: StatusMonitor, TProcessInstance: Proces
     inner_shutdown: ShutdownSignal,
     warning_count: &mut u32,
     stop_on_exit_codes: &[i32],
+    restart_policy: &RestartPolicy,
+    recent_restarts: &mut VecDeque<Instant>,
     stats: &mut ProcessWatcherStats,
 ) -> Result<Option<i32>, anyhow::Error> {
     let mut is_healthy = false;
@@ -303,7 +369,7 @@ async fn do_health_check<TStatusMonitor: StatusMonitor, TProcessInstance: Proces
         if uptime.elapsed() < expected_startup_time && !ping_failed {
             warn!(target: LOG_TARGET, "{} is not healthy. Waiting for startup time to elapse", name);
         } else {
-            match child.stop().await {
+            let last_exit_code = match child.stop().await {
                 Ok(exit_code) => {
                     if exit_code != 0 {
                         if stop_on_exit_codes.contains(&exit_code) {
@@ -315,15 +381,45 @@ async fn do_health_check<TStatusMonitor: StatusMonitor, TProcessInstance: Proces
                     } else {
                         info!(target: LOG_TARGET, "{} exited successfully", name);
                     }
+                    exit_code
                 }
                 Err(e) => {
                     error!(target: LOG_TARGET, "{} exited with error: {}", name, e);
                     //   return Err(e);
+                    -1
                 }
+            };
+
+            while recent_restarts
+                .front()
+                .is_some_and(|restart| restart.elapsed() > restart_policy.restart_window)
+            {
+                recent_restarts.pop_front();
+            }
+            if recent_restarts.len() >= restart_policy.max_restarts {
+                error!(target: LOG_TARGET, "{} was restarted {} times within {:?}, giving up", name, recent_restarts.len(), restart_policy.restart_window);
+                stats.has_given_up = true;
+                EventsEmitter::emit_process_watcher_gave_up(ProcessWatcherGaveUpPayload {
+                    name,
+                    num_restarts: stats.num_restarts,
+                    exit_code: last_exit_code,
+                })
+                .await;
+                return Ok(Some(last_exit_code));
             }
+
             // Restart dead app
-            sleep(Duration::from_secs(1)).await;
-            warn!(target: LOG_TARGET, "Restarting {} after health check failure", name);
+            let attempt = u32::try_from(recent_restarts.len()).unwrap_or(u32::MAX);
+            let backoff = restart_policy.backoff_for(attempt);
+            warn!(target: LOG_TARGET, "Restarting {} in {:?} after health check failure", name, backoff);
+            let mut inner_shutdown3 = inner_shutdown.clone();
+            let mut app_shutdown3 = global_shutdown_signal.clone();
+            select! {
+                _ = sleep(backoff) => {},
+                _ = inner_shutdown3.wait() => return Ok(None),
+                _ = app_shutdown3.wait() => return Ok(None),
+            }
+            recent_restarts.push_back(Instant::now());
             *uptime = Instant::now();
             stats.num_restarts += 1;
             stats.current_uptime = uptime.elapsed();
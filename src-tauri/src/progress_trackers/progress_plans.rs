@@ -98,6 +98,7 @@ pub enum ProgressSetupNodePlan {
     BinariesTor,
     BinariesNode,
     StartTor,
+    DownloadingSnapshot,
     StartingNode,
     WaitingForInitialSync,
     WaitingForHeaderSync,
@@ -117,6 +118,7 @@ impl ProgressStep for ProgressSetupNodePlan {
             ProgressSetupNodePlan::BinariesTor => 1,
             ProgressSetupNodePlan::BinariesNode => 1,
             ProgressSetupNodePlan::StartTor => 1,
+            ProgressSetupNodePlan::DownloadingSnapshot => 4,
             ProgressSetupNodePlan::StartingNode => 1,
             ProgressSetupNodePlan::WaitingForInitialSync => 2,
             ProgressSetupNodePlan::WaitingForHeaderSync => 2,
@@ -130,6 +132,7 @@ impl ProgressStep for ProgressSetupNodePlan {
             ProgressSetupNodePlan::BinariesTor => "binaries-tor".to_string(),
             ProgressSetupNodePlan::BinariesNode => "binaries-node".to_string(),
             ProgressSetupNodePlan::StartTor => "start-tor".to_string(),
+            ProgressSetupNodePlan::DownloadingSnapshot => "downloading-snapshot".to_string(),
             ProgressSetupNodePlan::StartingNode => "starting-node".to_string(),
             ProgressSetupNodePlan::WaitingForInitialSync => "waiting-for-initial-sync".to_string(),
             ProgressSetupNodePlan::WaitingForHeaderSync => "waiting-for-header-sync".to_string(),
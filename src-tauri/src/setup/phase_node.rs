@@ -20,14 +20,18 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use std::{collections::HashMap, time::Duration};
+use std::{collections::HashMap, path::PathBuf, time::Duration};
 
 use crate::{
     binaries::{Binaries, BinaryResolver},
     configs::{config_core::ConfigCore, trait_config::ConfigImpl},
     events_emitter::EventsEmitter,
     events_manager::EventsManager,
-    node::node_manager::{NodeManagerError, STOP_ON_ERROR_CODES},
+    node::{
+        local_node_adapter::LocalNodePeerSettings,
+        node_manager::{NodeManagerError, NodeType, STOP_ON_ERROR_CODES},
+        node_snapshot::{bootstrap_from_snapshot, is_snapshot_bootstrap_enabled},
+    },
     progress_trackers::{
         progress_plans::{ProgressPlans, ProgressSetupNodePlan},
         progress_stepper::ProgressStepperBuilder,
@@ -37,7 +41,7 @@ use crate::{
     tasks_tracker::TasksTrackers,
     UniverseAppState,
 };
-use anyhow::Error;
+use anyhow::{anyhow, Error};
 use log::{error, info, warn};
 use tari_shutdown::ShutdownSignal;
 use tauri::{AppHandle, Manager};
@@ -64,7 +68,11 @@ pub struct NodeSetupPhaseOutput {}
 #[derive(Clone, Default)]
 pub struct NodeSetupPhaseAppConfiguration {
     use_tor: bool,
-    base_node_grpc_address: String,
+    base_node_grpc_addresses: Vec<String>,
+    local_node_pruning_horizon: Option<u64>,
+    local_node_data_dir: Option<PathBuf>,
+    local_node_snapshot_mirror: Option<String>,
+    local_node_peer_settings: LocalNodePeerSettings,
 }
 
 pub struct NodeSetupPhase {
@@ -132,6 +140,9 @@ impl SetupPhaseImpl for NodeSetupPhase {
             .add_step(ProgressPlans::Node(ProgressSetupNodePlan::BinariesTor))
             .add_step(ProgressPlans::Node(ProgressSetupNodePlan::BinariesNode))
             .add_step(ProgressPlans::Node(ProgressSetupNodePlan::StartTor))
+            .add_step(ProgressPlans::Node(
+                ProgressSetupNodePlan::DownloadingSnapshot,
+            ))
             .add_step(ProgressPlans::Node(ProgressSetupNodePlan::StartingNode))
             .add_step(ProgressPlans::Node(
                 ProgressSetupNodePlan::WaitingForInitialSync,
@@ -148,14 +159,30 @@ impl SetupPhaseImpl for NodeSetupPhase {
 
     async fn load_app_configuration() -> Result<Self::AppConfiguration, Error> {
         let use_tor = *ConfigCore::content().await.use_tor();
-        let base_node_grpc_address = ConfigCore::content()
-            .await
-            .remote_base_node_address()
-            .clone();
+        let config = ConfigCore::content().await;
+        let base_node_grpc_addresses = if *config.node_type() == NodeType::Custom {
+            let custom_node = config
+                .custom_node()
+                .as_ref()
+                .ok_or_else(|| anyhow!("Custom node type selected without custom node settings"))?;
+            vec![custom_node.grpc_address()]
+        } else {
+            config.remote_base_node_addresses()
+        };
+
+        let local_node_pruning_horizon = config
+            .local_node_pruned_mode()
+            .then_some(*config.local_node_pruning_horizon());
+
+        let local_node_peer_settings = LocalNodePeerSettings::from_config(&config);
 
         Ok(NodeSetupPhaseAppConfiguration {
             use_tor,
-            base_node_grpc_address,
+            base_node_grpc_addresses,
+            local_node_pruning_horizon,
+            local_node_data_dir: config.local_node_data_dir().clone(),
+            local_node_snapshot_mirror: config.local_node_snapshot_mirror().clone(),
+            local_node_peer_settings,
         })
     }
 
@@ -201,12 +228,55 @@ impl SetupPhaseImpl for NodeSetupPhase {
                 .await?;
         }
 
+        state
+            .node_manager
+            .set_local_node_storage(
+                self.app_configuration.local_node_pruning_horizon,
+                self.app_configuration.local_node_data_dir.clone(),
+            )
+            .await;
+        state
+            .node_manager
+            .set_local_node_peer_settings(self.app_configuration.local_node_peer_settings.clone())
+            .await;
+
+        let snapshot_mirror = match &self.app_configuration.local_node_snapshot_mirror {
+            Some(_) if !is_snapshot_bootstrap_enabled() => {
+                info!(target: LOG_TARGET, "Snapshot bootstrapping is disabled in this build, ignoring the snapshot mirror");
+                None
+            }
+            Some(mirror) if state.node_manager.is_local().await? => Some(mirror),
+            _ => None,
+        };
+        if let Some(snapshot_mirror) = snapshot_mirror {
+            let snapshot_progress_tracker = progress_stepper.channel_step_range_updates(
+                ProgressPlans::Node(ProgressSetupNodePlan::DownloadingSnapshot),
+                Some(ProgressPlans::Node(ProgressSetupNodePlan::StartingNode)),
+            );
+            let network_dir = state.node_manager.get_local_node_dir(&data_dir).await;
+            // The node can still sync from its peers, so a failed bootstrap doesn't fail the setup
+            if let Err(e) = bootstrap_from_snapshot(
+                snapshot_mirror,
+                &network_dir,
+                self.app_configuration.local_node_pruning_horizon.is_some(),
+                snapshot_progress_tracker,
+            )
+            .await
+            {
+                warn!(target: LOG_TARGET, "Could not bootstrap the node from a snapshot, syncing from peers instead: {:?}", e);
+            }
+        } else {
+            progress_stepper.skip_step(ProgressPlans::Node(
+                ProgressSetupNodePlan::DownloadingSnapshot,
+            ));
+        }
+
         let tor_control_port = state.tor_manager.get_control_port().await?;
         progress_stepper
             .resolve_step(ProgressPlans::Node(ProgressSetupNodePlan::StartingNode))
             .await;
 
-        info!(target: LOG_TARGET, "Starting node manager, grpc address: {}", self.app_configuration.base_node_grpc_address);
+        info!(target: LOG_TARGET, "Starting node manager, grpc addresses: {:?}", self.app_configuration.base_node_grpc_addresses);
 
         for _i in 0..2 {
             match state
@@ -217,7 +287,7 @@ impl SetupPhaseImpl for NodeSetupPhase {
                     log_dir.clone(),
                     self.app_configuration.use_tor,
                     tor_control_port,
-                    Some(self.app_configuration.base_node_grpc_address.clone()),
+                    Some(self.app_configuration.base_node_grpc_addresses.clone()),
                 )
                 .await
             {
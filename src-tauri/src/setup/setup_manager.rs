@@ -26,7 +26,18 @@ use super::{
     utils::phase_builder::PhaseBuilder,
 };
 use crate::app_in_memory_config::{MinerType, DEFAULT_EXCHANGE_ID};
+use crate::battery_policy::BatteryPolicy;
 use crate::configs::config_core::ConfigCoreContent;
+use crate::control_api_server::ControlApiServer;
+use crate::gpu_engine_fallback::GpuEngineFallback;
+use crate::hardware::thermal_governor::ThermalGovernor;
+use crate::idle_mining::IdleMining;
+use crate::metrics_exporter::MetricsExporter;
+use crate::mining_history::MiningHistory;
+use crate::mining_scheduler::MiningScheduler;
+use crate::node::node_disk_monitor::NodeDiskMonitor;
+use crate::pool_failover::PoolFailover;
+use crate::stratum_proxy::StratumProxy;
 use crate::{
     configs::{
         config_core::ConfigCore, config_mining::ConfigMining, config_ui::ConfigUI,
@@ -35,6 +46,7 @@ use crate::{
     events::ConnectionStatusPayload,
     events_emitter::EventsEmitter,
     events_manager::EventsManager,
+    headless_mode::HeadlessMode,
     initialize_frontend_updates,
     release_notes::ReleaseNotes,
     tasks_tracker::TasksTrackers,
@@ -273,34 +285,39 @@ impl SetupManager {
         websocket_events_manager_guard.set_app_handle(app_handle.clone());
         drop(websocket_events_manager_guard);
 
-        let mut websocket_manager_write = state.websocket_manager.write().await;
-        websocket_manager_write.set_app_handle(app_handle.clone());
-        drop(websocket_manager_write);
-
-        let webview = app_handle
-            .get_webview_window("main")
-            .expect("main window must exist");
-        let websocket_tx = state.websocket_message_tx.clone();
-        webview.listen("ws-tx", move |event: tauri::Event| {
-            let event_cloned = event.clone();
-            let websocket_tx_clone = websocket_tx.clone();
-
-            tauri::async_runtime::spawn(async move {
-                let message = event_cloned.payload();
-                if let Ok(message) = serde_json::from_str::<WebsocketMessage>(message)
-                    .inspect_err(|e| error!("websocket malformatted: {}", e))
-                {
-                    if websocket_tx_clone
-                        .send(message.clone())
-                        .await
-                        .inspect_err(|e| error!("too many messages in websocket send queue {}", e))
-                        .is_ok()
+        // Websocket messages are relayed from the webview, which does not exist in headless mode
+        if !HeadlessMode::current().is_enabled() {
+            let mut websocket_manager_write = state.websocket_manager.write().await;
+            websocket_manager_write.set_app_handle(app_handle.clone());
+            drop(websocket_manager_write);
+
+            let webview = app_handle
+                .get_webview_window("main")
+                .expect("main window must exist");
+            let websocket_tx = state.websocket_message_tx.clone();
+            webview.listen("ws-tx", move |event: tauri::Event| {
+                let event_cloned = event.clone();
+                let websocket_tx_clone = websocket_tx.clone();
+
+                tauri::async_runtime::spawn(async move {
+                    let message = event_cloned.payload();
+                    if let Ok(message) = serde_json::from_str::<WebsocketMessage>(message)
+                        .inspect_err(|e| error!("websocket malformatted: {}", e))
                     {
-                        log::trace!("websocket message sent {:?}", message);
+                        if websocket_tx_clone
+                            .send(message.clone())
+                            .await
+                            .inspect_err(|e| {
+                                error!("too many messages in websocket send queue {}", e)
+                            })
+                            .is_ok()
+                        {
+                            log::trace!("websocket message sent {:?}", message);
+                        }
                     }
-                }
+                });
             });
-        });
+        }
         EventsManager::handle_node_type_update(&app_handle).await;
 
         ConfigCore::initialize(app_handle.clone()).await;
@@ -308,6 +325,29 @@ impl SetupManager {
         ConfigMining::initialize(app_handle.clone()).await;
         ConfigUI::initialize(app_handle.clone()).await;
 
+        let _unused = ControlApiServer::start(app_handle.clone())
+            .await
+            .inspect_err(|e| error!(target: LOG_TARGET, "Failed to start control API: {:?}", e));
+        let _unused = StratumProxy::start(app_handle.clone())
+            .await
+            .inspect_err(|e| error!(target: LOG_TARGET, "Failed to start stratum proxy: {:?}", e));
+        let _unused = MetricsExporter::start(app_handle.clone())
+            .await
+            .inspect_err(
+                |e| error!(target: LOG_TARGET, "Failed to start metrics exporter: {:?}", e),
+            );
+        let _unused = MiningHistory::start(app_handle.clone())
+            .await
+            .inspect_err(|e| error!(target: LOG_TARGET, "Failed to start mining history: {:?}", e));
+        MiningScheduler::start(app_handle.clone()).await;
+        IdleMining::start(app_handle.clone()).await;
+        ThermalGovernor::start(app_handle.clone()).await;
+        SystemStatus::start_power_source_watcher().await;
+        BatteryPolicy::start(app_handle.clone()).await;
+        PoolFailover::start(app_handle.clone()).await;
+        GpuEngineFallback::start(app_handle.clone()).await;
+        NodeDiskMonitor::start(app_handle.clone()).await;
+
         let build_in_exchange_id = in_memory_config.read().await.exchange_id.clone();
         let is_on_exchange_miner_build =
             MinerType::from_str(&build_in_exchange_id).is_exchange_mode();
@@ -871,6 +911,14 @@ impl SetupManager {
         EventsEmitter::emit_unlock_gpu_mining().await;
     }
 
+    pub async fn is_cpu_mining_unlocked(&self) -> bool {
+        *self.is_cpu_mining_unlocked.lock().await
+    }
+
+    pub async fn is_gpu_mining_unlocked(&self) -> bool {
+        *self.is_gpu_mining_unlocked.lock().await
+    }
+
     async fn lock_cpu_mining(&self) {
         if !*self.is_cpu_mining_unlocked.lock().await {
             debug!(target: LOG_TARGET, "Mining is already locked");
@@ -909,11 +957,19 @@ impl SetupManager {
         info!(target: LOG_TARGET, "Setup Finished");
         EventsEmitter::emit_initial_setup_finished().await;
         let _unused = initialize_frontend_updates(&app_handle).await;
+        if HeadlessMode::current().is_enabled() {
+            HeadlessMode::start_mining(&app_handle).await;
+        }
     }
 
     async fn handle_restart_finished(&self) {
         info!(target: LOG_TARGET, "Restart Finished");
         EventsEmitter::emit_connection_status_changed(ConnectionStatusPayload::Succeed).await;
+        if HeadlessMode::current().is_enabled() {
+            if let Some(app_handle) = self.app_handle.lock().await.clone() {
+                HeadlessMode::start_mining(&app_handle).await;
+            }
+        }
     }
 
     pub async fn start_setup(&self, app_handle: AppHandle) {
@@ -981,6 +1037,16 @@ impl SetupManager {
         }
     }
 
+    pub async fn handle_remote_node_failover(&self) {
+        if let Some(app_handle) = self.app_handle.lock().await.clone() {
+            info!(target: LOG_TARGET, "Remote node failed over, restarting phases that use it");
+            self.restart_phases(app_handle, vec![SetupPhase::Wallet, SetupPhase::Mining])
+                .await;
+        } else {
+            error!(target: LOG_TARGET, "Failed to restart phases after remote node failover: app_handle not defined");
+        }
+    }
+
     pub async fn spawn_sleep_mode_handler(app_handle: AppHandle) {
         info!(target: LOG_TARGET, "Spawning Sleep Mode Handler");
         let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
@@ -0,0 +1,296 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+mod models;
+mod node_jobs;
+mod randomx;
+mod relay;
+
+use std::{
+    collections::{HashMap, HashSet},
+    net::SocketAddr,
+    sync::{Arc, LazyLock, Mutex},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::bail;
+use log::{info, warn};
+use tari_shutdown::ShutdownSignal;
+use tauri::{AppHandle, Manager};
+use tokio::{net::TcpStream, select, sync::RwLock};
+
+use crate::{
+    commands::CpuMinerConnection,
+    configs::{config_core::ConfigCore, config_wallet::ConfigWallet, trait_config::ConfigImpl},
+    events::StratumProxyUpdatePayload,
+    events_emitter::EventsEmitter,
+    tasks_tracker::TasksTrackers,
+    utils::pool_url_utils::pool_address,
+    UniverseAppState,
+};
+
+pub use models::StratumWorker;
+use node_jobs::NodeJobs;
+
+const LOG_TARGET: &str = "tari::universe::stratum_proxy";
+const WORKERS_UPDATE_INTERVAL: Duration = Duration::from_secs(10);
+
+static INSTANCE: LazyLock<StratumProxy> = LazyLock::new(StratumProxy::new);
+
+/// Where LAN miners are sent, follows whatever the local xmrig mines on
+enum Upstream {
+    Pool { address: String, login: String },
+    MergeMiningProxy,
+}
+
+/// Stratum server that lets other xmrig rigs on the LAN mine through this install,
+/// with share accounting per worker. See `docs/stratum_proxy` for what it does and doesn't serve.
+pub struct StratumProxy {
+    workers: RwLock<HashMap<String, StratumWorker>>,
+    /// Top nonce bytes of the miners currently served from the node's templates
+    nonce_prefixes: Mutex<HashSet<u8>>,
+}
+
+impl StratumProxy {
+    fn new() -> Self {
+        Self {
+            workers: RwLock::new(HashMap::new()),
+            nonce_prefixes: Mutex::new(HashSet::new()),
+        }
+    }
+
+    pub fn current() -> &'static StratumProxy {
+        &INSTANCE
+    }
+
+    /// Serves Stratum on the configured address when it is enabled in the core config.
+    /// The server lives until the common tasks are shut down.
+    pub async fn start(app_handle: AppHandle) -> Result<(), anyhow::Error> {
+        if !*ConfigCore::content().await.stratum_proxy_enabled() {
+            info!(target: LOG_TARGET, "Stratum proxy is disabled");
+            return Ok(());
+        }
+
+        let addr: SocketAddr = ConfigCore::content()
+            .await
+            .stratum_proxy_bind_address()
+            .parse()?;
+        let password = ConfigCore::content()
+            .await
+            .stratum_proxy_password()
+            .clone()
+            .filter(|password| !password.is_empty());
+        let listener = tokio::net::TcpListener::bind(addr).await?;
+        info!(target: LOG_TARGET, "Stratum proxy listening on {}", addr);
+        if password.is_none() && !addr.ip().is_loopback() {
+            warn!(target: LOG_TARGET, "Stratum proxy has no password, anyone who can reach {} can mine through it", addr);
+        }
+
+        let node_jobs = NodeJobs::new(app_handle.clone())?;
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        let task_tracker = TasksTrackers::current().common.get_task_tracker().await;
+        task_tracker.spawn(node_jobs.clone().run(shutdown_signal.clone()));
+        task_tracker.clone().spawn(async move {
+            let mut update_interval = tokio::time::interval(WORKERS_UPDATE_INTERVAL);
+            loop {
+                select! {
+                    accepted = listener.accept() => match accepted {
+                        Ok((miner, peer)) => {
+                            info!(target: LOG_TARGET, "Miner connected from {}", peer);
+                            task_tracker.spawn(Self::serve(
+                                app_handle.clone(),
+                                miner,
+                                peer,
+                                node_jobs.clone(),
+                                password.clone(),
+                                shutdown_signal.clone(),
+                            ));
+                        }
+                        Err(e) => warn!(target: LOG_TARGET, "Could not accept miner connection: {:?}", e),
+                    },
+                    _ = update_interval.tick() => {
+                        let workers = Self::current().workers().await;
+                        if !workers.is_empty() {
+                            EventsEmitter::emit_stratum_proxy_update(StratumProxyUpdatePayload { workers }).await;
+                        }
+                    }
+                    _ = shutdown_signal.wait() => {
+                        info!(target: LOG_TARGET, "Shutdown signal received, stopping stratum proxy");
+                        break;
+                    }
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    async fn serve(
+        app_handle: AppHandle,
+        miner: TcpStream,
+        peer: SocketAddr,
+        node_jobs: Arc<NodeJobs>,
+        password: Option<String>,
+        shutdown_signal: ShutdownSignal,
+    ) {
+        let result = match Self::upstream(&app_handle).await {
+            Ok(Upstream::Pool { address, login }) => {
+                relay::relay_session(miner, peer, address, login, password, shutdown_signal).await
+            }
+            Ok(Upstream::MergeMiningProxy) => {
+                node_jobs
+                    .serve(miner, peer, password, shutdown_signal)
+                    .await
+            }
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(()) => info!(target: LOG_TARGET, "Miner {} disconnected", peer),
+            Err(e) => warn!(target: LOG_TARGET, "Miner {} disconnected: {:?}", peer, e),
+        }
+    }
+
+    async fn upstream(app_handle: &AppHandle) -> Result<Upstream, anyhow::Error> {
+        let tari_address = ConfigWallet::content()
+            .await
+            .get_current_used_tari_address()
+            .to_base58();
+        let state = app_handle.state::<UniverseAppState>();
+        let cpu_miner_config = state.cpu_miner_config.read().await;
+        let (Some(host_name), Some(port)) = (
+            cpu_miner_config.pool_host_name.as_ref(),
+            cpu_miner_config.pool_port,
+        ) else {
+            return Ok(Upstream::MergeMiningProxy);
+        };
+        let login = match cpu_miner_config.node_connection {
+            CpuMinerConnection::BuiltInProxy => return Ok(Upstream::MergeMiningProxy),
+            CpuMinerConnection::Pool => tari_address,
+            CpuMinerConnection::MergeMinedPool => {
+                format!("{}:{}", cpu_miner_config.monero_address, tari_address)
+            }
+        };
+        // Miners are relayed as plain text, there is no TLS client to wrap the pool connection in
+        if cpu_miner_config.pool_options.tls {
+            bail!("Relaying to TLS pools is not supported");
+        }
+        Ok(Upstream::Pool {
+            address: pool_address(host_name, port),
+            login,
+        })
+    }
+
+    /// A top nonce byte no other connected miner searches. Never zero, which would leave the miner
+    /// free to use the whole nonce range, so `None` once 255 miners are connected
+    fn claim_nonce_prefix(&self) -> Option<u8> {
+        let mut nonce_prefixes = self.nonce_prefixes.lock().ok()?;
+        let nonce_prefix = (1..=u8::MAX).find(|prefix| !nonce_prefixes.contains(prefix))?;
+        nonce_prefixes.insert(nonce_prefix);
+        Some(nonce_prefix)
+    }
+
+    fn release_nonce_prefix(&self, nonce_prefix: u8) {
+        if let Ok(mut nonce_prefixes) = self.nonce_prefixes.lock() {
+            nonce_prefixes.remove(&nonce_prefix);
+        }
+    }
+
+    async fn worker_connected(&self, name: &str, address: &str) {
+        let mut workers = self.workers.write().await;
+        let worker = workers
+            .entry(name.to_string())
+            .or_insert_with(|| StratumWorker {
+                name: name.to_string(),
+                ..StratumWorker::default()
+            });
+        worker.address = address.to_string();
+        worker.is_connected = true;
+        worker.connected_at = unix_timestamp();
+        worker.session_difficulty = 0;
+    }
+
+    async fn worker_disconnected(&self, name: &str) {
+        if let Some(worker) = self.workers.write().await.get_mut(name) {
+            worker.is_connected = false;
+        }
+    }
+
+    async fn record_share(&self, name: &str, is_accepted: bool, difficulty: u64) {
+        if let Some(worker) = self.workers.write().await.get_mut(name) {
+            if is_accepted {
+                worker.accepted_shares += 1;
+                worker.session_difficulty += difficulty;
+            } else {
+                worker.rejected_shares += 1;
+            }
+            worker.last_share_time = Some(unix_timestamp());
+        }
+    }
+
+    /// Every worker seen since the app started, rigs reconnecting under the same name share an entry
+    pub async fn workers(&self) -> Vec<StratumWorker> {
+        let now = unix_timestamp();
+        let mut workers: Vec<StratumWorker> = self
+            .workers
+            .read()
+            .await
+            .values()
+            .cloned()
+            .map(|mut worker| {
+                if worker.is_connected {
+                    let elapsed = now.saturating_sub(worker.connected_at).max(1);
+                    worker.hashrate = worker.session_difficulty as f64 / elapsed as f64;
+                }
+                worker
+            })
+            .collect();
+        workers.sort_by(|a, b| a.name.cmp(&b.name));
+        workers
+    }
+}
+
+fn unix_timestamp() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn hands_out_each_nonce_prefix_once() {
+        let proxy = StratumProxy::new();
+        let nonce_prefixes: HashSet<u8> = (0..255)
+            .map(|_| proxy.claim_nonce_prefix().unwrap())
+            .collect();
+        assert_eq!(nonce_prefixes.len(), 255);
+        assert!(!nonce_prefixes.contains(&0));
+        assert_eq!(proxy.claim_nonce_prefix(), None);
+
+        proxy.release_nonce_prefix(42);
+        assert_eq!(proxy.claim_nonce_prefix(), Some(42));
+    }
+}
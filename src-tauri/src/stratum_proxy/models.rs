@@ -0,0 +1,159 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+/// Byte offset of the 4 byte nonce in Monero hashing and block template blobs
+pub(super) const NONCE_OFFSET: usize = 39;
+pub(super) const NONCE_SIZE: usize = 4;
+
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct StratumWorker {
+    /// `rigid` or `pass` sent on login, only `rigid` counts when the proxy has a password. The peer address otherwise
+    pub name: String,
+    pub address: String,
+    pub is_connected: bool,
+    /// Unix timestamp in seconds of the current or last connection
+    pub connected_at: u64,
+    pub accepted_shares: u64,
+    pub rejected_shares: u64,
+    /// Unix timestamp in seconds
+    pub last_share_time: Option<u64>,
+    /// Estimated from the difficulty of accepted shares since the worker connected
+    pub hashrate: f64,
+    #[serde(skip)]
+    pub(super) session_difficulty: u64,
+}
+
+/// Worker name a miner asked for on login. `pass` only names the worker while the proxy has no password
+pub(super) fn worker_name(login_params: &Value, peer_address: &str, has_password: bool) -> String {
+    let keys: &[&str] = if has_password {
+        &["rigid"]
+    } else {
+        &["rigid", "pass"]
+    };
+    keys.iter()
+        .filter_map(|key| login_params.get(key).and_then(Value::as_str))
+        .find(|value| !value.is_empty() && *value != "x")
+        .unwrap_or(peer_address)
+        .to_string()
+}
+
+/// Whether a login carries the proxy password, any login is let in when none is set
+pub(super) fn is_authorized(login_params: &Value, password: Option<&str>) -> bool {
+    password
+        .is_none_or(|password| login_params.get("pass").and_then(Value::as_str) == Some(password))
+}
+
+pub(super) fn result_response(id: &Value, result: Value) -> String {
+    json!({ "id": id, "jsonrpc": "2.0", "error": null, "result": result }).to_string()
+}
+
+pub(super) fn error_response(id: &Value, message: &str) -> String {
+    json!({ "id": id, "jsonrpc": "2.0", "error": { "code": -1, "message": message } }).to_string()
+}
+
+pub(super) fn job_notification(job: Value) -> String {
+    json!({ "jsonrpc": "2.0", "method": "job", "params": job }).to_string()
+}
+
+/// Difficulty of a compact (4 byte) or full (8 byte) little endian stratum target
+pub(super) fn difficulty_from_target(target: &str) -> Option<u64> {
+    let bytes = hex::decode(target).ok()?;
+    match bytes.len() {
+        4 => {
+            let target = u32::from_le_bytes(bytes.try_into().ok()?);
+            (target > 0).then(|| u64::from(u32::MAX) / u64::from(target))
+        }
+        8 => {
+            let target = u64::from_le_bytes(bytes.try_into().ok()?);
+            (target > 0).then(|| u64::MAX / target)
+        }
+        _ => None,
+    }
+}
+
+pub(super) fn target_from_difficulty(difficulty: u64) -> String {
+    hex::encode((u64::MAX / difficulty.max(1)).to_le_bytes())
+}
+
+/// Difficulty met by a RandomX result hash, going by its most significant 8 bytes
+pub(super) fn hash_difficulty(result: &str) -> Option<u64> {
+    let bytes = hex::decode(result).ok()?;
+    if bytes.len() != 32 {
+        return None;
+    }
+    let high_bytes: [u8; 8] = bytes.get(24..32)?.try_into().ok()?;
+    match u64::from_le_bytes(high_bytes) {
+        0 => Some(u64::MAX),
+        value => Some(u64::MAX / value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn converts_between_targets_and_difficulty() {
+        assert_eq!(difficulty_from_target("b88d0600"), Some(10_000));
+        assert_eq!(
+            difficulty_from_target(&target_from_difficulty(250_000)),
+            Some(250_000)
+        );
+        assert_eq!(difficulty_from_target("00000000"), None);
+        assert_eq!(difficulty_from_target("zz"), None);
+    }
+
+    #[test]
+    fn reads_difficulty_from_result_hash() {
+        let mut hash = [0u8; 32];
+        hash[24..32].copy_from_slice(&(u64::MAX / 5_000).to_le_bytes());
+        assert_eq!(hash_difficulty(&hex::encode(hash)), Some(5_000));
+        assert_eq!(hash_difficulty("abcd"), None);
+    }
+
+    #[test]
+    fn picks_worker_name_from_login() {
+        let params = json!({ "login": "wallet", "pass": "x", "rigid": "rig-1" });
+        assert_eq!(worker_name(&params, "10.0.0.2", false), "rig-1");
+        let params = json!({ "login": "wallet", "pass": "x" });
+        assert_eq!(worker_name(&params, "10.0.0.2", false), "10.0.0.2");
+        let params = json!({ "login": "wallet", "pass": "secret" });
+        assert_eq!(worker_name(&params, "10.0.0.2", false), "secret");
+        assert_eq!(worker_name(&params, "10.0.0.2", true), "10.0.0.2");
+    }
+
+    #[test]
+    fn checks_the_proxy_password_on_login() {
+        let params = json!({ "login": "wallet", "pass": "secret" });
+        assert!(is_authorized(&params, None));
+        assert!(is_authorized(&params, Some("secret")));
+        assert!(!is_authorized(&params, Some("other")));
+        assert!(!is_authorized(
+            &json!({ "login": "wallet" }),
+            Some("secret")
+        ));
+    }
+}
@@ -0,0 +1,521 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::collections::{HashSet, VecDeque};
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::{anyhow, bail};
+use log::{error, info, warn};
+use serde::Deserialize;
+use serde_json::{json, Value};
+use tari_shutdown::ShutdownSignal;
+use tauri::{AppHandle, Manager};
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::select;
+use tokio::sync::watch;
+use tokio::time::MissedTickBehavior;
+
+use super::models::{
+    error_response, hash_difficulty, is_authorized, job_notification, result_response,
+    target_from_difficulty, worker_name, NONCE_OFFSET, NONCE_SIZE,
+};
+use super::randomx::RandomXHasher;
+use super::StratumProxy;
+use crate::UniverseAppState;
+
+const LOG_TARGET: &str = "tari::universe::stratum_proxy::node_jobs";
+const TEMPLATE_POLL_INTERVAL: Duration = Duration::from_secs(5);
+/// Difficulty of the shares LAN miners submit, a few shares a minute for a typical CPU
+const SHARE_DIFFICULTY: u64 = 25_000;
+/// Shares for the previous jobs are still accepted for a while after a new template arrives
+const RECENT_TEMPLATES: usize = 3;
+
+#[derive(Deserialize)]
+struct BlockTemplateResponse {
+    blocktemplate_blob: String,
+    blockhashing_blob: String,
+    difficulty: u64,
+    height: u64,
+    seed_hash: String,
+}
+
+struct BlockTemplate {
+    job_id: String,
+    blocktemplate_blob: String,
+    blockhashing_blob: String,
+    difficulty: u64,
+    height: u64,
+    seed_hash: String,
+    seed: Vec<u8>,
+}
+
+/// Copy of a hex blob with `bytes` written over it at byte `offset`, an error rather than a panic when it is too short
+fn replace_bytes(blob: &str, offset: usize, bytes: &str) -> Result<String, anyhow::Error> {
+    let range = offset * 2..offset * 2 + bytes.len();
+    if blob.get(range.clone()).is_none() {
+        bail!("Blob is too short");
+    }
+    let mut blob = blob.to_string();
+    blob.replace_range(range, bytes);
+    Ok(blob)
+}
+
+impl BlockTemplate {
+    fn share_difficulty(&self) -> u64 {
+        SHARE_DIFFICULTY.min(self.difficulty)
+    }
+
+    /// Every miner gets its own top nonce byte, so they never search the same nonces
+    fn job(&self, nonce_prefix: u8) -> Result<Value, anyhow::Error> {
+        let blob = replace_bytes(
+            &self.blockhashing_blob,
+            NONCE_OFFSET + NONCE_SIZE - 1,
+            &format!("{nonce_prefix:02x}"),
+        )?;
+        Ok(json!({
+            "blob": blob,
+            "job_id": self.job_id,
+            "target": target_from_difficulty(self.share_difficulty()),
+            "height": self.height,
+            "seed_hash": self.seed_hash,
+            "algo": "rx/0",
+        }))
+    }
+
+    /// What the miner hashed for the given nonce
+    fn hashing_blob(&self, nonce: &str) -> Result<Vec<u8>, anyhow::Error> {
+        Ok(hex::decode(replace_bytes(
+            &self.blockhashing_blob,
+            NONCE_OFFSET,
+            nonce,
+        )?)?)
+    }
+
+    fn block_blob(&self, nonce: &str) -> Result<String, anyhow::Error> {
+        replace_bytes(&self.blocktemplate_blob, NONCE_OFFSET, nonce)
+    }
+}
+
+/// Hands out jobs built from the merge mining proxy's block templates, the same templates the local xmrig mines on
+pub(super) struct NodeJobs {
+    app_handle: AppHandle,
+    client: reqwest::Client,
+    hasher: RandomXHasher,
+    templates_tx: watch::Sender<Option<Arc<BlockTemplate>>>,
+    recent_templates: Mutex<VecDeque<Arc<BlockTemplate>>>,
+    next_job_id: AtomicU64,
+}
+
+impl NodeJobs {
+    pub fn new(app_handle: AppHandle) -> Result<Arc<Self>, anyhow::Error> {
+        let (templates_tx, _) = watch::channel(None);
+        Ok(Arc::new(Self {
+            app_handle,
+            client: reqwest::Client::new(),
+            hasher: RandomXHasher::new()?,
+            templates_tx,
+            recent_templates: Mutex::new(VecDeque::with_capacity(RECENT_TEMPLATES)),
+            next_job_id: AtomicU64::new(1),
+        }))
+    }
+
+    /// Polls for new templates while any miner is connected
+    pub async fn run(self: Arc<Self>, mut shutdown_signal: ShutdownSignal) {
+        let mut interval = tokio::time::interval(TEMPLATE_POLL_INTERVAL);
+        interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        loop {
+            select! {
+                _ = interval.tick() => {
+                    if self.templates_tx.receiver_count() == 0 {
+                        continue;
+                    }
+                    if let Err(e) = self.refresh_template().await {
+                        warn!(target: LOG_TARGET, "Could not get block template: {:?}", e);
+                    }
+                }
+                _ = shutdown_signal.wait() => break,
+            }
+        }
+    }
+
+    async fn json_rpc(&self, method: &str, params: Value) -> Result<Value, anyhow::Error> {
+        let state = self.app_handle.state::<UniverseAppState>();
+        let port = state.mm_proxy_manager.get_monero_port().await?;
+        let response: Value = self
+            .client
+            .post(format!("http://127.0.0.1:{port}/json_rpc"))
+            .json(&json!({ "jsonrpc": "2.0", "id": "0", "method": method, "params": params }))
+            .send()
+            .await?
+            .json()
+            .await?;
+        if let Some(error) = response.get("error").filter(|error| !error.is_null()) {
+            return Err(anyhow!("{} failed: {}", method, error));
+        }
+        response
+            .get("result")
+            .cloned()
+            .ok_or_else(|| anyhow!("{} returned no result", method))
+    }
+
+    async fn refresh_template(&self) -> Result<(), anyhow::Error> {
+        let state = self.app_handle.state::<UniverseAppState>();
+        let monero_address = state.cpu_miner_config.read().await.monero_address.clone();
+        let result = self
+            .json_rpc(
+                "get_block_template",
+                json!({ "wallet_address": monero_address, "reserve_size": 0 }),
+            )
+            .await?;
+        let response: BlockTemplateResponse = serde_json::from_value(result)?;
+        let min_blob_length = (NONCE_OFFSET + NONCE_SIZE) * 2;
+        if response.blockhashing_blob.len() < min_blob_length
+            || response.blocktemplate_blob.len() < min_blob_length
+        {
+            bail!("Block template is too short");
+        }
+        if hex::decode(&response.blockhashing_blob).is_err()
+            || hex::decode(&response.blocktemplate_blob).is_err()
+        {
+            bail!("Block template is not hex");
+        }
+        let seed = hex::decode(&response.seed_hash)?;
+        if self
+            .templates_tx
+            .borrow()
+            .as_ref()
+            .is_some_and(|template| template.blockhashing_blob == response.blockhashing_blob)
+        {
+            return Ok(());
+        }
+
+        let template = Arc::new(BlockTemplate {
+            job_id: self.next_job_id.fetch_add(1, Ordering::Relaxed).to_string(),
+            blocktemplate_blob: response.blocktemplate_blob,
+            blockhashing_blob: response.blockhashing_blob,
+            difficulty: response.difficulty,
+            height: response.height,
+            seed_hash: response.seed_hash,
+            seed,
+        });
+        {
+            let mut recent_templates = self
+                .recent_templates
+                .lock()
+                .map_err(|_| anyhow!("Recent templates lock poisoned"))?;
+            recent_templates.push_front(template.clone());
+            recent_templates.truncate(RECENT_TEMPLATES);
+        }
+        self.templates_tx.send_replace(Some(template));
+        Ok(())
+    }
+
+    fn recent_template(&self, job_id: &str) -> Option<Arc<BlockTemplate>> {
+        self.recent_templates
+            .lock()
+            .ok()?
+            .iter()
+            .find(|template| template.job_id == job_id)
+            .cloned()
+    }
+
+    async fn submit_block(&self, template: &BlockTemplate, nonce: &str, worker: &str) {
+        let block_blob = match template.block_blob(nonce) {
+            Ok(block_blob) => block_blob,
+            Err(e) => {
+                error!(target: LOG_TARGET, "Could not build block found by {}: {:?}", worker, e);
+                return;
+            }
+        };
+        match self.json_rpc("submit_block", json!([block_blob])).await {
+            Ok(_) => {
+                info!(target: LOG_TARGET, "Submitted block at height {} found by {}", template.height, worker)
+            }
+            Err(e) => {
+                error!(target: LOG_TARGET, "Could not submit block found by {}: {:?}", worker, e)
+            }
+        }
+        // The template is spent either way
+        if let Err(e) = self.refresh_template().await {
+            warn!(target: LOG_TARGET, "Could not get block template: {:?}", e);
+        }
+    }
+
+    pub async fn serve(
+        self: Arc<Self>,
+        miner: TcpStream,
+        peer: SocketAddr,
+        password: Option<String>,
+        mut shutdown_signal: ShutdownSignal,
+    ) -> Result<(), anyhow::Error> {
+        let nonce_prefix = StratumProxy::current()
+            .claim_nonce_prefix()
+            .ok_or_else(|| anyhow!("Every nonce range is taken by other miners"))?;
+        let mut session = NodeSession {
+            jobs: self.clone(),
+            peer_address: peer.ip().to_string(),
+            password,
+            nonce_prefix,
+            worker: None,
+            submitted_shares: HashSet::new(),
+        };
+        let (miner_reader, mut miner_writer) = miner.into_split();
+        let mut miner_lines = BufReader::new(miner_reader).lines();
+        let mut templates_rx = self.templates_tx.subscribe();
+
+        let result: Result<(), anyhow::Error> = async {
+            loop {
+                select! {
+                    line = miner_lines.next_line() => {
+                        let Some(line) = line? else {
+                            return Ok(());
+                        };
+                        let response = session.handle_request(&line).await;
+                        miner_writer.write_all(format!("{response}\n").as_bytes()).await?;
+                    }
+                    changed = templates_rx.changed() => {
+                        if changed.is_err() {
+                            return Ok(());
+                        }
+                        let template = templates_rx.borrow_and_update().clone();
+                        session.forget_expired_shares();
+                        if let (Some(template), true) = (template, session.worker.is_some()) {
+                            let notification = job_notification(template.job(session.nonce_prefix)?);
+                            miner_writer.write_all(format!("{notification}\n").as_bytes()).await?;
+                        }
+                    }
+                    _ = shutdown_signal.wait() => return Ok(()),
+                }
+            }
+        }
+        .await;
+
+        StratumProxy::current().release_nonce_prefix(session.nonce_prefix);
+        if let Some(worker) = session.worker {
+            StratumProxy::current().worker_disconnected(&worker).await;
+        }
+        result
+    }
+}
+
+struct NodeSession {
+    jobs: Arc<NodeJobs>,
+    peer_address: String,
+    password: Option<String>,
+    nonce_prefix: u8,
+    worker: Option<String>,
+    submitted_shares: HashSet<(String, String)>,
+}
+
+impl NodeSession {
+    async fn handle_request(&mut self, line: &str) -> String {
+        let Ok(request) = serde_json::from_str::<Value>(line) else {
+            return error_response(&Value::Null, "Invalid request");
+        };
+        let id = request.get("id").cloned().unwrap_or(Value::Null);
+        let params = request.get("params").cloned().unwrap_or(Value::Null);
+        match request.get("method").and_then(Value::as_str) {
+            Some("login") => self.login(&id, &params).await,
+            Some("getjob") => match self.current_job().await {
+                Ok(job) => result_response(&id, job),
+                Err(e) => error_response(&id, &e.to_string()),
+            },
+            Some("submit") => self.submit(&id, &params).await,
+            Some("keepalived") => result_response(&id, json!({ "status": "KEEPALIVED" })),
+            _ => error_response(&id, "Unsupported method"),
+        }
+    }
+
+    async fn current_job(&self) -> Result<Value, anyhow::Error> {
+        if self.jobs.templates_tx.borrow().is_none() {
+            self.jobs.refresh_template().await?;
+        }
+        let template = self
+            .jobs
+            .templates_tx
+            .borrow()
+            .clone()
+            .ok_or_else(|| anyhow!("No job available yet"))?;
+        template.job(self.nonce_prefix)
+    }
+
+    async fn login(&mut self, id: &Value, params: &Value) -> String {
+        if !is_authorized(params, self.password.as_deref()) {
+            return error_response(id, "Unauthorized");
+        }
+        let job = match self.current_job().await {
+            Ok(job) => job,
+            Err(e) => {
+                warn!(target: LOG_TARGET, "Could not get a job for {}: {:?}", self.peer_address, e);
+                return error_response(id, "The local node is not ready yet");
+            }
+        };
+        let name = worker_name(params, &self.peer_address, self.password.is_some());
+        StratumProxy::current()
+            .worker_connected(&name, &self.peer_address)
+            .await;
+        self.worker = Some(name);
+        result_response(
+            id,
+            json!({
+                "id": self.nonce_prefix.to_string(),
+                "job": job,
+                "status": "OK",
+                "extensions": ["keepalive"],
+            }),
+        )
+    }
+
+    async fn submit(&mut self, id: &Value, params: &Value) -> String {
+        let Some(worker) = self.worker.clone() else {
+            return error_response(id, "Unauthenticated");
+        };
+        match self.check_share(params, &worker).await {
+            Ok(difficulty) => {
+                StratumProxy::current()
+                    .record_share(&worker, true, difficulty)
+                    .await;
+                result_response(id, json!({ "status": "OK" }))
+            }
+            Err(e) => {
+                StratumProxy::current()
+                    .record_share(&worker, false, 0)
+                    .await;
+                error_response(id, &e.to_string())
+            }
+        }
+    }
+
+    /// Returns the difficulty the share is credited with. The result hash is recomputed,
+    /// so neither the share accounting nor the node ever sees a hash the miner made up.
+    async fn check_share(&mut self, params: &Value, worker: &str) -> Result<u64, anyhow::Error> {
+        let field = |key: &str| {
+            params
+                .get(key)
+                .and_then(Value::as_str)
+                .ok_or_else(|| anyhow!("Missing {}", key))
+        };
+        let job_id = field("job_id")?;
+        let nonce = field("nonce")?.to_lowercase();
+        let result = field("result")?.to_lowercase();
+
+        let template = self
+            .jobs
+            .recent_template(job_id)
+            .ok_or_else(|| anyhow!("Block expired"))?;
+        if nonce.len() != NONCE_SIZE * 2
+            || hex::decode(&nonce).is_err()
+            || !nonce.ends_with(&format!("{:02x}", self.nonce_prefix))
+        {
+            bail!("Invalid nonce");
+        }
+        if !self
+            .submitted_shares
+            .insert((job_id.to_string(), nonce.clone()))
+        {
+            bail!("Duplicate share");
+        }
+        let hash = self
+            .jobs
+            .hasher
+            .hash(template.seed.clone(), template.hashing_blob(&nonce)?)
+            .await
+            .map(hex::encode)?;
+        if hash != result {
+            bail!("Invalid result");
+        }
+        let difficulty = hash_difficulty(&hash).ok_or_else(|| anyhow!("Invalid result"))?;
+        let share_difficulty = template.share_difficulty();
+        if difficulty < share_difficulty {
+            bail!("Low difficulty share");
+        }
+        if difficulty >= template.difficulty {
+            self.jobs.submit_block(&template, &nonce, worker).await;
+        }
+        Ok(share_difficulty)
+    }
+
+    fn forget_expired_shares(&mut self) {
+        let jobs = self.jobs.clone();
+        self.submitted_shares
+            .retain(|(job_id, _)| jobs.recent_template(job_id).is_some());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    fn template(blob: &str) -> BlockTemplate {
+        BlockTemplate {
+            job_id: "1".to_string(),
+            blocktemplate_blob: blob.to_string(),
+            blockhashing_blob: blob.to_string(),
+            difficulty: 100_000,
+            height: 3_000_000,
+            seed_hash: "00".repeat(32),
+            seed: vec![0; 32],
+        }
+    }
+
+    #[test]
+    fn gives_every_miner_its_own_nonce_prefix() {
+        let template = template(&"00".repeat(76));
+        let job = template.job(0xab).unwrap();
+        let blob = job["blob"].as_str().unwrap();
+        assert_eq!(&blob[84..86], "ab");
+        assert_eq!(blob.len(), 152);
+        assert_eq!(job["target"], target_from_difficulty(SHARE_DIFFICULTY));
+    }
+
+    #[test]
+    fn puts_the_nonce_into_the_block() {
+        let template = template(&"00".repeat(76));
+        let block_blob = template.block_blob("01020304").unwrap();
+        assert_eq!(&block_blob[76..88], "000102030400");
+        assert_eq!(
+            template.hashing_blob("01020304").unwrap()[NONCE_OFFSET..NONCE_OFFSET + NONCE_SIZE],
+            [1, 2, 3, 4]
+        );
+    }
+
+    #[test]
+    fn rejects_short_blobs_instead_of_panicking() {
+        let template = template(&"00".repeat(NONCE_OFFSET));
+        assert!(template.job(1).is_err());
+        assert!(template.block_blob("01020304").is_err());
+        assert!(template.hashing_blob("01020304").is_err());
+    }
+
+    #[test]
+    fn credits_at_most_the_network_difficulty() {
+        let mut template = template(&"00".repeat(76));
+        assert_eq!(template.share_difficulty(), SHARE_DIFFICULTY);
+        template.difficulty = 1_000;
+        assert_eq!(template.share_difficulty(), 1_000);
+    }
+}
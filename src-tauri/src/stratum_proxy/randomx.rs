@@ -0,0 +1,111 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::sync::mpsc;
+use std::thread;
+
+use anyhow::anyhow;
+use log::warn;
+use randomx_rs::{RandomXCache, RandomXFlag, RandomXVM};
+use tokio::sync::oneshot;
+
+const LOG_TARGET: &str = "tari::universe::stratum_proxy::randomx";
+
+struct HashRequest {
+    seed: Vec<u8>,
+    input: Vec<u8>,
+    reply: oneshot::Sender<Result<Vec<u8>, anyhow::Error>>,
+}
+
+/// Light mode RandomX hasher for checking the shares LAN miners submit.
+/// The VM can't move between threads, so it lives on its own thread and is rebuilt when the seed changes.
+pub(super) struct RandomXHasher {
+    requests: mpsc::Sender<HashRequest>,
+}
+
+impl RandomXHasher {
+    pub fn new() -> Result<Self, anyhow::Error> {
+        let (requests, requests_rx) = mpsc::channel();
+        thread::Builder::new()
+            .name("stratum-proxy-randomx".to_string())
+            .spawn(move || hash_requests(requests_rx))?;
+        Ok(Self { requests })
+    }
+
+    pub async fn hash(&self, seed: Vec<u8>, input: Vec<u8>) -> Result<Vec<u8>, anyhow::Error> {
+        let (reply, reply_rx) = oneshot::channel();
+        self.requests
+            .send(HashRequest { seed, input, reply })
+            .map_err(|_| anyhow!("RandomX hasher stopped"))?;
+        reply_rx
+            .await
+            .map_err(|_| anyhow!("RandomX hasher stopped"))?
+    }
+}
+
+/// Runs until every `RandomXHasher` handle is dropped
+fn hash_requests(requests: mpsc::Receiver<HashRequest>) {
+    let mut vm: Option<(Vec<u8>, RandomXVM)> = None;
+    for request in requests {
+        if vm.as_ref().is_none_or(|(seed, _)| *seed != request.seed) {
+            vm = None;
+            match new_vm(&request.seed) {
+                Ok(new_vm) => vm = Some((request.seed.clone(), new_vm)),
+                Err(e) => {
+                    warn!(target: LOG_TARGET, "Could not initialize RandomX: {:?}", e);
+                    let _unused = request.reply.send(Err(e));
+                    continue;
+                }
+            }
+        }
+        let result = vm
+            .as_ref()
+            .ok_or_else(|| anyhow!("RandomX is not initialized"))
+            .and_then(|(_, vm)| Ok(vm.calculate_hash(&request.input)?));
+        let _unused = request.reply.send(result);
+    }
+}
+
+fn new_vm(seed: &[u8]) -> Result<RandomXVM, anyhow::Error> {
+    let flags = RandomXFlag::get_recommended_flags();
+    let cache = RandomXCache::new(flags, seed)?;
+    Ok(RandomXVM::new(flags, Some(cache), None)?)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[tokio::test]
+    async fn hashes_the_reference_vector() {
+        let hasher = RandomXHasher::new().unwrap();
+        let hash = hasher
+            .hash(b"test key 000".to_vec(), b"This is a test".to_vec())
+            .await
+            .unwrap();
+        assert_eq!(
+            hex::encode(hash),
+            "639183aae1bf4c9a35884cb46b09cad9175f04efd7684e7262a0ac1c2f0b4e3f"
+        );
+    }
+}
@@ -0,0 +1,268 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::collections::HashSet;
+use std::net::SocketAddr;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use serde_json::Value;
+use tari_shutdown::ShutdownSignal;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::TcpStream;
+use tokio::select;
+use tokio::time::timeout;
+
+use super::models::{difficulty_from_target, error_response, is_authorized, worker_name};
+use super::StratumProxy;
+
+const POOL_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Share accounting for one miner relayed to the pool
+struct RelaySession {
+    peer_address: String,
+    login: String,
+    password: Option<String>,
+    worker: Option<String>,
+    pending_submits: HashSet<String>,
+    share_difficulty: u64,
+}
+
+impl RelaySession {
+    /// Swaps the miner's login for ours, so the whole farm mines to this wallet.
+    /// Returns the line to forward to the pool, or the error to answer the miner with
+    async fn from_miner(&mut self, line: String) -> Result<String, String> {
+        let Ok(mut request) = serde_json::from_str::<Value>(&line) else {
+            return Ok(line);
+        };
+        match request.get("method").and_then(Value::as_str) {
+            Some("login") => {
+                let id = request.get("id").cloned().unwrap_or(Value::Null);
+                let Some(params) = request.get_mut("params").and_then(Value::as_object_mut) else {
+                    return Ok(line);
+                };
+                let login_params = Value::Object(params.clone());
+                if !is_authorized(&login_params, self.password.as_deref()) {
+                    return Err(error_response(&id, "Unauthorized"));
+                }
+                let name = worker_name(&login_params, &self.peer_address, self.password.is_some());
+                params.insert("login".to_string(), Value::String(self.login.clone()));
+                if self.password.is_some() {
+                    // The proxy password is none of the pool's business
+                    params.insert("pass".to_string(), Value::String("x".to_string()));
+                }
+                StratumProxy::current()
+                    .worker_connected(&name, &self.peer_address)
+                    .await;
+                self.worker = Some(name);
+                Ok(serde_json::to_string(&request).unwrap_or(line))
+            }
+            Some("submit") => {
+                if let Some(id) = request.get("id") {
+                    self.pending_submits.insert(id.to_string());
+                }
+                Ok(line)
+            }
+            _ => Ok(line),
+        }
+    }
+
+    async fn from_pool(&mut self, line: &str) {
+        let Ok(message) = serde_json::from_str::<Value>(line) else {
+            return;
+        };
+        if let Some(difficulty) = message
+            .pointer("/result/job/target")
+            .or_else(|| message.pointer("/params/target"))
+            .and_then(Value::as_str)
+            .and_then(difficulty_from_target)
+        {
+            self.share_difficulty = difficulty;
+        }
+
+        let Some(id) = message.get("id").map(Value::to_string) else {
+            return;
+        };
+        if !self.pending_submits.remove(&id) {
+            return;
+        }
+        let Some(worker) = self.worker.as_ref() else {
+            return;
+        };
+        let has_error = message.get("error").is_some_and(|error| !error.is_null());
+        let is_accepted =
+            !has_error && message.pointer("/result/status").and_then(Value::as_str) == Some("OK");
+        StratumProxy::current()
+            .record_share(worker, is_accepted, self.share_difficulty)
+            .await;
+    }
+}
+
+/// Forwards a miner to the pool line by line, keeping count of the shares the pool accepts
+pub(super) async fn relay_session(
+    miner: TcpStream,
+    peer: SocketAddr,
+    pool_address: String,
+    login: String,
+    password: Option<String>,
+    mut shutdown_signal: ShutdownSignal,
+) -> Result<(), anyhow::Error> {
+    let pool = timeout(POOL_CONNECT_TIMEOUT, TcpStream::connect(&pool_address))
+        .await
+        .map_err(|_| anyhow!("Timed out connecting to pool {}", pool_address))??;
+    let (miner_reader, mut miner_writer) = miner.into_split();
+    let (pool_reader, mut pool_writer) = pool.into_split();
+    let mut miner_lines = BufReader::new(miner_reader).lines();
+    let mut pool_lines = BufReader::new(pool_reader).lines();
+    let mut session = RelaySession {
+        peer_address: peer.ip().to_string(),
+        login,
+        password,
+        worker: None,
+        pending_submits: HashSet::new(),
+        share_difficulty: 0,
+    };
+
+    let result: Result<(), anyhow::Error> = async {
+        loop {
+            select! {
+                line = miner_lines.next_line() => {
+                    let Some(line) = line? else {
+                        return Ok(());
+                    };
+                    match session.from_miner(line).await {
+                        Ok(line) => pool_writer.write_all(format!("{line}\n").as_bytes()).await?,
+                        Err(reply) => miner_writer.write_all(format!("{reply}\n").as_bytes()).await?,
+                    }
+                }
+                line = pool_lines.next_line() => {
+                    let Some(line) = line? else {
+                        return Err(anyhow!("Pool {} closed the connection", pool_address));
+                    };
+                    session.from_pool(&line).await;
+                    miner_writer.write_all(format!("{line}\n").as_bytes()).await?;
+                }
+                _ = shutdown_signal.wait() => return Ok(()),
+            }
+        }
+    }
+    .await;
+
+    if let Some(worker) = session.worker {
+        StratumProxy::current().worker_disconnected(&worker).await;
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use serde_json::json;
+
+    fn session(password: Option<&str>) -> RelaySession {
+        RelaySession {
+            peer_address: "10.0.0.2".to_string(),
+            login: "proxy-wallet".to_string(),
+            password: password.map(str::to_string),
+            worker: None,
+            pending_submits: HashSet::new(),
+            share_difficulty: 0,
+        }
+    }
+
+    fn login(pass: &str, rigid: &str) -> String {
+        json!({
+            "id": 1,
+            "method": "login",
+            "params": { "login": "rig-wallet", "pass": pass, "rigid": rigid },
+        })
+        .to_string()
+    }
+
+    fn submit(id: u64) -> String {
+        json!({ "id": id, "method": "submit", "params": {} }).to_string()
+    }
+
+    #[tokio::test]
+    async fn forwards_logins_with_the_proxy_wallet() {
+        let mut session = session(Some("secret"));
+        let forwarded: Value = serde_json::from_str(
+            &session
+                .from_miner(login("secret", "relay-rig"))
+                .await
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(forwarded["params"]["login"], "proxy-wallet");
+        assert_eq!(forwarded["params"]["pass"], "x");
+        assert_eq!(session.worker.as_deref(), Some("relay-rig"));
+    }
+
+    #[tokio::test]
+    async fn answers_logins_with_the_wrong_password_itself() {
+        let mut session = session(Some("secret"));
+        let reply: Value = serde_json::from_str(
+            &session
+                .from_miner(login("guess", "relay-rig"))
+                .await
+                .unwrap_err(),
+        )
+        .unwrap();
+        assert_eq!(reply["id"], 1);
+        assert_eq!(reply["error"]["message"], "Unauthorized");
+        assert_eq!(session.worker, None);
+    }
+
+    #[tokio::test]
+    async fn counts_the_shares_the_pool_accepts() {
+        let mut session = session(None);
+        session
+            .from_miner(login("x", "relay-counted-rig"))
+            .await
+            .unwrap();
+        session
+            .from_pool(
+                &json!({ "id": 1, "result": { "job": { "target": "b88d0600" } } }).to_string(),
+            )
+            .await;
+        session.from_miner(submit(2)).await.unwrap();
+        session
+            .from_pool(&json!({ "id": 2, "error": null, "result": { "status": "OK" } }).to_string())
+            .await;
+        session.from_miner(submit(3)).await.unwrap();
+        session
+            .from_pool(
+                &json!({ "id": 3, "error": { "message": "Low difficulty share" } }).to_string(),
+            )
+            .await;
+
+        let workers = StratumProxy::current().workers().await;
+        let worker = workers
+            .iter()
+            .find(|worker| worker.name == "relay-counted-rig")
+            .unwrap();
+        assert_eq!(worker.accepted_shares, 1);
+        assert_eq!(worker.rejected_shares, 1);
+        assert_eq!(worker.session_difficulty, 10_000);
+    }
+}
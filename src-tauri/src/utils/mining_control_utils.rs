@@ -0,0 +1,149 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use log::{error, info, warn};
+use tauri::{AppHandle, Manager};
+
+use crate::{
+    battery_policy::BatteryPolicy,
+    commands,
+    configs::{
+        config_mining::{ConfigMining, ConfigMiningContent, MiningMode},
+        config_wallet::ConfigWallet,
+        trait_config::ConfigImpl,
+    },
+    events_emitter::EventsEmitter,
+    hardware::thermal_governor::ThermalGovernor,
+    UniverseAppState,
+};
+
+const LOG_TARGET: &str = "tari::universe::mining_control_utils";
+
+pub async fn is_cpu_mining(app_handle: &AppHandle) -> bool {
+    let state = app_handle.state::<UniverseAppState>();
+    let cpu_miner = state.cpu_miner.read().await;
+    cpu_miner.is_running().await
+}
+
+pub async fn is_gpu_mining(app_handle: &AppHandle) -> bool {
+    let state = app_handle.state::<UniverseAppState>();
+    let gpu_miner = state.gpu_miner.read().await;
+    gpu_miner.is_running().await
+}
+
+/// Returns whether the CPU miner was started, nothing is started while the battery policy
+/// or the thermal governor holds it back
+pub async fn start_cpu_mining(app_handle: &AppHandle) -> bool {
+    if BatteryPolicy::is_cpu_mining_held() {
+        info!(target: LOG_TARGET, "Not starting CPU mining, it is held by the battery policy");
+        return false;
+    }
+    if ThermalGovernor::current().is_cpu_paused().await {
+        info!(target: LOG_TARGET, "Not starting CPU mining, it is paused by the thermal governor");
+        return false;
+    }
+    let state = app_handle.state::<UniverseAppState>();
+    commands::start_cpu_mining(state, app_handle.clone())
+        .await
+        .inspect_err(|e| error!(target: LOG_TARGET, "Could not start CPU mining: {}", e))
+        .is_ok()
+}
+
+/// Returns whether the GPU miner was started, nothing is started while the battery policy
+/// or the thermal governor holds it back
+pub async fn start_gpu_mining(app_handle: &AppHandle) -> bool {
+    if BatteryPolicy::is_gpu_mining_held() {
+        info!(target: LOG_TARGET, "Not starting GPU mining, it is held by the battery policy");
+        return false;
+    }
+    if ThermalGovernor::current().is_gpu_paused().await {
+        info!(target: LOG_TARGET, "Not starting GPU mining, it is paused by the thermal governor");
+        return false;
+    }
+    let state = app_handle.state::<UniverseAppState>();
+    commands::start_gpu_mining(state, app_handle.clone())
+        .await
+        .inspect_err(|e| error!(target: LOG_TARGET, "Could not start GPU mining: {}", e))
+        .is_ok()
+}
+
+pub async fn stop_cpu_mining(app_handle: &AppHandle) {
+    let state = app_handle.state::<UniverseAppState>();
+    if let Err(e) = commands::stop_cpu_mining(state).await {
+        error!(target: LOG_TARGET, "Could not stop CPU mining: {}", e);
+    }
+}
+
+pub async fn stop_gpu_mining(app_handle: &AppHandle) {
+    let state = app_handle.state::<UniverseAppState>();
+    if let Err(e) = commands::stop_gpu_mining(state).await {
+        error!(target: LOG_TARGET, "Could not stop GPU mining: {}", e);
+    }
+}
+
+/// Applies the current mining config to a running CPU miner. xmrig is reconfigured live when possible
+/// so it keeps its RandomX dataset, otherwise it is restarted.
+pub async fn reconfigure_cpu_mining(app_handle: &AppHandle) {
+    let state = app_handle.state::<UniverseAppState>();
+    let result = {
+        let _lock = state.cpu_miner_stop_start_mutex.lock().await;
+        let mining_config = ConfigMining::content().await;
+        let tari_address = ConfigWallet::content()
+            .await
+            .get_current_used_tari_address();
+        let cpu_miner_config = state.cpu_miner_config.read().await;
+        let mut cpu_miner = state.cpu_miner.write().await;
+        if !cpu_miner.is_running().await {
+            return;
+        }
+        cpu_miner
+            .reconfigure(
+                &cpu_miner_config,
+                &state.mm_proxy_manager,
+                *mining_config.mode(),
+                mining_config.max_cpu_usage(),
+                &tari_address,
+            )
+            .await
+    };
+
+    match result {
+        Ok(true) => return,
+        Ok(false) => info!(target: LOG_TARGET, "CPU miner changes need a restart"),
+        Err(e) => {
+            warn!(target: LOG_TARGET, "Could not reconfigure CPU miner, restarting it: {}", e)
+        }
+    }
+    stop_cpu_mining(app_handle).await;
+    start_cpu_mining(app_handle).await;
+}
+
+/// The CPU miner is reconfigured in place, a running GPU miner is stopped because glytex only picks up
+/// the mode when it is started. Starting it again is left to the caller.
+pub async fn switch_mining_mode(app_handle: &AppHandle, mode: MiningMode) {
+    stop_gpu_mining(app_handle).await;
+    match ConfigMining::update_field(ConfigMiningContent::set_mode, mode).await {
+        Ok(()) => EventsEmitter::emit_mining_config_loaded(ConfigMining::content().await).await,
+        Err(e) => error!(target: LOG_TARGET, "Could not switch mining mode: {:?}", e),
+    }
+    reconfigure_cpu_mining(app_handle).await;
+}
@@ -28,8 +28,10 @@ pub mod locks_utils;
 pub mod logging_utils;
 pub mod macos_utils;
 pub mod math_utils;
+pub mod mining_control_utils;
 pub mod network_status;
 pub mod platform_utils;
+pub mod pool_url_utils;
 pub mod wallet_utils;
 
 pub mod system_status;
@@ -0,0 +1,125 @@
+// Copyright 2025. The Tari Project
+//
+// Redistribution and use in source and binary forms, with or without modification, are permitted provided that the
+// following conditions are met:
+//
+// 1. Redistributions of source code must retain the above copyright notice, this list of conditions and the following
+// disclaimer.
+//
+// 2. Redistributions in binary form must reproduce the above copyright notice, this list of conditions and the
+// following disclaimer in the documentation and/or other materials provided with the distribution.
+//
+// 3. Neither the name of the copyright holder nor the names of its contributors may be used to endorse or promote
+// products derived from this software without specific prior written permission.
+//
+// THIS SOFTWARE IS PROVIDED BY THE COPYRIGHT HOLDERS AND CONTRIBUTORS "AS IS" AND ANY EXPRESS OR IMPLIED WARRANTIES,
+// INCLUDING, BUT NOT LIMITED TO, THE IMPLIED WARRANTIES OF MERCHANTABILITY AND FITNESS FOR A PARTICULAR PURPOSE ARE
+// DISCLAIMED. IN NO EVENT SHALL THE COPYRIGHT HOLDER OR CONTRIBUTORS BE LIABLE FOR ANY DIRECT, INDIRECT, INCIDENTAL,
+// SPECIAL, EXEMPLARY, OR CONSEQUENTIAL DAMAGES (INCLUDING, BUT NOT LIMITED TO, PROCUREMENT OF SUBSTITUTE GOODS OR
+// SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
+// WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
+// USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
+
+use std::fmt::{self, Display};
+
+use anyhow::{anyhow, bail};
+
+/// Schemes that mean the pool only talks TLS
+const TLS_SCHEMES: [&str; 4] = ["stratum+ssl", "stratum+tls", "ssl", "tls"];
+
+/// Pool address parsed from `host:port` or a full stratum URL such as `stratum+ssl://[2001:db8::1]:443`
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoolUrl {
+    /// IPv6 hosts are kept without brackets
+    pub host: String,
+    pub port: u16,
+    pub tls: bool,
+}
+
+impl PoolUrl {
+    pub fn parse(url: &str) -> Result<Self, anyhow::Error> {
+        let url = url.trim();
+        let (scheme, rest) = match url.split_once("://") {
+            Some((scheme, rest)) => (Some(scheme.to_lowercase()), rest),
+            None => (None, url),
+        };
+        // Anything after the authority, such as a trailing slash, is ignored
+        let authority = rest.split('/').next().unwrap_or(rest);
+
+        let (host, port) = if let Some(bracketed) = authority.strip_prefix('[') {
+            let (host, port) = bracketed
+                .split_once(']')
+                .ok_or_else(|| anyhow!("Unterminated IPv6 address in pool URL: {}", url))?;
+            let port = port
+                .strip_prefix(':')
+                .ok_or_else(|| anyhow!("Missing port in pool URL: {}", url))?;
+            (host, port)
+        } else {
+            let (host, port) = authority
+                .rsplit_once(':')
+                .ok_or_else(|| anyhow!("Missing port in pool URL: {}", url))?;
+            if host.contains(':') {
+                bail!("IPv6 addresses must be in brackets in pool URL: {}", url);
+            }
+            (host, port)
+        };
+        if host.is_empty() {
+            bail!("Missing host in pool URL: {}", url);
+        }
+        let port = port
+            .parse::<u16>()
+            .map_err(|_| anyhow!("Invalid port number in pool URL: {}", url))?;
+
+        Ok(Self {
+            host: host.to_string(),
+            port,
+            tls: scheme.is_some_and(|scheme| TLS_SCHEMES.contains(&scheme.as_str())),
+        })
+    }
+}
+
+impl Display for PoolUrl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", pool_address(&self.host, self.port))
+    }
+}
+
+/// `host:port`, with IPv6 hosts in brackets so the result can be handed to xmrig or a socket
+pub fn pool_address(host: &str, port: u16) -> String {
+    if host.contains(':') {
+        format!("[{host}]:{port}")
+    } else {
+        format!("{host}:{port}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn parses_pool_urls() {
+        let url = PoolUrl::parse("pool-global.tari.snipanet.com:3333").unwrap();
+        assert_eq!(url.host, "pool-global.tari.snipanet.com");
+        assert_eq!(url.port, 3333);
+        assert!(!url.tls);
+
+        let url = PoolUrl::parse("stratum+ssl://pool.example.com:443/").unwrap();
+        assert_eq!(url.host, "pool.example.com");
+        assert_eq!(url.port, 443);
+        assert!(url.tls);
+
+        let url = PoolUrl::parse("stratum+tcp://[2001:db8::1]:3333").unwrap();
+        assert_eq!(url.host, "2001:db8::1");
+        assert_eq!(url.port, 3333);
+        assert!(!url.tls);
+        assert_eq!(url.to_string(), "[2001:db8::1]:3333");
+
+        assert!(PoolUrl::parse("2001:db8::1:3333").is_err());
+        assert!(PoolUrl::parse("stratum+tcp://pool.example.com").is_err());
+        assert!(PoolUrl::parse("pool.example.com:port").is_err());
+        assert!(PoolUrl::parse(":3333").is_err());
+    }
+}
@@ -21,28 +21,46 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use std::sync::LazyLock;
+use std::time::Duration;
 
 use anyhow::Error;
 use log::{error, info};
 use psp::monitor::{PowerMonitor, PowerState};
+use serde::Serialize;
+use tokio::select;
 use tokio::sync::watch;
 
+use crate::tasks_tracker::TasksTrackers;
+
 const LOG_TARGET: &str = "tari::universe::external_dependencies";
+const POWER_SOURCE_CHECK_INTERVAL: Duration = Duration::from_secs(30);
 
 static INSTANCE: LazyLock<SystemStatus> = LazyLock::new(SystemStatus::new);
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct PowerSourceStatus {
+    pub is_on_battery: bool,
+    pub battery_percentage: Option<u8>,
+}
+
 pub struct SystemStatus {
     sleep_mode_watcher_sender: watch::Sender<bool>,
     sleep_mode_watcher_receiver: watch::Receiver<bool>,
+    power_source_watcher_sender: watch::Sender<PowerSourceStatus>,
+    power_source_watcher_receiver: watch::Receiver<PowerSourceStatus>,
 }
 
 impl SystemStatus {
     fn new() -> Self {
         let (sleep_mode_watcher_sender, sleep_mode_watcher_receiver) = watch::channel(false);
+        let (power_source_watcher_sender, power_source_watcher_receiver) =
+            watch::channel(PowerSourceStatus::default());
 
         Self {
             sleep_mode_watcher_sender,
             sleep_mode_watcher_receiver,
+            power_source_watcher_sender,
+            power_source_watcher_receiver,
         }
     }
 
@@ -81,7 +99,164 @@ impl SystemStatus {
         self.sleep_mode_watcher_receiver.clone()
     }
 
+    pub fn get_power_source_watcher(&self) -> watch::Receiver<PowerSourceStatus> {
+        self.power_source_watcher_receiver.clone()
+    }
+
+    /// The power monitor only reports sleep and wake events, so the power source is polled instead.
+    /// Only Linux is read for now, other platforms always report running on AC.
+    pub async fn start_power_source_watcher() {
+        let mut shutdown_signal = TasksTrackers::current().common.get_signal().await;
+        TasksTrackers::current()
+            .common
+            .get_task_tracker()
+            .await
+            .spawn(async move {
+                let mut interval = tokio::time::interval(POWER_SOURCE_CHECK_INTERVAL);
+                loop {
+                    select! {
+                        _ = interval.tick() => {
+                            let Some(status) = read_power_source_status() else {
+                                continue;
+                            };
+                            SystemStatus::current().power_source_watcher_sender.send_if_modified(|current| {
+                                if *current == status {
+                                    return false;
+                                }
+                                info!(target: LOG_TARGET, "Power source changed: {:?}", status);
+                                *current = status;
+                                true
+                            });
+                        }
+                        _ = shutdown_signal.wait() => {
+                            info!(target: LOG_TARGET, "Shutdown signal received, stopping power source watcher");
+                            break;
+                        }
+                    }
+                }
+            });
+    }
+
     pub fn current() -> &'static SystemStatus {
         &INSTANCE
     }
 }
+
+#[cfg(target_os = "linux")]
+fn read_power_source_status() -> Option<PowerSourceStatus> {
+    read_power_supply(std::path::Path::new("/sys/class/power_supply"))
+}
+
+#[cfg(not(target_os = "linux"))]
+fn read_power_source_status() -> Option<PowerSourceStatus> {
+    None
+}
+
+/// Reads the power supplies exposed by the kernel. Returns `None` when there is no system battery.
+#[cfg(target_os = "linux")]
+fn read_power_supply(dir: &std::path::Path) -> Option<PowerSourceStatus> {
+    let read_value = |path: std::path::PathBuf| {
+        std::fs::read_to_string(path)
+            .ok()
+            .map(|value| value.trim().to_string())
+    };
+
+    let mut is_ac_online: Option<bool> = None;
+    let mut is_discharging = false;
+    let mut battery_percentage: Option<u8> = None;
+    let mut has_battery = false;
+    for entry in std::fs::read_dir(dir).ok()?.flatten() {
+        let path = entry.path();
+        match read_value(path.join("type")).as_deref() {
+            // USB-C chargers show up as USB supplies on most laptops
+            Some("Mains") | Some("USB") => {
+                let is_online = read_value(path.join("online")).as_deref() == Some("1");
+                is_ac_online = Some(is_ac_online.unwrap_or(false) || is_online);
+            }
+            Some("Battery") => {
+                // Batteries of wireless mice, keyboards and the like are device scoped
+                if read_value(path.join("scope")).as_deref() == Some("Device") {
+                    continue;
+                }
+                has_battery = true;
+                is_discharging |= read_value(path.join("status")).as_deref() == Some("Discharging");
+                if let Some(capacity) =
+                    read_value(path.join("capacity")).and_then(|value| value.parse::<u8>().ok())
+                {
+                    battery_percentage =
+                        Some(battery_percentage.map_or(capacity, |current| current.min(capacity)));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if !has_battery {
+        return None;
+    }
+    Some(PowerSourceStatus {
+        is_on_battery: is_ac_online.map_or(is_discharging, |is_online| !is_online),
+        battery_percentage,
+    })
+}
+
+#[cfg(all(test, target_os = "linux"))]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+    use std::fs;
+    use std::path::Path;
+
+    fn write_supply(dir: &Path, name: &str, values: &[(&str, &str)]) {
+        let supply_dir = dir.join(name);
+        fs::create_dir_all(&supply_dir).unwrap();
+        for (file, value) in values {
+            fs::write(supply_dir.join(file), format!("{}\n", value)).unwrap();
+        }
+    }
+
+    #[test]
+    fn reads_battery_and_ac_state() {
+        let dir = std::env::temp_dir().join(format!("power_supply_test_{}", std::process::id()));
+        let _unused = fs::remove_dir_all(&dir);
+        write_supply(&dir, "AC", &[("type", "Mains"), ("online", "0")]);
+        write_supply(
+            &dir,
+            "BAT0",
+            &[
+                ("type", "Battery"),
+                ("status", "Discharging"),
+                ("capacity", "42"),
+            ],
+        );
+        write_supply(
+            &dir,
+            "hidpp_battery_0",
+            &[("type", "Battery"), ("scope", "Device"), ("capacity", "5")],
+        );
+
+        assert_eq!(
+            read_power_supply(&dir),
+            Some(PowerSourceStatus {
+                is_on_battery: true,
+                battery_percentage: Some(42),
+            })
+        );
+
+        write_supply(&dir, "AC", &[("online", "1")]);
+        assert!(!read_power_supply(&dir).unwrap().is_on_battery);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn desktop_without_battery_reports_nothing() {
+        let dir = std::env::temp_dir().join(format!("power_supply_desktop_{}", std::process::id()));
+        let _unused = fs::remove_dir_all(&dir);
+        write_supply(&dir, "AC", &[("type", "Mains"), ("online", "1")]);
+
+        assert_eq!(read_power_supply(&dir), None);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
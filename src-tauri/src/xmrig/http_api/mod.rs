@@ -21,15 +21,25 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 pub mod models;
+use anyhow::anyhow;
 use log::{debug, error};
+use serde_json::{json, Value};
 const LOG_TARGET: &str = "tari::universe::xmrig::http_api";
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct XmrigHttpApiClient {
     url: String,
     access_token: String,
 }
 
+impl std::fmt::Debug for XmrigHttpApiClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("XmrigHttpApiClient")
+            .field("url", &self.url)
+            .finish_non_exhaustive()
+    }
+}
+
 impl XmrigHttpApiClient {
     pub fn new(url: String, access_token: String) -> Self {
         Self { url, access_token }
@@ -44,6 +54,61 @@ impl XmrigHttpApiClient {
             .await
     }
 
+    /// Write endpoints need xmrig to run with `--http-no-restricted`
+    async fn send_json(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: &Value,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        let url = format!("{}/{}", self.url, path);
+        reqwest::Client::new()
+            .request(method, &url)
+            .header("Authorization", format!("Bearer {}", self.access_token))
+            .json(body)
+            .send()
+            .await?
+            .error_for_status()
+    }
+
+    async fn json_rpc(&self, method: &str) -> Result<(), anyhow::Error> {
+        let body = json!({ "jsonrpc": "2.0", "id": 1, "method": method });
+        let response: Value = self
+            .send_json(reqwest::Method::POST, "json_rpc", &body)
+            .await?
+            .json()
+            .await?;
+        match response.get("error") {
+            Some(error) if !error.is_null() => Err(anyhow!("xmrig {} failed: {}", method, error)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Stops hashing without unloading the RandomX dataset
+    pub async fn pause(&self) -> Result<(), anyhow::Error> {
+        self.json_rpc("pause").await
+    }
+
+    pub async fn resume(&self) -> Result<(), anyhow::Error> {
+        self.json_rpc("resume").await
+    }
+
+    pub async fn config(&self) -> Result<Value, anyhow::Error> {
+        Ok(self
+            .get("1/config")
+            .await?
+            .error_for_status()?
+            .json()
+            .await?)
+    }
+
+    /// xmrig reloads the backends and pools from the new config while keeping the process alive
+    pub async fn update_config(&self, config: &Value) -> Result<(), anyhow::Error> {
+        self.send_json(reqwest::Method::PUT, "1/config", config)
+            .await?;
+        Ok(())
+    }
+
     pub async fn summary(&self) -> Result<models::Summary, anyhow::Error> {
         for _i in 0..3 {
             let response = self.get("2/summary").await?;
@@ -64,4 +129,9 @@ impl XmrigHttpApiClient {
         }
         Err(anyhow::anyhow!("Failed to get xmrig summary"))
     }
+
+    pub async fn backends(&self) -> Result<Vec<models::Backend>, anyhow::Error> {
+        let response = self.get("2/backends").await?.error_for_status()?;
+        Ok(response.json().await?)
+    }
 }
@@ -20,39 +20,195 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Deserialize, Debug, Clone)]
 pub(crate) struct Summary {
+    #[serde(default)]
+    pub(crate) resources: Resources,
+    #[serde(default)]
+    pub(crate) results: Results,
     pub(crate) connection: Connection,
-
+    #[serde(default)]
+    pub(crate) cpu: Cpu,
     pub(crate) hashrate: Hashrate,
-    // hugepages: bool,
+    #[serde(default)]
+    pub(crate) hugepages: Hugepages,
+    #[serde(default)]
+    pub(crate) paused: bool,
 }
 
-#[derive(Deserialize, Debug)]
-pub struct Resources {}
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Resources {
+    pub(crate) memory: Memory,
+}
 
-#[derive(Deserialize, Debug)]
-pub struct Memory {}
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Memory {
+    pub(crate) free: u64,
+    pub(crate) total: u64,
+    pub(crate) resident_set_memory: u64,
+}
 
-#[derive(Deserialize, Debug)]
+#[derive(Deserialize, Debug, Clone, Default)]
+#[serde(default)]
 pub struct Results {
+    pub(crate) hashes_total: u64,
     // Sometimes this is not present in v6.21.0
-    // error_log: Vec<String>,
+    pub(crate) error_log: Vec<Value>,
 }
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct Connection {
     pub(crate) uptime: u64,
+    #[serde(default)]
+    pub(crate) ping: u64,
+    #[serde(default)]
+    pub(crate) failures: u64,
+    #[serde(default)]
+    pub(crate) tls: Option<String>,
+    #[serde(default)]
+    pub(crate) accepted: u64,
+    #[serde(default)]
+    pub(crate) rejected: u64,
     // Sometimes doesn't exist
-    // pub(crate) error_log: Vec<String>,
+    #[serde(default)]
+    pub(crate) error_log: Vec<Value>,
 }
 
-#[derive(Deserialize, Debug)]
-pub struct Cpu {}
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct Cpu {
+    pub(crate) brand: String,
+    pub(crate) aes: bool,
+    pub(crate) avx2: bool,
+    pub(crate) x64: bool,
+    pub(crate) l2: u64,
+    pub(crate) l3: u64,
+    pub(crate) cores: u32,
+    pub(crate) threads: u32,
+    pub(crate) packages: u32,
+    pub(crate) nodes: u32,
+    pub(crate) backend: Option<String>,
+    pub(crate) msr: Option<String>,
+    pub(crate) assembly: Option<String>,
+}
 
 #[derive(Deserialize, Debug, Clone)]
 pub struct Hashrate {
     pub(crate) total: Vec<Option<f64>>,
+    #[serde(default)]
+    pub(crate) highest: Option<f64>,
+    /// 10s/60s/15m hashrate of every mining thread
+    #[serde(default)]
+    pub(crate) threads: Vec<Vec<Option<f64>>>,
+}
+
+/// xmrig reports `false` when huge pages are disabled and `[allocated, total]` otherwise
+#[derive(Deserialize, Debug, Clone)]
+#[serde(untagged)]
+pub enum Hugepages {
+    Enabled(bool),
+    Pages([u64; 2]),
+}
+
+impl Default for Hugepages {
+    fn default() -> Self {
+        Self::Enabled(false)
+    }
+}
+
+impl Hugepages {
+    pub fn status(&self) -> HugepagesStatus {
+        match *self {
+            Hugepages::Enabled(enabled) => HugepagesStatus {
+                enabled,
+                allocated: 0,
+                total: 0,
+            },
+            Hugepages::Pages([allocated, total]) => HugepagesStatus {
+                enabled: allocated > 0,
+                allocated,
+                total,
+            },
+        }
+    }
+}
+
+#[derive(Serialize, Debug, Clone, Default)]
+pub struct HugepagesStatus {
+    pub enabled: bool,
+    pub allocated: u64,
+    pub total: u64,
+}
+
+/// Entry of the `2/backends` response, one per cpu/opencl/cuda backend
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct Backend {
+    #[serde(rename = "type")]
+    pub(crate) kind: String,
+    #[serde(default)]
+    pub(crate) algo: Option<String>,
+    #[serde(default)]
+    pub(crate) hugepages: Hugepages,
+    #[serde(default)]
+    pub(crate) threads: Vec<BackendThread>,
+}
+
+#[derive(Deserialize, Debug, Clone)]
+pub(crate) struct BackendThread {
+    #[serde(default)]
+    pub(crate) intensity: u32,
+    #[serde(default)]
+    pub(crate) affinity: i64,
+    #[serde(default)]
+    pub(crate) hashrate: Vec<Option<f64>>,
+}
+
+/// Renders an xmrig error log entry, which is either a plain message or a json object
+pub(crate) fn error_log_message(entry: &Value) -> String {
+    match entry.as_str() {
+        Some(message) => message.to_string(),
+        None => entry.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+    use super::*;
+
+    #[test]
+    fn parses_summary_and_backends() {
+        let summary: Summary = serde_json::from_str(
+            r#"{
+                "resources": {"memory": {"free": 1024, "total": 4096, "resident_set_memory": 512}},
+                "results": {"hashes_total": 100},
+                "connection": {"uptime": 10, "ping": 42, "accepted": 3, "rejected": 1, "error_log": []},
+                "cpu": {"brand": "AMD Ryzen 7", "aes": true, "l3": 33554432, "threads": 16},
+                "hashrate": {"total": [1500.5, null, null], "highest": 1600.0, "threads": [[100.0, null, null]]},
+                "hugepages": [1168, 1168]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(summary.connection.accepted, 3);
+        assert!(summary.cpu.aes);
+        assert_eq!(summary.hugepages.status().allocated, 1168);
+        assert!(summary.results.error_log.is_empty());
+
+        let backends: Vec<Backend> = serde_json::from_str(
+            r#"[
+                {"type": "cpu", "enabled": true, "algo": "rx/0", "hugepages": false,
+                 "threads": [{"intensity": 1, "affinity": 2, "hashrate": [200.0, null, null]}]},
+                {"type": "opencl", "enabled": false}
+            ]"#,
+        )
+        .unwrap();
+        assert_eq!(backends[0].threads[0].affinity, 2);
+        assert!(!backends[0].hugepages.status().enabled);
+        assert!(backends[1].threads.is_empty());
+    }
 }
@@ -20,23 +20,28 @@
 // WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
-use anyhow::Error;
+use anyhow::{anyhow, Error};
 use async_trait::async_trait;
-use log::warn;
-use std::path::PathBuf;
+use log::{debug, warn};
+use serde_json::{json, Value};
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use std::time::Duration;
 use tari_shutdown::Shutdown;
 use tokio::sync::watch;
 
+use crate::configs::config_mining::PoolConnectionOptions;
 use crate::port_allocator::PortAllocator;
 use crate::process_adapter::{
     HealthStatus, ProcessAdapter, ProcessInstance, ProcessStartupSpec, StatusMonitor,
 };
+use crate::utils::pool_url_utils::pool_address;
 use crate::xmrig;
-use crate::xmrig::http_api::models::Summary;
+use crate::xmrig::http_api::models::{Backend, Summary};
 use crate::xmrig::http_api::XmrigHttpApiClient;
 
 const LOG_TARGET: &str = "tari::universe::xmrig_adapter";
+const POOL_CONFIG_FILE_NAME: &str = "pool_config.json";
 
 pub enum XmrigNodeConnection {
     LocalMmproxy {
@@ -48,12 +53,14 @@ pub enum XmrigNodeConnection {
         host_name: String,
         port: u16,
         tari_address: String,
+        options: PoolConnectionOptions,
     },
     MergeMinedPool {
         host_name: String,
         port: u16,
         monero_address: String,
         tari_address: String,
+        options: PoolConnectionOptions,
     },
     Benchmark,
 }
@@ -81,58 +88,205 @@ impl XmrigNodeConnection {
                 host_name,
                 port,
                 tari_address: monero_address,
+                options,
             } => {
-                vec![
+                let mut args = vec![
                     "--url".to_string(),
-                    format!("{}:{}", host_name, port),
+                    pool_address(host_name, *port),
                     "--coin=monero".to_string(),
                     "--user".to_string(),
                     format!("{}", monero_address),
-                ]
+                ];
+                args.extend(Self::pool_option_args(options));
+                args
             }
             XmrigNodeConnection::MergeMinedPool {
                 host_name,
                 port,
                 monero_address,
                 tari_address,
+                options,
             } => {
-                vec![
+                let mut args = vec![
                     "--url".to_string(),
-                    format!("{}:{}", host_name, port),
+                    pool_address(host_name, *port),
                     "--coin=monero".to_string(),
                     "--user".to_string(),
                     format!("{}:{}", monero_address, tari_address),
-                ]
+                ];
+                args.extend(Self::pool_option_args(options));
+                args
             }
             XmrigNodeConnection::Benchmark => {
                 vec!["--benchmark=1m".to_string()]
             }
         }
     }
+
+    /// Applies the connection to a config fetched from xmrig's `1/config`, mirroring `generate_args`
+    pub fn apply_to_config(&self, config: &mut Value) -> Result<(), Error> {
+        let (url, user, daemon, options) = match self {
+            XmrigNodeConnection::LocalMmproxy {
+                host_name,
+                port,
+                monero_address,
+            } => (
+                format!("{}:{}", host_name, port),
+                monero_address.clone(),
+                true,
+                PoolConnectionOptions::default(),
+            ),
+            XmrigNodeConnection::Pool {
+                host_name,
+                port,
+                tari_address,
+                options,
+            } => (
+                pool_address(host_name, *port),
+                tari_address.clone(),
+                false,
+                options.clone(),
+            ),
+            XmrigNodeConnection::MergeMinedPool {
+                host_name,
+                port,
+                monero_address,
+                tari_address,
+                options,
+            } => (
+                pool_address(host_name, *port),
+                format!("{}:{}", monero_address, tari_address),
+                false,
+                options.clone(),
+            ),
+            XmrigNodeConnection::Benchmark => {
+                return Err(anyhow!("Benchmark runs can't be reconfigured"));
+            }
+        };
+
+        let config = config
+            .as_object_mut()
+            .ok_or_else(|| anyhow!("xmrig config is not an object"))?;
+        config.insert(
+            "pools".to_string(),
+            json!([{
+                "url": url,
+                "user": user,
+                "pass": options.password.unwrap_or_else(|| "x".to_string()),
+                "coin": "monero",
+                "daemon": daemon,
+                "tls": options.tls,
+                "tls-fingerprint": options.tls_fingerprint,
+                "rig-id": options.worker_name,
+                "enabled": true,
+            }]),
+        );
+        // We are using a local daemon, so retry as soon as possible
+        config.insert("retry-pause".to_string(), json!(if daemon { 1 } else { 5 }));
+        Ok(())
+    }
+
+    fn password(&self) -> Option<&str> {
+        match self {
+            XmrigNodeConnection::Pool { options, .. }
+            | XmrigNodeConnection::MergeMinedPool { options, .. } => options.password.as_deref(),
+            XmrigNodeConnection::LocalMmproxy { .. } | XmrigNodeConnection::Benchmark => None,
+        }
+    }
+
+    /// The password is left out, it would be readable by every local user in the process list.
+    /// Connections with a password go through `write_pool_config` instead.
+    fn pool_option_args(options: &PoolConnectionOptions) -> Vec<String> {
+        let mut args = vec![];
+        if options.tls {
+            args.push("--tls".to_string());
+        }
+        if let Some(fingerprint) = &options.tls_fingerprint {
+            args.push(format!("--tls-fingerprint={fingerprint}"));
+        }
+        if let Some(worker_name) = &options.worker_name {
+            args.push(format!("--rig-id={worker_name}"));
+        }
+        args
+    }
 }
 
 pub struct XmrigAdapter {
     pub node_connection: Option<XmrigNodeConnection>,
     // pub monero_address: Option<String>,
-    pub http_api_token: String,
     pub http_api_port: u16,
     pub cpu_threads: Option<Option<u32>>,
     pub extra_options: Vec<String>,
     pub summary_broadcast: watch::Sender<Option<Summary>>,
+    pub backends_broadcast: watch::Sender<Vec<Backend>>,
+}
+
+/// Replaces the cpu thread profiles in xmrig's `1/config` the same way `--threads` does,
+/// `None` drops them so xmrig generates profiles for every core again
+pub fn apply_cpu_threads(config: &mut Value, cpu_threads: Option<u32>) -> Result<(), Error> {
+    let cpu = config
+        .get_mut("cpu")
+        .and_then(Value::as_object_mut)
+        .ok_or_else(|| anyhow!("xmrig config has no cpu section"))?;
+    // Profiles are arrays or objects keyed by algorithm, everything else is a backend setting
+    cpu.retain(|_, value| !value.is_array() && !value.is_object());
+    match cpu_threads {
+        Some(threads) => {
+            cpu.insert(
+                "*".to_string(),
+                json!({ "intensity": 1, "threads": threads, "affinity": -1 }),
+            );
+        }
+        None => {
+            cpu.insert("max-threads-hint".to_string(), json!(100));
+        }
+    }
+    Ok(())
+}
+
+/// Writes the pool connection to a config file only the current user can read,
+/// the other options are still passed as arguments and applied on top of it
+fn write_pool_config(
+    node_connection: &XmrigNodeConnection,
+    pool_config_file: &Path,
+) -> Result<(), Error> {
+    let mut config = json!({});
+    node_connection.apply_to_config(&mut config)?;
+    if let Some(parent) = pool_config_file.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut open_options = std::fs::OpenOptions::new();
+    open_options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        open_options.mode(0o600);
+    }
+    let mut file = open_options.open(pool_config_file)?;
+    // The mode above only applies when the file is created, a file from an earlier launch keeps its own
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+    }
+    file.write_all(serde_json::to_string_pretty(&config)?.as_bytes())?;
+    Ok(())
 }
 
 impl XmrigAdapter {
-    pub fn new(summary_broadcast: watch::Sender<Option<Summary>>) -> Self {
+    pub fn new(
+        summary_broadcast: watch::Sender<Option<Summary>>,
+        backends_broadcast: watch::Sender<Vec<Backend>>,
+    ) -> Self {
         let http_api_port = PortAllocator::new().assign_port_with_fallback();
-        let http_api_token = "pass".to_string();
         Self {
             node_connection: None,
             // monero_address: None,
-            http_api_token: http_api_token.clone(),
             http_api_port,
             cpu_threads: None,
             extra_options: Vec::new(),
             summary_broadcast,
+            backends_broadcast,
         }
     }
 }
@@ -150,11 +304,21 @@ impl ProcessAdapter for XmrigAdapter {
         _is_first_start: bool,
     ) -> Result<(ProcessInstance, Self::StatusMonitor), anyhow::Error> {
         let xmrig_shutdown = Shutdown::new();
-        let mut args = self
+        let node_connection = self
             .node_connection
             .as_ref()
-            .ok_or(anyhow::anyhow!("Node connection not set"))?
-            .generate_args();
+            .ok_or(anyhow::anyhow!("Node connection not set"))?;
+        let pool_config_file = data_dir.join("xmrig").join(POOL_CONFIG_FILE_NAME);
+        let mut args = if node_connection.password().is_some() {
+            write_pool_config(node_connection, &pool_config_file)?;
+            vec![format!("--config={}", pool_config_file.display())]
+        } else {
+            // Don't leave a password from an earlier pool on disk
+            if pool_config_file.exists() {
+                std::fs::remove_file(&pool_config_file)?;
+            }
+            node_connection.generate_args()
+        };
         let xmrig_log_file = log_dir.join("xmrig").join("xmrig.log");
         std::fs::create_dir_all(
             xmrig_log_file
@@ -180,8 +344,12 @@ impl ProcessAdapter for XmrigAdapter {
             warn!(target: LOG_TARGET, "Could not create xmrig log file parent directory - {}", error);
         });
 
+        // The write API is open while the token is known, so it only lives in memory for this launch
+        let http_api_token = hex::encode(rand::random::<[u8; 32]>());
         args.push(format!("--http-port={}", self.http_api_port));
-        args.push(format!("--http-access-token={}", self.http_api_token));
+        args.push(format!("--http-access-token={http_api_token}"));
+        // Needed for live reconfiguration and pause/resume
+        args.push("--http-no-restricted".to_string());
         args.push("--donate-level=1".to_string());
 
         // don't specify threads for ludicrous mode
@@ -208,9 +376,10 @@ impl ProcessAdapter for XmrigAdapter {
             },
             XmrigStatusMonitor {
                 summary_broadcast: self.summary_broadcast.clone(),
+                backends_broadcast: self.backends_broadcast.clone(),
                 client: XmrigHttpApiClient::new(
                     format!("http://127.0.0.1:{}", self.http_api_port),
-                    self.http_api_token.clone(),
+                    http_api_token,
                 ),
             },
         ))
@@ -229,6 +398,7 @@ impl ProcessAdapter for XmrigAdapter {
 pub struct XmrigStatusMonitor {
     client: XmrigHttpApiClient,
     summary_broadcast: watch::Sender<Option<Summary>>,
+    backends_broadcast: watch::Sender<Vec<Backend>>,
 }
 
 #[async_trait]
@@ -237,6 +407,18 @@ impl StatusMonitor for XmrigStatusMonitor {
         match tokio::time::timeout(timeout_duration, self.summary()).await {
             Ok(summary_result) => match summary_result {
                 Ok(s) => {
+                    // Per-thread details are only diagnostics, a failure here doesn't make xmrig unhealthy
+                    match tokio::time::timeout(timeout_duration, self.client.backends()).await {
+                        Ok(Ok(backends)) => {
+                            let _result = self.backends_broadcast.send(backends);
+                        }
+                        Ok(Err(e)) => {
+                            debug!(target: LOG_TARGET, "Failed to get xmrig backends: {}", e);
+                        }
+                        Err(_timeout_error) => {
+                            debug!(target: LOG_TARGET, "Timeout while getting xmrig backends");
+                        }
+                    }
                     let _result = self.summary_broadcast.send(Some(s));
                     HealthStatus::Healthy
                 }
@@ -259,4 +441,8 @@ impl XmrigStatusMonitor {
     pub async fn summary(&self) -> Result<xmrig::http_api::models::Summary, Error> {
         self.client.summary().await
     }
+
+    pub fn client(&self) -> &XmrigHttpApiClient {
+        &self.client
+    }
 }
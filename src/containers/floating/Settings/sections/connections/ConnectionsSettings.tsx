@@ -2,14 +2,24 @@ import Node from './Node.tsx';
 import Network from './Network.tsx';
 import Peers from './Peers.tsx';
 import NodeTypeConfiguration from './NodeTypeConfiguration.tsx';
+import CustomNode from './CustomNode.tsx';
+import LocalNodeStorage from './LocalNodeStorage.tsx';
+import SnapshotMirror from './SnapshotMirror.tsx';
 import { useSetupStore } from '@app/store/useSetupStore.ts';
 
 export const ConnectionsSettings = () => {
     const isAppSettingUp = useSetupStore((s) => !s.appUnlocked);
     return (
         <>
-            {import.meta.env.MODE == 'development' && <NodeTypeConfiguration />}
+            {import.meta.env.MODE == 'development' && (
+                <>
+                    <NodeTypeConfiguration />
+                    <CustomNode />
+                </>
+            )}
             <Node />
+            <LocalNodeStorage />
+            <SnapshotMirror />
             {!isAppSettingUp && (
                 <>
                     <Network />
@@ -0,0 +1,62 @@
+import { useCallback, useMemo, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+
+import { Typography } from '@app/components/elements/Typography.tsx';
+import { Input } from '@app/components/elements/inputs/Input';
+import { Button } from '@app/components/elements/buttons/Button.tsx';
+import { useConfigCoreStore } from '@app/store/useAppConfigStore.ts';
+import { setCustomNode } from '@app/store/actions/appConfigStoreActions.ts';
+
+import {
+    SettingsGroup,
+    SettingsGroupAction,
+    SettingsGroupContent,
+    SettingsGroupTitle,
+    SettingsGroupWrapper,
+} from '../../components/SettingsGroup.styles.ts';
+
+export default function CustomNode() {
+    const { t } = useTranslation(['settings'], { useSuspense: false });
+    const customNode = useConfigCoreStore((s) => s.custom_node);
+    const [host, setHost] = useState(customNode?.host || '');
+    const [grpcPort, setGrpcPort] = useState(customNode?.grpc_port || 0);
+
+    const isValid = useMemo(() => !!host.trim() && grpcPort > 0 && grpcPort <= 65535, [host, grpcPort]);
+    const isChanged = host !== customNode?.host || grpcPort !== customNode?.grpc_port;
+
+    const onSave = useCallback(async () => {
+        await setCustomNode(host.trim(), grpcPort);
+    }, [host, grpcPort]);
+
+    return (
+        <SettingsGroupWrapper>
+            <SettingsGroup>
+                <SettingsGroupContent>
+                    <SettingsGroupTitle>
+                        <Typography variant="h6">{t('custom-node')}</Typography>
+                    </SettingsGroupTitle>
+                    <Typography>{t('custom-node-description')}</Typography>
+                    <Input
+                        name="custom-node-host"
+                        value={host}
+                        placeholder="192.168.1.10"
+                        onChange={({ target }) => setHost(target.value)}
+                    />
+                    <Input
+                        name="custom-node-grpc-port"
+                        value={grpcPort || ''}
+                        placeholder="18142"
+                        hasError={!!host && !isValid}
+                        onChange={({ target }) => {
+                            if (target.value && isNaN(+target.value)) return;
+                            setGrpcPort(Number(target.value.trim()));
+                        }}
+                    />
+                </SettingsGroupContent>
+                <SettingsGroupAction style={{ alignItems: 'center' }}>
+                    {isValid && isChanged && <Button onClick={onSave}>{t('save')}</Button>}
+                </SettingsGroupAction>
+            </SettingsGroup>
+        </SettingsGroupWrapper>
+    );
+}
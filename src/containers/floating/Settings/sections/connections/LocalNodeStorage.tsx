@@ -0,0 +1,40 @@
+import React, { useCallback } from 'react';
+import { useTranslation } from 'react-i18next';
+
+import { Typography } from '@app/components/elements/Typography.tsx';
+import { ToggleSwitch } from '@app/components/elements/ToggleSwitch.tsx';
+import { useConfigCoreStore } from '@app/store/useAppConfigStore.ts';
+import { setLocalNodePrunedMode } from '@app/store/actions/appConfigStoreActions.ts';
+
+import {
+    SettingsGroup,
+    SettingsGroupAction,
+    SettingsGroupContent,
+    SettingsGroupTitle,
+    SettingsGroupWrapper,
+} from '../../components/SettingsGroup.styles.ts';
+
+export default function LocalNodeStorage() {
+    const { t } = useTranslation(['settings'], { useSuspense: false });
+    const isPrunedMode = useConfigCoreStore((s) => s.local_node_pruned_mode);
+
+    const handlePrunedModeChange = useCallback(async (event: React.ChangeEvent<HTMLInputElement>) => {
+        await setLocalNodePrunedMode(event.target.checked);
+    }, []);
+
+    return (
+        <SettingsGroupWrapper>
+            <SettingsGroup>
+                <SettingsGroupContent>
+                    <SettingsGroupTitle>
+                        <Typography variant="h6">{t('local-node-pruned-mode')}</Typography>
+                    </SettingsGroupTitle>
+                    <Typography>{t('local-node-pruned-mode-description')}</Typography>
+                </SettingsGroupContent>
+                <SettingsGroupAction>
+                    <ToggleSwitch checked={isPrunedMode} onChange={handlePrunedModeChange} key={`${isPrunedMode}`} />
+                </SettingsGroupAction>
+            </SettingsGroup>
+        </SettingsGroupWrapper>
+    );
+}
@@ -28,6 +28,7 @@ export default function NodeTypeConfiguration() {
             { label: 'Local', value: 'Local' },
             { label: 'Remote', value: 'Remote' },
             { label: 'Remote & Local', value: 'RemoteUntilLocal' },
+            { label: 'Custom', value: 'Custom' },
         ],
         []
     );
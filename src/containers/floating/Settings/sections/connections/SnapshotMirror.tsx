@@ -0,0 +1,64 @@
+import { useCallback, useEffect, useState } from 'react';
+import { useTranslation } from 'react-i18next';
+import { invoke } from '@tauri-apps/api/core';
+
+import { Typography } from '@app/components/elements/Typography.tsx';
+import { Input } from '@app/components/elements/inputs/Input';
+import { Button } from '@app/components/elements/buttons/Button.tsx';
+import { useConfigCoreStore } from '@app/store/useAppConfigStore.ts';
+import { setLocalNodeSnapshotMirror } from '@app/store/actions/appConfigStoreActions.ts';
+
+import {
+    SettingsGroup,
+    SettingsGroupAction,
+    SettingsGroupContent,
+    SettingsGroupTitle,
+    SettingsGroupWrapper,
+} from '../../components/SettingsGroup.styles.ts';
+
+export default function SnapshotMirror() {
+    const { t } = useTranslation(['settings'], { useSuspense: false });
+    const snapshotMirror = useConfigCoreStore((s) => s.local_node_snapshot_mirror);
+    const [isEnabled, setIsEnabled] = useState(false);
+    const [mirror, setMirror] = useState(snapshotMirror || '');
+
+    useEffect(() => {
+        invoke('is_node_snapshot_bootstrap_enabled').then(setIsEnabled).catch(console.error);
+    }, []);
+
+    const isValid = !mirror.trim() || mirror.trim().startsWith('https://');
+    const isChanged = mirror.trim() !== (snapshotMirror || '');
+
+    const onSave = useCallback(async () => {
+        await setLocalNodeSnapshotMirror(mirror.trim() || undefined);
+    }, [mirror]);
+
+    return (
+        <SettingsGroupWrapper>
+            <SettingsGroup>
+                <SettingsGroupContent>
+                    <SettingsGroupTitle>
+                        <Typography variant="h6">{t('local-node-snapshot-mirror')}</Typography>
+                    </SettingsGroupTitle>
+                    {isEnabled ? (
+                        <>
+                            <Typography>{t('local-node-snapshot-mirror-description')}</Typography>
+                            <Input
+                                name="local-node-snapshot-mirror"
+                                value={mirror}
+                                placeholder="https://"
+                                hasError={!isValid}
+                                onChange={({ target }) => setMirror(target.value)}
+                            />
+                        </>
+                    ) : (
+                        <Typography>{t('local-node-snapshot-mirror-disabled')}</Typography>
+                    )}
+                </SettingsGroupContent>
+                <SettingsGroupAction style={{ alignItems: 'center' }}>
+                    {isEnabled && isValid && isChanged && <Button onClick={onSave}>{t('save')}</Button>}
+                </SettingsGroupAction>
+            </SettingsGroup>
+        </SettingsGroupWrapper>
+    );
+}
@@ -1,7 +1,8 @@
-import { memo, useCallback } from 'react';
+import { memo, useCallback, useMemo } from 'react';
 
 import { Typography } from '@app/components/elements/Typography.tsx';
 import { ToggleSwitch } from '@app/components/elements/ToggleSwitch.tsx';
+import { Select } from '@app/components/elements/inputs/Select';
 
 import { useTranslation } from 'react-i18next';
 import {
@@ -17,6 +18,13 @@ import { toggleDeviceExclusion } from '@app/store/actions/miningStoreActions.ts'
 import { useMiningStore } from '@app/store/useMiningStore.ts';
 import { useConfigMiningStore } from '@app/store/useAppConfigStore.ts';
 import { useSetupStore } from '@app/store/useSetupStore.ts';
+import { setGpuDeviceSettings } from '@app/store/actions/appConfigStoreActions.ts';
+import { GpuDeviceMiningSettings } from '@app/types/configs.ts';
+import { MiningModeType } from '@app/store/types.ts';
+import { formatHashrate } from '@app/utils/formatters.ts';
+
+const DEFAULT_VALUE = 'default';
+const MODES: MiningModeType[] = ['Eco', 'Ludicrous', 'Custom'];
 
 const GpuDevices = memo(function GpuDevices() {
     const { t } = useTranslation(['common', 'settings'], { useSuspense: false });
@@ -27,6 +35,9 @@ const GpuDevices = memo(function GpuDevices() {
     const miningGpuInitiated = useMiningStore((s) => s.isGpuMiningInitiated);
     const isGpuMiningEnabled = useConfigMiningStore((s) => s.gpu_mining_enabled);
     const isExcludingGpuDevices = useMiningStore((s) => s.isExcludingGpuDevices);
+    const availableEngines = useMiningStore((s) => s.availableEngines);
+    const gpuDeviceSettings = useConfigMiningStore((s) => s.gpu_device_settings);
+    const gpuDeviceUpdates = useMiningMetricsStore((s) => s.gpu_device_updates);
     const isDisabled =
         !isHardwarePhaseFinished || isExcludingGpuDevices || isGPUMining || miningGpuInitiated || !isGpuMiningEnabled;
 
@@ -34,6 +45,33 @@ const GpuDevices = memo(function GpuDevices() {
         await toggleDeviceExclusion(device.device_index, !device.settings.is_excluded);
     }, []);
 
+    const modeOptions = useMemo(
+        () => [
+            { label: t('gpu-device-mode-default', { ns: 'settings' }), value: DEFAULT_VALUE },
+            ...MODES.map((mode) => ({ label: mode, value: mode })),
+        ],
+        [t]
+    );
+    const engineOptions = useMemo(
+        () => [
+            { label: t('gpu-device-engine-default', { ns: 'settings' }), value: DEFAULT_VALUE },
+            ...availableEngines.map((engine) => ({ label: engine, value: engine })),
+        ],
+        [availableEngines, t]
+    );
+
+    const handleDeviceSettingsChange = useCallback(
+        async (deviceIndex: number, changes: Partial<GpuDeviceMiningSettings>) => {
+            const current = gpuDeviceSettings.find((settings) => settings.device_index === deviceIndex);
+            const updated = { device_index: deviceIndex, ...current, ...changes };
+            await setGpuDeviceSettings([
+                ...gpuDeviceSettings.filter((settings) => settings.device_index !== deviceIndex),
+                updated,
+            ]);
+        },
+        [gpuDeviceSettings]
+    );
+
     return (
         <>
             <SettingsGroupWrapper>
@@ -48,24 +86,72 @@ const GpuDevices = memo(function GpuDevices() {
                 <SettingsGroup>
                     <SettingsGroupContent>
                         {(gpuDevices || []).length > 0 ? (
-                            gpuDevices.map((device, i) => (
-                                <Stack
-                                    key={device.device_index}
-                                    direction="row"
-                                    alignItems="center"
-                                    justifyContent="space-between"
-                                >
-                                    <Typography variant="h6">
-                                        {i + 1}. {device.device_name}
-                                    </Typography>
-                                    <ToggleSwitch
+                            gpuDevices.map((device, i) => {
+                                const settings = gpuDeviceSettings.find(
+                                    (s) => s.device_index === device.device_index
+                                );
+                                const update = gpuDeviceUpdates.find((u) => u.device_index === device.device_index);
+                                const hashrate = formatHashrate(Math.round(update?.stats?.hash_rate ?? 0));
+                                const temperature = update?.hardware?.parameters?.current_temperature;
+                                const powerDraw = update?.hardware?.parameters?.power_draw;
+                                return (
+                                    <Stack
                                         key={device.device_index}
-                                        checked={!device.settings.is_excluded}
-                                        disabled={isDisabled}
-                                        onChange={() => handleSetExcludedDevice(device)}
-                                    />
-                                </Stack>
-                            ))
+                                        direction="row"
+                                        alignItems="center"
+                                        justifyContent="space-between"
+                                        gap={8}
+                                    >
+                                        <Stack>
+                                            <Typography variant="h6">
+                                                {i + 1}. {device.device_name}
+                                            </Typography>
+                                            {isGPUMining && update ? (
+                                                <Typography variant="p">
+                                                    {update.health === 'Stalled'
+                                                        ? t('gpu-device-stalled', { ns: 'settings' })
+                                                        : `${hashrate.value}${hashrate.unit}`}
+                                                    {temperature ? ` · ${Math.round(temperature)}°C` : null}
+                                                    {powerDraw ? ` · ${Math.round(powerDraw)} W` : null}
+                                                </Typography>
+                                            ) : null}
+                                        </Stack>
+                                        <Stack direction="row" alignItems="center" gap={8}>
+                                            <Select
+                                                options={modeOptions}
+                                                selectedValue={settings?.mode ?? DEFAULT_VALUE}
+                                                onChange={(value) =>
+                                                    handleDeviceSettingsChange(device.device_index, {
+                                                        mode:
+                                                            value === DEFAULT_VALUE
+                                                                ? undefined
+                                                                : (value as MiningModeType),
+                                                    })
+                                                }
+                                                variant="bordered"
+                                                forceHeight={36}
+                                            />
+                                            <Select
+                                                options={engineOptions}
+                                                selectedValue={settings?.engine ?? DEFAULT_VALUE}
+                                                onChange={(value) =>
+                                                    handleDeviceSettingsChange(device.device_index, {
+                                                        engine: value === DEFAULT_VALUE ? undefined : value,
+                                                    })
+                                                }
+                                                variant="bordered"
+                                                forceHeight={36}
+                                            />
+                                            <ToggleSwitch
+                                                key={device.device_index}
+                                                checked={!device.settings.is_excluded}
+                                                disabled={isDisabled}
+                                                onChange={() => handleSetExcludedDevice(device)}
+                                            />
+                                        </Stack>
+                                    </Stack>
+                                );
+                            })
                         ) : (
                             <Typography variant="p">{t('gpu-device-no-found', { ns: 'settings' })}</Typography>
                         )}
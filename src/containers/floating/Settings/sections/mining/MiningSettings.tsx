@@ -3,6 +3,7 @@ import GpuMiningMarkup from './GpuMiningMarkup.tsx';
 import MineOnStartMarkup from './MineOnStartMarkup.tsx';
 import GpuDevices from './GpuDevices.tsx';
 import GpuEngine from './GpuEngine.tsx';
+import StratumWorkers from './StratumWorkers.tsx';
 
 export const MiningSettings = () => {
     return (
@@ -11,6 +12,7 @@ export const MiningSettings = () => {
             <GpuMiningMarkup />
             <GpuEngine />
             <GpuDevices />
+            <StratumWorkers />
             <MineOnStartMarkup />
         </>
     );
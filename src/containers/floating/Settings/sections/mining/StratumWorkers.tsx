@@ -0,0 +1,64 @@
+import { memo, useEffect } from 'react';
+import { useTranslation } from 'react-i18next';
+
+import { Typography } from '@app/components/elements/Typography.tsx';
+import { Stack } from '@app/components/elements/Stack';
+import { useMiningMetricsStore } from '@app/store/useMiningMetricsStore.ts';
+import { fetchStratumProxyWorkers } from '@app/store/actions/miningMetricsStoreActions.ts';
+import { formatHashrate } from '@app/utils/formatters.ts';
+import {
+    SettingsGroup,
+    SettingsGroupContent,
+    SettingsGroupTitle,
+    SettingsGroupWrapper,
+} from '../../components/SettingsGroup.styles.ts';
+
+const StratumWorkers = memo(function StratumWorkers() {
+    const { t } = useTranslation(['settings'], { useSuspense: false });
+    const workers = useMiningMetricsStore((s) => s.stratum_workers);
+
+    useEffect(() => {
+        void fetchStratumProxyWorkers();
+    }, []);
+
+    if (!workers.length) return null;
+
+    return (
+        <SettingsGroupWrapper>
+            <SettingsGroup>
+                <SettingsGroupContent>
+                    <SettingsGroupTitle>
+                        <Typography variant="h6">{t('stratum-workers')}</Typography>
+                    </SettingsGroupTitle>
+                    <Typography variant="p">{t('stratum-workers-description')}</Typography>
+                </SettingsGroupContent>
+            </SettingsGroup>
+            <SettingsGroup>
+                <SettingsGroupContent>
+                    {workers.map((worker) => {
+                        const hashrate = formatHashrate(Math.round(worker.hashrate));
+                        return (
+                            <Stack key={worker.name} direction="row" alignItems="center" justifyContent="space-between">
+                                <Typography variant="h6">
+                                    {worker.name} ({worker.address})
+                                </Typography>
+                                <Typography variant="p">
+                                    {worker.is_connected
+                                        ? `${hashrate.value}${hashrate.unit}`
+                                        : t('stratum-worker-disconnected')}
+                                    {' · '}
+                                    {t('stratum-worker-shares', {
+                                        accepted: worker.accepted_shares,
+                                        rejected: worker.rejected_shares,
+                                    })}
+                                </Typography>
+                            </Stack>
+                        );
+                    })}
+                </SettingsGroupContent>
+            </SettingsGroup>
+        </SettingsGroupWrapper>
+    );
+});
+
+export default StratumWorkers;
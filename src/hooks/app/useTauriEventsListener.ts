@@ -11,6 +11,8 @@ import {
     setGpuDevices,
     setGpuMiningStatus,
     setPoolStatus,
+    handleGpuDevicesUpdate,
+    handleStratumProxyUpdate,
 } from '@app/store/actions/miningMetricsStoreActions';
 import {
     handleAskForRestart,
@@ -20,13 +22,23 @@ import {
     setShouldShowExchangeSpecificModal,
     setShowExternalDependenciesDialog,
 } from '@app/store/actions/uiStoreActions';
-import { setAvailableEngines } from '@app/store/actions/miningStoreActions';
 import {
+    handleBatteryPolicyUpdate,
+    handleGpuEngineFallback,
+    handleIdleMiningUpdate,
+    handleMiningBenchmarkProgress,
+    handleMiningScheduleUpdate,
+    handleThermalGovernorAction,
+    setAvailableEngines,
+} from '@app/store/actions/miningStoreActions';
+import {
+    handleNodeDiskSpaceLow,
     handleRestartingPhases,
     handleShowRelesaeNotes,
     loadExternalDependencies,
     handleCriticalProblemEvent,
     setCriticalError,
+    setError,
     setIsStuckOnOrphanChain,
     setNetworkStatus,
 } from '@app/store/actions/appStateStoreActions';
@@ -58,7 +70,7 @@ import { handleShowStagedSecurityModal } from '@app/store/actions/stagedSecurity
 import { refreshTransactions } from '@app/hooks/wallet/useFetchTxHistory.ts';
 import { handleBaseWalletUpate, handleExternalWalletAddressUpdate } from '@app/store/actions/walletStoreActions';
 
-const LOG_EVENT_TYPES = ['WalletAddressUpdate', 'CriticalProblem', 'MissingApplications'];
+const LOG_EVENT_TYPES = ['WalletAddressUpdate', 'CriticalProblem', 'MissingApplications', 'ProcessWatcherGaveUp'];
 
 const useTauriEventsListener = () => {
     const eventRef = useRef<BackendStateUpdateEvent | null>(null);
@@ -225,6 +237,38 @@ const useTauriEventsListener = () => {
                         case 'ShouldShowExchangeMinerModal':
                             setShouldShowExchangeSpecificModal(true);
                             break;
+                        case 'MiningScheduleUpdate':
+                            handleMiningScheduleUpdate(event.payload);
+                            break;
+                        case 'IdleMiningUpdate':
+                            handleIdleMiningUpdate(event.payload);
+                            break;
+                        case 'ThermalGovernorAction':
+                            handleThermalGovernorAction(event.payload);
+                            break;
+                        case 'BatteryPolicyUpdate':
+                            handleBatteryPolicyUpdate(event.payload);
+                            break;
+                        case 'MiningBenchmarkProgress':
+                            handleMiningBenchmarkProgress(event.payload);
+                            break;
+                        case 'StratumProxyUpdate':
+                            handleStratumProxyUpdate(event.payload.workers);
+                            break;
+                        case 'GpuDevicesUpdate':
+                            handleGpuDevicesUpdate(event.payload);
+                            break;
+                        case 'GpuEngineFallback':
+                            handleGpuEngineFallback(event.payload);
+                            break;
+                        case 'NodeDiskSpaceLow':
+                            handleNodeDiskSpaceLow(event.payload);
+                            break;
+                        case 'ProcessWatcherGaveUp':
+                            setError(
+                                `${event.payload.name} kept crashing and was stopped after ${event.payload.num_restarts} restarts`
+                            );
+                            break;
                         default:
                             console.warn('Unknown event', JSON.stringify(event));
                             break;
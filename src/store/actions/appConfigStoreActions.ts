@@ -23,7 +23,16 @@ import { setError } from './appStateStoreActions.ts';
 import { setIsAppExchangeSpecific, setUITheme } from './uiStoreActions';
 import { GpuThreads } from '@app/types/app-status.ts';
 import { displayMode, MiningModeType } from '../types';
-import { ConfigCore, ConfigMining, ConfigUI, ConfigWallet } from '@app/types/configs.ts';
+import {
+    BatteryMiningAction,
+    ConfigCore,
+    ConfigMining,
+    ConfigUI,
+    ConfigWallet,
+    GpuDeviceMiningSettings,
+    GpuTemperatureCeiling,
+    MiningScheduleRule,
+} from '@app/types/configs.ts';
 import { NodeType, updateNodeType as updateNodeTypeForNodeStore } from '../useNodeStore.ts';
 import { setCurrentExchangeMinerId } from '../useExchangeStore.ts';
 import { fetchExchangeContent, refreshXCContent } from '@app/hooks/exchanges/fetchExchangeContent.ts';
@@ -208,6 +217,89 @@ export const setMineOnAppStart = async (mineOnAppStart: boolean) => {
         useConfigMiningStore.setState({ mine_on_app_start: !mineOnAppStart });
     });
 };
+export const setMiningSchedule = async (enabled: boolean, rules: MiningScheduleRule[]) => {
+    const previous = useConfigMiningStore.getState();
+    useConfigMiningStore.setState({ mining_schedule_enabled: enabled, mining_schedule: rules });
+    invoke('set_mining_schedule', { enabled, rules }).catch((e) => {
+        console.error('Could not set mining schedule', e);
+        setError('Could not change mining schedule');
+        useConfigMiningStore.setState({
+            mining_schedule_enabled: previous.mining_schedule_enabled,
+            mining_schedule: previous.mining_schedule,
+        });
+    });
+};
+export const setIdleMining = async (enabled: boolean, timeoutSecs: number, mode: MiningModeType) => {
+    const previous = useConfigMiningStore.getState();
+    useConfigMiningStore.setState({
+        idle_mining_enabled: enabled,
+        idle_mining_timeout_secs: timeoutSecs,
+        idle_mining_mode: mode,
+    });
+    invoke('set_idle_mining', { enabled, timeoutSecs, mode }).catch((e) => {
+        console.error('Could not set idle mining', e);
+        setError('Could not change idle mining');
+        useConfigMiningStore.setState({
+            idle_mining_enabled: previous.idle_mining_enabled,
+            idle_mining_timeout_secs: previous.idle_mining_timeout_secs,
+            idle_mining_mode: previous.idle_mining_mode,
+        });
+    });
+};
+interface SetThermalGovernorProps {
+    enabled: boolean;
+    cpuMaxTemperature?: number;
+    gpuMaxTemperatures: GpuTemperatureCeiling[];
+    hysteresis: number;
+}
+export const setThermalGovernor = async (params: SetThermalGovernorProps) => {
+    const { enabled, cpuMaxTemperature, gpuMaxTemperatures, hysteresis } = params;
+    const previous = useConfigMiningStore.getState();
+    useConfigMiningStore.setState({
+        thermal_governor_enabled: enabled,
+        cpu_max_temperature: cpuMaxTemperature,
+        gpu_max_temperatures: gpuMaxTemperatures,
+        thermal_hysteresis: hysteresis,
+    });
+    invoke('set_thermal_governor', { enabled, cpuMaxTemperature, gpuMaxTemperatures, hysteresis }).catch((e) => {
+        console.error('Could not set thermal governor', e);
+        setError('Could not change thermal governor');
+        useConfigMiningStore.setState({
+            thermal_governor_enabled: previous.thermal_governor_enabled,
+            cpu_max_temperature: previous.cpu_max_temperature,
+            gpu_max_temperatures: previous.gpu_max_temperatures,
+            thermal_hysteresis: previous.thermal_hysteresis,
+        });
+    });
+};
+export const setGpuDeviceSettings = async (gpuDeviceSettings: GpuDeviceMiningSettings[]) => {
+    const previous = useConfigMiningStore.getState().gpu_device_settings;
+    useConfigMiningStore.setState({ gpu_device_settings: gpuDeviceSettings });
+    try {
+        await invoke('set_gpu_device_settings', { gpuDeviceSettings });
+        // glytex picks up grid sizes and the engine on launch
+        if (useMiningMetricsStore.getState().gpu_mining_status.is_mining) {
+            await stopGpuMining();
+            await startGpuMining();
+        }
+    } catch (e) {
+        console.error('Could not set GPU device settings', e);
+        setError('Could not change GPU device settings');
+        useConfigMiningStore.setState({ gpu_device_settings: previous });
+    }
+};
+export const setBatteryPolicy = async (action: BatteryMiningAction, threshold?: number) => {
+    const previous = useConfigMiningStore.getState();
+    useConfigMiningStore.setState({ battery_policy_action: action, battery_policy_threshold: threshold });
+    invoke('set_battery_policy', { action, threshold }).catch((e) => {
+        console.error('Could not set battery policy', e);
+        setError('Could not change battery policy');
+        useConfigMiningStore.setState({
+            battery_policy_action: previous.battery_policy_action,
+            battery_policy_threshold: previous.battery_policy_threshold,
+        });
+    });
+};
 export const setMode = async (params: SetModeProps) => {
     const { mode, customGpuLevels, customCpuLevels } = params;
 
@@ -312,6 +404,25 @@ export const setUseTor = async (useTor: boolean) => {
         useConfigCoreStore.setState({ use_tor: !useTor });
     });
 };
+export const setLocalNodePrunedMode = async (prunedMode: boolean) => {
+    const { local_node_pruning_horizon: pruningHorizon, local_node_data_dir: dataDir } =
+        useConfigCoreStore.getState();
+    useConfigCoreStore.setState({ local_node_pruned_mode: prunedMode });
+    invoke('set_local_node_storage', { prunedMode, pruningHorizon, dataDir }).catch((e) => {
+        console.error('Could not set local node pruned mode', e);
+        setError('Could not change local node pruned mode');
+        useConfigCoreStore.setState({ local_node_pruned_mode: !prunedMode });
+    });
+};
+export const setLocalNodeSnapshotMirror = async (mirror?: string) => {
+    const previousMirror = useConfigCoreStore.getState().local_node_snapshot_mirror;
+    useConfigCoreStore.setState({ local_node_snapshot_mirror: mirror });
+    invoke('set_local_node_snapshot_mirror', { mirror }).catch((e) => {
+        console.error('Could not set local node snapshot mirror', e);
+        setError('Could not change local node snapshot mirror');
+        useConfigCoreStore.setState({ local_node_snapshot_mirror: previousMirror });
+    });
+};
 export const setVisualMode = (enabled: boolean) => {
     useConfigUIStore.setState({ visual_mode: enabled });
     invoke('set_visual_mode', { enabled }).catch((e) => {
@@ -332,6 +443,16 @@ export const setNodeType = async (nodeType: NodeType) => {
     });
 };
 
+export const setCustomNode = async (host: string, grpcPort: number) => {
+    const previousCustomNode = useConfigCoreStore.getState().custom_node;
+    useConfigCoreStore.setState({ custom_node: { host, grpc_port: grpcPort } });
+    invoke('set_custom_node', { host, grpcPort }).catch((e) => {
+        console.error('Could not set custom node', e);
+        setError('Could not change custom node');
+        useConfigCoreStore.setState({ custom_node: previousCustomNode });
+    });
+};
+
 export const fetchBackendInMemoryConfig = async () => {
     try {
         const appInMemoryConfig = await invoke('get_app_in_memory_config');
@@ -2,7 +2,11 @@ import { invoke } from '@tauri-apps/api/core';
 import { useAppStateStore } from '../appStateStore.ts';
 import { ExternalDependency, NetworkStatus } from '@app/types/app-status.ts';
 import { addToast } from '@app/components/ToastStack/useToastStore.tsx';
-import { CriticalProblemPayload, ShowReleaseNotesPayload } from '@app/types/events-payloads.ts';
+import {
+    CriticalProblemPayload,
+    NodeDiskSpaceLowPayload,
+    ShowReleaseNotesPayload,
+} from '@app/types/events-payloads.ts';
 import { setDialogToShow, useMiningStore, useUIStore } from '../index.ts';
 import { SetupPhase } from '@app/types/backend-state.ts';
 import {
@@ -88,6 +92,15 @@ export const handleShowRelesaeNotes = (payload: ShowReleaseNotesPayload) => {
     }
 };
 
+export const handleNodeDiskSpaceLow = ({ mount_point, available_space }: NodeDiskSpaceLowPayload) => {
+    const availableGb = Math.floor(available_space / 1_000_000_000);
+    addToast({
+        title: 'Low disk space',
+        text: `Only ${availableGb} GB left on ${mount_point}, the node will stop syncing once it is full`,
+        type: 'warning',
+    });
+};
+
 export const handleRestartingPhases = async (phasesToRestart: SetupPhase[]) => {
     if (phasesToRestart.length === 0) {
         return;
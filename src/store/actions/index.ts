@@ -73,4 +73,6 @@ export {
     handleConnectedPeersUpdate,
     handleBaseNodeStatusUpdate,
     handleMiningModeChange,
+    handleStratumProxyUpdate,
+    fetchStratumProxyWorkers,
 } from './miningMetricsStoreActions.ts';
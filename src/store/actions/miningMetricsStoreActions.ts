@@ -1,4 +1,5 @@
-import { BaseNodeStatus, CpuMinerStatus, GpuDevice, GpuMinerStatus } from '@app/types/app-status.ts';
+import { invoke } from '@tauri-apps/api/core';
+import { BaseNodeStatus, CpuMinerStatus, GpuDevice, GpuMinerStatus, StratumWorker } from '@app/types/app-status.ts';
 import { setGpuMiningEnabled } from './appConfigStoreActions';
 import {
     useBlockchainVisualisationStore,
@@ -8,6 +9,7 @@ import {
     useWalletStore,
 } from '../';
 import { setAnimationState } from '@tari-project/tari-tower';
+import { GpuDeviceUpdatePayload } from '@app/types/events-payloads.ts';
 
 export const setGpuDevices = (gpu_devices: GpuDevice[]) => {
     useMiningMetricsStore.setState({ gpu_devices });
@@ -36,6 +38,20 @@ export const setCpuMiningStatus = (cpu_mining_status: CpuMinerStatus) => {
 export const setPoolStatus = (pool_status: CpuMinerStatus['pool_status']) => {
     useMiningMetricsStore.setState((current) => ({ cpu_mining_status: { ...current.cpu_mining_status, pool_status } }));
 };
+export const handleGpuDevicesUpdate = (gpu_device_updates: GpuDeviceUpdatePayload[]) => {
+    useMiningMetricsStore.setState({ gpu_device_updates });
+};
+export const handleStratumProxyUpdate = (stratum_workers: StratumWorker[]) => {
+    useMiningMetricsStore.setState({ stratum_workers });
+};
+export const fetchStratumProxyWorkers = async () => {
+    try {
+        const stratum_workers = await invoke('get_stratum_proxy_workers');
+        useMiningMetricsStore.setState({ stratum_workers });
+    } catch (e) {
+        console.error('Could not get stratum proxy workers', e);
+    }
+};
 export const handleConnectedPeersUpdate = (connected_peers: string[]) => {
     const wasNodeConnected = useMiningMetricsStore.getState().isNodeConnected;
     const isNodeConnected = connected_peers?.length > 0;
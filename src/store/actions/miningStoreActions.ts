@@ -7,11 +7,20 @@ import { useMiningStore } from '../useMiningStore.ts';
 import { MiningModeType } from '../types.ts';
 import { setGpuMiningEnabled, setMode } from './appConfigStoreActions.ts';
 import { setError } from './appStateStoreActions.ts';
+import { addToast } from '@app/components/ToastStack/useToastStore.tsx';
 import { handleMiningModeChange, setGpuDevices } from '../actions/miningMetricsStoreActions.ts';
 import { useSetupStore } from '@app/store/useSetupStore.ts';
 import { useConfigMiningStore } from '../useAppConfigStore.ts';
 import { Network } from '@app/utils/network.ts';
 import { getParsedMaxLevels } from '@app/utils/mining/power-levels.ts';
+import {
+    BatteryPolicyUpdatePayload,
+    GpuEngineFallbackPayload,
+    IdleMiningUpdatePayload,
+    MiningBenchmarkProgressPayload,
+    MiningScheduleUpdatePayload,
+    ThermalGovernorAction,
+} from '@app/types/events-payloads.ts';
 
 interface ChangeMiningModeArgs {
     mode: MiningModeType;
@@ -31,7 +40,6 @@ export const changeMiningMode = async (params: ChangeMiningModeArgs) => {
     const { mode, customGpuLevels, customCpuLevels } = params;
     console.info(`Changing mode to ${mode}...`);
 
-    const cpu_mining_status = useMiningMetricsStore.getState().cpu_mining_status;
     const gpu_mining_status = useMiningMetricsStore.getState().gpu_mining_status;
 
     useMiningStore.setState({ isChangingMode: true });
@@ -39,9 +47,10 @@ export const changeMiningMode = async (params: ChangeMiningModeArgs) => {
     const wasCpuMiningInitiated = useMiningStore.getState().isCpuMiningInitiated;
     const wasGpuMiningInitiated = useMiningStore.getState().isGpuMiningInitiated;
 
-    if (cpu_mining_status.is_mining || gpu_mining_status.is_mining) {
-        console.info('Pausing mining...');
-        await stopMining();
+    // The CPU miner is reconfigured in place by set_mode, only the GPU miner needs a restart
+    if (gpu_mining_status.is_mining) {
+        console.info('Pausing GPU mining...');
+        await stopGpuMining();
     }
 
     const parsedMax = getParsedMaxLevels(useMiningStore.getState().maxAvailableThreads);
@@ -115,6 +124,18 @@ export const restartMining = async () => {
 };
 export const setAvailableEngines = (availableEngines: string[], currentEngine: string) =>
     useMiningStore.setState({ availableEngines, engine: currentEngine });
+export const handleGpuEngineFallback = ({ failed_engine, engine }: GpuEngineFallbackPayload) => {
+    if (!engine) {
+        setError(`GPU mining stopped, the ${failed_engine} engine kept failing and no other engine is available`);
+        return;
+    }
+    useMiningStore.setState({ engine });
+    addToast({
+        title: 'GPU engine changed',
+        text: `The ${failed_engine} engine kept failing, GPU mining switched to ${engine}`,
+        type: 'warning',
+    });
+};
 export const setCustomLevelsDialogOpen = (customLevelsDialogOpen: boolean) =>
     useMiningStore.setState({ customLevelsDialogOpen });
 export const setEngine = async (engine) => {
@@ -241,6 +262,92 @@ export const stopMining = async () => {
         setError(e as string);
     }
 };
+export const handleMiningScheduleUpdate = (payload: MiningScheduleUpdatePayload) => {
+    useMiningStore.setState({
+        isCpuMiningInitiated: payload.cpu_mining,
+        isGpuMiningInitiated: payload.gpu_mining,
+    });
+};
+
+export const handleIdleMiningUpdate = (payload: IdleMiningUpdatePayload) => {
+    useMiningStore.setState({
+        isIdleMining: payload.is_idle,
+        isCpuMiningInitiated: payload.cpu_mining,
+        isGpuMiningInitiated: payload.gpu_mining,
+    });
+};
+
+export const handleBatteryPolicyUpdate = (payload: BatteryPolicyUpdatePayload) => {
+    useMiningStore.setState({
+        powerSource: payload.power_source,
+        isCpuMiningInitiated: payload.cpu_mining,
+        isGpuMiningInitiated: payload.gpu_mining,
+    });
+};
+
+const MAX_THERMAL_GOVERNOR_ACTIONS = 100;
+export const handleThermalGovernorAction = (action: ThermalGovernorAction) => {
+    useMiningStore.setState((c) => ({
+        thermalGovernorActions: [...c.thermalGovernorActions, action].slice(-MAX_THERMAL_GOVERNOR_ACTIONS),
+    }));
+};
+export const fetchThermalGovernorActions = async () => {
+    try {
+        const thermalGovernorActions = await invoke('get_thermal_governor_actions');
+        useMiningStore.setState({ thermalGovernorActions });
+    } catch (e) {
+        console.error('Could not get thermal governor actions: ', e);
+    }
+};
+
+export const handleMiningBenchmarkProgress = (benchmarkProgress: MiningBenchmarkProgressPayload) => {
+    useMiningStore.setState({ benchmarkProgress });
+};
+export const runMiningBenchmark = async (benchmarkCpu: boolean, benchmarkGpu: boolean) => {
+    if (useMiningStore.getState().isBenchmarking) return;
+    useMiningStore.setState({ isBenchmarking: true, benchmarkProgress: undefined });
+    try {
+        const benchmarkResults = await invoke('run_mining_benchmark', { benchmarkCpu, benchmarkGpu });
+        useMiningStore.setState({ benchmarkResults });
+    } catch (e) {
+        console.error('Mining benchmark failed: ', e);
+        setError(e as string);
+    } finally {
+        useMiningStore.setState({ isBenchmarking: false });
+    }
+};
+export const fetchMiningBenchmarkResults = async () => {
+    try {
+        const benchmarkResults = await invoke('get_mining_benchmark_results');
+        useMiningStore.setState({ benchmarkResults: benchmarkResults ?? undefined });
+    } catch (e) {
+        console.error('Could not get mining benchmark results: ', e);
+    }
+};
+export const exportMiningBenchmarkResults = async () => {
+    try {
+        return await invoke('export_mining_benchmark_results');
+    } catch (e) {
+        console.error('Could not export mining benchmark results: ', e);
+        setError(e as string);
+    }
+};
+interface FetchMiningHistoryArgs {
+    from: number;
+    to: number;
+    bucketSecs?: number;
+}
+export const fetchMiningHistory = async ({ from, to, bucketSecs }: FetchMiningHistoryArgs) => {
+    try {
+        const [samples, blocks] = await Promise.all([
+            invoke('get_mining_history', { from, to, bucketSecs }),
+            invoke('get_block_history', { from, to }),
+        ]);
+        return { samples, blocks };
+    } catch (e) {
+        console.error('Could not get mining history: ', e);
+    }
+};
 export const toggleDeviceExclusion = async (deviceIndex: number, excluded: boolean) => {
     try {
         const wasGpuMiningInitiated = useMiningStore.getState().isGpuMiningInitiated;
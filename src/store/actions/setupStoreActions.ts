@@ -66,6 +66,8 @@ export const handleWalletUnlocked = () => {
 
 export const handleCpuMiningUnlocked = async () => {
     useSetupStore.setState({ cpuMiningUnlocked: true });
+    // Miners are started by the backend scheduler when the mining schedule is enabled
+    if (useConfigMiningStore.getState().mining_schedule_enabled) return;
 
     const mineOnAppStart = useConfigMiningStore.getState().mine_on_app_start;
     const cpuMiningEnabled = useConfigMiningStore.getState().cpu_mining_enabled;
@@ -80,6 +82,7 @@ export const handleCpuMiningUnlocked = async () => {
 };
 export const handleGpuMiningUnlocked = async () => {
     useSetupStore.setState({ gpuMiningUnlocked: true });
+    if (useConfigMiningStore.getState().mining_schedule_enabled) return;
 
     const mineOnAppStart = useConfigMiningStore.getState().mine_on_app_start;
     const gpuMiningEnabled = useConfigMiningStore.getState().gpu_mining_enabled;
@@ -16,6 +16,13 @@ const configCoreInitialState: ConfigCore = {
     mmproxy_use_monero_failover: false,
     pre_release: false,
     remote_base_node_address: '',
+    remote_base_node_fallback_addresses: [],
+    local_node_pruned_mode: false,
+    local_node_pruning_horizon: 100,
+    local_node_min_free_disk_space_gb: 10,
+    local_node_banned_peers: [],
+    local_node_seed_peers: [],
+    local_node_dns_seeds: [],
     should_auto_launch: false,
     use_tor: false,
     airdrop_tokens: undefined,
@@ -50,6 +57,17 @@ const configMininigInitialState: ConfigMining = {
     mine_on_app_start: false,
     mode: 'Eco',
     mining_time: 0,
+    mining_schedule_enabled: false,
+    mining_schedule: [],
+    idle_mining_enabled: false,
+    idle_mining_timeout_secs: 300,
+    idle_mining_mode: 'Ludicrous',
+    thermal_governor_enabled: false,
+    gpu_max_temperatures: [],
+    gpu_device_settings: [],
+    thermal_hysteresis: 5,
+    battery_policy_action: 'None',
+    mining_history_retention_days: 365,
 };
 
 const configUIInitialState: UIConfigStoreState = {
@@ -1,5 +1,6 @@
 import { create } from './create';
-import { BaseNodeStatus, CpuMinerStatus, GpuMinerStatus, GpuDevice } from '@app/types/app-status';
+import { BaseNodeStatus, CpuMinerStatus, GpuMinerStatus, GpuDevice, StratumWorker } from '@app/types/app-status';
+import { GpuDeviceUpdatePayload } from '@app/types/events-payloads.ts';
 
 interface MiningMetricsStoreState {
     isNodeConnected: boolean;
@@ -8,6 +9,8 @@ interface MiningMetricsStoreState {
     gpu_devices: GpuDevice[];
     gpu_mining_status: GpuMinerStatus;
     cpu_mining_status: CpuMinerStatus;
+    stratum_workers: StratumWorker[];
+    gpu_device_updates: GpuDeviceUpdatePayload[];
 }
 
 const initialState: MiningMetricsStoreState = {
@@ -35,8 +38,17 @@ const initialState: MiningMetricsStoreState = {
         is_mining: false,
         hash_rate: 0,
         estimated_earnings: 0,
-        connection: { is_connected: false },
+        connection: {
+            is_connected: false,
+            accepted_shares: 0,
+            rejected_shares: 0,
+            ping: 0,
+            failures: 0,
+            error_log: [],
+        },
     },
+    stratum_workers: [],
+    gpu_device_updates: [],
 };
 
 export const useMiningMetricsStore = create<MiningMetricsStoreState>()(() => ({
@@ -1,6 +1,11 @@
 import { Network } from '@app/utils/network';
 import { create } from './create';
-import { MaxConsumptionLevels } from '@app/types/app-status';
+import { MaxConsumptionLevels, MiningBenchmarkResults } from '@app/types/app-status';
+import {
+    MiningBenchmarkProgressPayload,
+    PowerSourceStatus,
+    ThermalGovernorAction,
+} from '@app/types/events-payloads';
 
 interface MiningStoreState {
     hashrateReady?: boolean;
@@ -11,6 +16,12 @@ interface MiningStoreState {
     miningTime: number;
     isCpuMiningInitiated: boolean;
     isGpuMiningInitiated: boolean;
+    isIdleMining: boolean;
+    thermalGovernorActions: ThermalGovernorAction[];
+    powerSource?: PowerSourceStatus;
+    isBenchmarking: boolean;
+    benchmarkProgress?: MiningBenchmarkProgressPayload;
+    benchmarkResults?: MiningBenchmarkResults;
     wasMineOnAppStartExecuted?: boolean;
     sessionMiningTime: number;
     customLevelsDialogOpen: boolean;
@@ -29,6 +40,9 @@ const initialState: MiningStoreState = {
     hashrateReady: false,
     isCpuMiningInitiated: false,
     isGpuMiningInitiated: false,
+    isIdleMining: false,
+    thermalGovernorActions: [],
+    isBenchmarking: false,
     wasMineOnAppStartExecuted: false,
     isChangingMode: false,
     isExcludingGpuDevices: false,
@@ -2,11 +2,17 @@ import { BackgroundNodeSyncUpdatePayload } from '@app/types/events-payloads';
 import { create } from './create';
 import { deepEqual } from '@app/utils/objectDeepEqual.ts';
 
-export type NodeType = 'Local' | 'Remote' | 'RemoteUntilLocal' | 'LocalAfterRemote';
+export type NodeType = 'Local' | 'Remote' | 'RemoteUntilLocal' | 'LocalAfterRemote' | 'Custom';
 export interface NodeIdentity {
     public_key: string;
     public_addresses: string[];
 }
+export interface NodePeer {
+    public_key: string;
+    addresses: string[];
+    latency_ms?: number;
+    user_agent: string;
+}
 
 interface NodeStoreState {
     node_type?: NodeType;
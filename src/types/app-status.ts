@@ -173,12 +173,81 @@ export interface GpuDevice {
     settings: GpuSettings;
 }
 
+export type GpuDeviceHealth = 'Healthy' | 'Stalled' | 'Excluded';
+
+export interface GpuDeviceStats {
+    device_index: number;
+    hash_rate: number;
+    accepted_shares: number;
+    errors: number;
+    engine: string;
+}
+
+export interface GpuHardwareReading {
+    device_index: number;
+    vendor: string;
+    name: string;
+    status: {
+        is_available: boolean;
+        is_reader_implemented: boolean;
+    };
+    parameters?: {
+        usage_percentage: number;
+        current_temperature: number;
+        max_temperature: number;
+        power_draw: number;
+        fan_speed_percentage: number;
+    };
+}
+
 export interface CpuMinerStatus {
     is_mining: boolean;
     hash_rate: number;
     estimated_earnings: number;
     connection: CpuMinerConnectionStatus;
     pool_status?: PoolStatus;
+    diagnostics?: CpuMinerDiagnostics;
+}
+
+export interface CpuMinerDiagnostics {
+    is_paused: boolean;
+    algorithm?: string;
+    highest_hash_rate?: number;
+    hashes_total: number;
+    threads: CpuThreadStatus[];
+    hugepages: HugepagesStatus;
+    memory: {
+        free: number;
+        total: number;
+        resident_set_memory: number;
+    };
+    cpu: {
+        brand: string;
+        aes: boolean;
+        avx2: boolean;
+        x64: boolean;
+        l2: number;
+        l3: number;
+        cores: number;
+        threads: number;
+        packages: number;
+        nodes: number;
+        backend?: string;
+        msr?: string;
+        assembly?: string;
+    };
+}
+
+export interface CpuThreadStatus {
+    affinity: number;
+    intensity?: number;
+    hash_rate: number;
+}
+
+export interface HugepagesStatus {
+    enabled: boolean;
+    allocated: number;
+    total: number;
 }
 
 export interface PoolStatus {
@@ -186,10 +255,27 @@ export interface PoolStatus {
     unpaid: number;
     balance: number;
     min_payout: number;
+    pool_hashrate?: number;
+    invalid_shares?: number;
+    last_share_time?: number;
+    payouts: PoolPayout[];
+}
+
+export interface PoolPayout {
+    timestamp: number;
+    amount: number;
+    tx_hash?: string;
 }
 
 interface CpuMinerConnectionStatus {
     is_connected: boolean;
+    pool_url?: string;
+    accepted_shares: number;
+    rejected_shares: number;
+    ping: number;
+    failures: number;
+    tls?: string;
+    error_log: string[];
 }
 
 export interface GpuMinerStatus {
@@ -240,8 +326,56 @@ export interface PaperWalletDetails {
 
 export interface GpuThreads {
     gpu_name: string;
+    device_index?: number;
     max_gpu_threads: number;
 }
+export interface BenchmarkRun {
+    setting: number;
+    hash_rate: number;
+    max_temperature?: number;
+}
+export interface MiningBenchmarkResults {
+    timestamp: number;
+    cpu?: {
+        runs: BenchmarkRun[];
+        best_threads?: number;
+    };
+    gpus: {
+        device_index: number;
+        device_name: string;
+        runs: BenchmarkRun[];
+        best_grid_size?: number;
+    }[];
+}
+export interface HashrateSample {
+    timestamp: number;
+    cpu_hashrate: number;
+    gpu_hashrate: number;
+    cpu_estimated_earnings: number;
+    gpu_estimated_earnings: number;
+    block_height: number;
+    sha_network_hashrate: number;
+    randomx_network_hashrate: number;
+    pool_accepted_shares?: number;
+    pool_unpaid?: number;
+    pool_balance?: number;
+}
+export interface StratumWorker {
+    name: string;
+    address: string;
+    is_connected: boolean;
+    connected_at: number;
+    accepted_shares: number;
+    rejected_shares: number;
+    last_share_time?: number;
+    hashrate: number;
+}
+export interface BlockEvent {
+    timestamp: number;
+    block_height: number;
+    coinbase_reward?: number;
+    available_balance?: number;
+}
 export interface MaxConsumptionLevels {
     max_cpu_threads: number;
     max_gpus_threads: GpuThreads[];
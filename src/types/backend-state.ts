@@ -1,14 +1,24 @@
 import {
     BackgroundNodeSyncUpdatePayload,
+    BatteryPolicyUpdatePayload,
     ConnectedPeersUpdatePayload,
     ConnectionStatusPayload,
     CriticalProblemPayload,
     DetectedAvailableGpuEngines,
     DetectedDevicesPayload,
+    GpuDeviceUpdatePayload,
+    GpuEngineFallbackPayload,
+    IdleMiningUpdatePayload,
+    MiningBenchmarkProgressPayload,
+    MiningScheduleUpdatePayload,
     NewBlockHeightPayload,
+    NodeDiskSpaceLowPayload,
     NodeTypeUpdatePayload,
+    ProcessWatcherGaveUpPayload,
     ShowReleaseNotesPayload,
+    StratumProxyUpdatePayload,
     TariAddressUpdatePayload,
+    ThermalGovernorAction,
 } from './events-payloads.ts';
 import {
     BaseNodeStatus,
@@ -215,4 +225,44 @@ export type BackendStateUpdateEvent =
     | {
           event_type: 'ShouldShowExchangeMinerModal';
           payload: undefined;
+      }
+    | {
+          event_type: 'ProcessWatcherGaveUp';
+          payload: ProcessWatcherGaveUpPayload;
+      }
+    | {
+          event_type: 'MiningScheduleUpdate';
+          payload: MiningScheduleUpdatePayload;
+      }
+    | {
+          event_type: 'IdleMiningUpdate';
+          payload: IdleMiningUpdatePayload;
+      }
+    | {
+          event_type: 'ThermalGovernorAction';
+          payload: ThermalGovernorAction;
+      }
+    | {
+          event_type: 'BatteryPolicyUpdate';
+          payload: BatteryPolicyUpdatePayload;
+      }
+    | {
+          event_type: 'MiningBenchmarkProgress';
+          payload: MiningBenchmarkProgressPayload;
+      }
+    | {
+          event_type: 'StratumProxyUpdate';
+          payload: StratumProxyUpdatePayload;
+      }
+    | {
+          event_type: 'GpuDevicesUpdate';
+          payload: GpuDeviceUpdatePayload[];
+      }
+    | {
+          event_type: 'GpuEngineFallback';
+          payload: GpuEngineFallbackPayload;
+      }
+    | {
+          event_type: 'NodeDiskSpaceLow';
+          payload: NodeDiskSpaceLowPayload;
       };
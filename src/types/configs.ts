@@ -22,9 +22,26 @@ export interface ConfigCore {
         refreshToken: string;
     };
     remote_base_node_address: string;
+    remote_base_node_fallback_addresses: string[];
     node_type?: NodeType;
+    custom_node?: CustomNodeSettings;
+    local_node_pruned_mode: boolean;
+    local_node_pruning_horizon: number;
+    local_node_data_dir?: string;
+    local_node_min_free_disk_space_gb: number;
+    local_node_snapshot_mirror?: string;
+    // Dial-exclusions only, a banned peer can still connect inbound
+    local_node_banned_peers: { public_key: string; addresses: string[] }[];
+    local_node_seed_peers: string[];
+    local_node_dns_seeds: string[];
+    local_node_max_inbound_connections?: number;
+    local_node_max_outbound_connections?: number;
     exchange_id?: string;
 }
+export interface CustomNodeSettings {
+    host: string;
+    grpc_port: number;
+}
 export interface ConfigWallet {
     created_at: string;
     monero_address: string;
@@ -63,6 +80,43 @@ export interface ConfigMining {
     cpu_mining_enabled: boolean;
     gpu_engine: string;
     mining_time: number;
+    mining_schedule_enabled: boolean;
+    mining_schedule: MiningScheduleRule[];
+    idle_mining_enabled: boolean;
+    idle_mining_timeout_secs: number;
+    idle_mining_mode: MiningModeType;
+    thermal_governor_enabled: boolean;
+    cpu_max_temperature?: number;
+    gpu_max_temperatures: GpuTemperatureCeiling[];
+    gpu_device_settings: GpuDeviceMiningSettings[];
+    thermal_hysteresis: number;
+    battery_policy_action: BatteryMiningAction;
+    battery_policy_threshold?: number;
+    mining_history_retention_days: number;
+}
+
+export type BatteryMiningAction = 'None' | 'PauseGpuMining' | 'ForceEcoMode' | 'StopMining';
+
+export interface GpuDeviceMiningSettings {
+    device_index: number;
+    mode?: MiningModeType;
+    engine?: string;
+}
+
+export interface GpuTemperatureCeiling {
+    device_index: number;
+    max_temperature: number;
+}
+
+export type ScheduleDay = 'Monday' | 'Tuesday' | 'Wednesday' | 'Thursday' | 'Friday' | 'Saturday' | 'Sunday';
+
+export interface MiningScheduleRule {
+    days: ScheduleDay[];
+    start_time: string;
+    end_time: string;
+    cpu_mining: boolean;
+    gpu_mining: boolean;
+    mode: MiningModeType;
 }
 
 export interface ConfigBackendInMemory {
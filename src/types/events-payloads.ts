@@ -1,4 +1,14 @@
-import { GpuDevice, TransactionInfo, WalletBalance } from './app-status';
+import {
+    GpuDevice,
+    GpuDeviceHealth,
+    GpuDeviceStats,
+    GpuHardwareReading,
+    StratumWorker,
+    TransactionInfo,
+    WalletBalance,
+} from './app-status';
+import { BatteryMiningAction, MiningScheduleRule } from './configs';
+import { MiningModeType } from '@app/store/types.ts';
 
 export interface TariAddressUpdatePayload {
     tari_address_base58: string;
@@ -26,6 +36,77 @@ export interface CriticalProblemPayload {
     error_message?: string;
 }
 
+export interface ProcessWatcherGaveUpPayload {
+    name: string;
+    num_restarts: number;
+    exit_code: number;
+}
+
+export interface MiningScheduleUpdatePayload {
+    active_rule?: MiningScheduleRule;
+    cpu_mining: boolean;
+    gpu_mining: boolean;
+}
+
+export interface IdleMiningUpdatePayload {
+    is_idle: boolean;
+    mode: MiningModeType;
+    cpu_mining: boolean;
+    gpu_mining: boolean;
+}
+
+export interface PowerSourceStatus {
+    is_on_battery: boolean;
+    battery_percentage?: number;
+}
+
+export interface BatteryPolicyUpdatePayload {
+    power_source: PowerSourceStatus;
+    applied_action?: BatteryMiningAction;
+    cpu_mining: boolean;
+    gpu_mining: boolean;
+}
+
+export interface MiningBenchmarkProgressPayload {
+    completed_runs: number;
+    total_runs: number;
+}
+
+export interface GpuDeviceUpdatePayload {
+    device_index: number;
+    device_name: string;
+    health: GpuDeviceHealth;
+    stats?: GpuDeviceStats;
+    hardware?: GpuHardwareReading;
+}
+
+export interface GpuEngineFallbackPayload {
+    failed_engine: string;
+    engine?: string;
+}
+
+export interface NodeDiskSpaceLowPayload {
+    mount_point: string;
+    available_space: number;
+    min_free_space: number;
+}
+
+export interface StratumProxyUpdatePayload {
+    workers: StratumWorker[];
+}
+
+export type ThermalDevice = 'Cpu' | { Gpu: number };
+
+export interface ThermalGovernorAction {
+    device: ThermalDevice;
+    kind: 'Throttled' | 'Paused' | 'Resumed';
+    temperature: number;
+    max_temperature: number;
+    throttle_level: number;
+    load_percentage: number;
+    timestamp: number;
+}
+
 export interface ShowReleaseNotesPayload {
     release_notes: string;
     is_app_update_available: boolean;
@@ -11,12 +11,24 @@ import {
     BridgeEnvs,
     TariAddressVariants,
     BaseNodeStatus,
+    MiningBenchmarkResults,
+    HashrateSample,
+    BlockEvent,
+    StratumWorker,
 } from './app-status';
 import { Language } from '@app/i18initializer';
 import { PaperWalletDetails } from '@app/types/app-status.ts';
 import { displayMode, MiningModeType } from '@app/store/types.ts';
+import { NodePeer, NodeType } from '@app/store/useNodeStore.ts';
 import { SignData } from '@app/types/ws.ts';
-import { ConfigBackendInMemory } from '@app/types/configs.ts';
+import {
+    BatteryMiningAction,
+    ConfigBackendInMemory,
+    GpuDeviceMiningSettings,
+    GpuTemperatureCeiling,
+    MiningScheduleRule,
+} from '@app/types/configs.ts';
+import { ThermalGovernorAction } from '@app/types/events-payloads.ts';
 import { ExchangeMiner } from './exchange';
 import { ActiveTapplet } from '@app/types/tapplets/tapplet.types';
 
@@ -52,6 +64,44 @@ declare module '@tauri-apps/api/core' {
         param: 'set_mode',
         payload: { mode: MiningModeType; customCpuUsage: number; customGpuUsage: GpuThreads[] }
     ): Promise<void>;
+    function invoke(
+        param: 'set_idle_mining',
+        payload: { enabled: boolean; timeoutSecs: number; mode: MiningModeType }
+    ): Promise<void>;
+    function invoke(
+        param: 'set_thermal_governor',
+        payload: {
+            enabled: boolean;
+            cpuMaxTemperature?: number;
+            gpuMaxTemperatures: GpuTemperatureCeiling[];
+            hysteresis: number;
+        }
+    ): Promise<void>;
+    function invoke(
+        param: 'set_gpu_device_settings',
+        payload: { gpuDeviceSettings: GpuDeviceMiningSettings[] }
+    ): Promise<void>;
+    function invoke(param: 'get_thermal_governor_actions'): Promise<ThermalGovernorAction[]>;
+    function invoke(
+        param: 'run_mining_benchmark',
+        payload: { benchmarkCpu: boolean; benchmarkGpu: boolean }
+    ): Promise<MiningBenchmarkResults>;
+    function invoke(param: 'get_mining_benchmark_results'): Promise<MiningBenchmarkResults | null>;
+    function invoke(param: 'export_mining_benchmark_results'): Promise<string>;
+    function invoke(
+        param: 'get_mining_history',
+        payload: { from: number; to: number; bucketSecs?: number }
+    ): Promise<HashrateSample[]>;
+    function invoke(param: 'get_block_history', payload: { from: number; to: number }): Promise<BlockEvent[]>;
+    function invoke(param: 'get_stratum_proxy_workers'): Promise<StratumWorker[]>;
+    function invoke(
+        param: 'set_battery_policy',
+        payload: { action: BatteryMiningAction; threshold?: number }
+    ): Promise<void>;
+    function invoke(
+        param: 'set_mining_schedule',
+        payload: { enabled: boolean; rules: MiningScheduleRule[] }
+    ): Promise<void>;
     function invoke(param: 'get_max_consumption_levels'): Promise<MaxConsumptionLevels>;
     function invoke(param: 'set_display_mode', payload: { displayMode: displayMode }): Promise<void>;
     function invoke(param: 'get_seed_words'): Promise<string[]>;
@@ -121,6 +171,22 @@ declare module '@tauri-apps/api/core' {
     function invoke(param: 'validate_minotari_amount', payload: { amount: string }): Promise<string>;
     function invoke(param: 'trigger_phases_restart'): Promise<void>;
     function invoke(param: 'set_node_type', payload: { nodeType: NodeType }): Promise<void>;
+    function invoke(param: 'set_custom_node', payload: { host: string; grpcPort: number }): Promise<void>;
+    function invoke(param: 'list_node_peers'): Promise<NodePeer[]>;
+    function invoke(param: 'ban_node_peer', payload: { publicKey: string }): Promise<void>;
+    function invoke(param: 'unban_node_peer', payload: { publicKey: string }): Promise<void>;
+    function invoke(param: 'set_node_seed_peers', payload: { seedPeers: string[] }): Promise<void>;
+    function invoke(param: 'set_node_dns_seeds', payload: { dnsSeeds: string[] }): Promise<void>;
+    function invoke(
+        param: 'set_node_connection_limits',
+        payload: { maxInboundConnections?: number; maxOutboundConnections?: number }
+    ): Promise<void>;
+    function invoke(
+        param: 'set_local_node_storage',
+        payload: { prunedMode: boolean; pruningHorizon: number; dataDir?: string }
+    ): Promise<void>;
+    function invoke(param: 'is_node_snapshot_bootstrap_enabled'): Promise<boolean>;
+    function invoke(param: 'set_local_node_snapshot_mirror', payload: { mirror?: string }): Promise<void>;
     function invoke(param: 'set_warmup_seen', payload: { warmupSeen: boolean }): Promise<void>;
     function invoke(param: 'set_external_tari_address', payload: { address: string }): Promise<void>;
     function invoke(param: 'confirm_exchange_address', payload: { address: string }): Promise<void>;
@@ -5,6 +5,7 @@ function mapGPU(gpus: MaxConsumptionLevels['max_gpus_threads'], maxThreads: numb
     return (
         gpus?.map((gpu) => ({
             gpu_name: gpu.gpu_name,
+            device_index: gpu.device_index,
             max_gpu_threads: maxThreads,
         })) || []
     );